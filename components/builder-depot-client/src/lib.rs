@@ -368,7 +368,28 @@ impl Client {
     /// * Package cannot be found
     /// * Remote Depot is not available
     pub fn show_package<I: Identifiable>(&self, ident: &I) -> Result<depotsrv::Package> {
-        let mut res = try!(self.inner.get(&self.path_show_package(ident)).send());
+        self.show_package_at(&self.path_show_package(ident))
+    }
+
+    /// Returns a package struct for the latest package promoted to the given channel.
+    ///
+    /// An optional version can be specified which will scope the release returned to the latest
+    /// release of that package in the channel.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found in the channel
+    /// * Channel does not exist
+    /// * Remote Depot is not available
+    pub fn show_package_in_channel<I: Identifiable>(&self,
+                                                    ident: &I,
+                                                    channel: &str)
+                                                    -> Result<depotsrv::Package> {
+        self.show_package_at(&self.path_show_package_in_channel(ident, channel))
+    }
+
+    fn show_package_at(&self, path: &str) -> Result<depotsrv::Package> {
+        let mut res = try!(self.inner.get(path).send());
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
@@ -514,6 +535,23 @@ impl Client {
         }
     }
 
+    fn path_show_package_in_channel<I: Identifiable>(&self, package: &I, channel: &str) -> String {
+        let mut path = format!("channels/{}/{}/pkgs/{}",
+                               package.origin(),
+                               channel,
+                               package.name());
+        if let Some(version) = package.version() {
+            path.push_str(&format!("/{}", version));
+        }
+        match package.release() {
+            Some(release) if package.version().is_some() => {
+                path.push_str(&format!("/{}", release))
+            }
+            _ => path.push_str("/latest"),
+        }
+        path
+    }
+
     fn download<D>(&self, path: &str, dst_path: &Path, progress: Option<D>) -> Result<PathBuf>
         where D: DisplayProgress + Sized
     {
@@ -27,6 +27,7 @@ use std::str::FromStr;
 
 use time::SteadyTime;
 
+use habitat_butterfly::identity::Identity;
 use habitat_butterfly::server::{Server, Suitability};
 use habitat_butterfly::member::{Member, Health};
 use habitat_butterfly::server::timing::Timing;
@@ -50,6 +51,19 @@ impl Suitability for NSuitability {
 }
 
 pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
+    let mut server = new_server(name, ring_key, suitability);
+    server.start(Timing::default()).expect("Cannot start server");
+    server
+}
+
+pub fn start_server_with_identity(name: &str, identity: Identity) -> Server {
+    let mut server = new_server(name, None, 0);
+    server.set_identity(identity);
+    server.start(Timing::default()).expect("Cannot start server");
+    server
+}
+
+fn new_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
     SERVER_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
     let swim_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
     let gossip_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
@@ -58,17 +72,15 @@ pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> S
     let mut member = Member::default();
     member.set_swim_port(swim_port as i32);
     member.set_gossip_port(gossip_port as i32);
-    let mut server = Server::new(&listen_swim[..],
-                             &listen_gossip[..],
-                             member,
-                             Trace::default(),
-                             ring_key,
-                             Some(String::from(name)),
-                             None::<PathBuf>,
-                             Box::new(NSuitability(suitability)))
-            .unwrap();
-    server.start(Timing::default()).expect("Cannot start server");
-    server
+    Server::new(&listen_swim[..],
+                &listen_gossip[..],
+                member,
+                Trace::default(),
+                ring_key,
+                Some(String::from(name)),
+                None::<PathBuf>,
+                Box::new(NSuitability(suitability)))
+            .unwrap()
 }
 
 pub fn member_from_server(server: &Server) -> Member {
@@ -126,6 +138,14 @@ impl SwimNet {
         SwimNet { members: members }
     }
 
+    pub fn new_member_identities(identities: Vec<Identity>) -> SwimNet {
+        let mut members = Vec::with_capacity(identities.len());
+        for (x, identity) in identities.into_iter().enumerate() {
+            members.push(start_server_with_identity(&format!("{}", x), identity));
+        }
+        SwimNet { members: members }
+    }
+
     pub fn connect(&mut self, from_entry: usize, to_entry: usize) {
         let to = member_from_server(&self.members[to_entry]);
         trace_it!(TEST: &self.members[from_entry], format!("Connected {} {}", self.members[to_entry].name(), self.members[to_entry].member_id()));
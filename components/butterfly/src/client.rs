@@ -18,19 +18,25 @@
 
 use habitat_core::crypto::SymKey;
 use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message};
 use zmq;
 
 use ZMQ_CONTEXT;
+use identity::Identity;
 use message;
+use message::swim::Rumor as ProtoRumor;
 use rumor::Rumor;
+use rumor::departure::Departure;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
+use rumor::update_directive::{UpdateDirective, UpdateDirective_Action};
 use error::{Result, Error};
 
-/// Holds a ZMQ Push socket, and an optional ring encryption key.
+/// Holds a ZMQ Push socket, an optional ring encryption key, and an optional member identity.
 pub struct Client {
     socket: zmq::Socket,
     ring_key: Option<SymKey>,
+    identity: Option<Identity>,
 }
 
 impl Client {
@@ -51,9 +57,16 @@ impl Client {
         Ok(Client {
                socket: socket,
                ring_key: ring_key,
+               identity: None,
            })
     }
 
+    /// Sign everything this client sends, and every rumor it starts, with a member identity, for
+    /// rings which authenticate their members.
+    pub fn set_identity(&mut self, identity: Identity) {
+        self.identity = Some(identity);
+    }
+
     /// Create a service configuration and send it to the server.
     pub fn send_service_config(&mut self,
                                service_group: ServiceGroup,
@@ -61,7 +74,7 @@ impl Client {
                                config: Vec<u8>,
                                encrypted: bool)
                                -> Result<()> {
-        let mut sc = ServiceConfig::new("butterflyclient", service_group, config);
+        let mut sc = ServiceConfig::new(self.from_id(), service_group, config);
         sc.set_incarnation(incarnation);
         sc.set_encrypted(encrypted);
         self.send(sc)
@@ -75,16 +88,50 @@ impl Client {
                                               body: Vec<u8>,
                                               encrypted: bool)
                                               -> Result<()> {
-        let mut sf = ServiceFile::new("butterflyclient", service_group, filename, body);
+        let mut sf = ServiceFile::new(self.from_id(), service_group, filename, body);
         sf.set_incarnation(incarnation);
         sf.set_encrypted(encrypted);
         self.send(sf)
     }
 
+    /// Create an update directive and send it to the server.
+    pub fn send_update_directive(&mut self,
+                                 service_group: ServiceGroup,
+                                 incarnation: u64,
+                                 action: UpdateDirective_Action,
+                                 pkg: Option<String>)
+                                 -> Result<()> {
+        let mut ud = UpdateDirective::new(self.from_id(), service_group, action, pkg);
+        ud.set_incarnation(incarnation);
+        self.send(ud)
+    }
+
+    /// Create a departure for the given member and send it to the server.
+    pub fn send_departure<S: Into<String>>(&mut self, member_id: S) -> Result<()> {
+        let departure = Departure::new(self.from_id(), member_id);
+        self.send(departure)
+    }
+
+    /// The member id our rumors are from. With an identity, it must be the name of our member
+    /// key, or the server rejects them.
+    fn from_id(&self) -> String {
+        match self.identity {
+            Some(ref identity) => identity.member_name().to_string(),
+            None => "butterflyclient".to_string(),
+        }
+    }
+
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
-        let bytes = try!(rumor.write_to_bytes());
-        let wire_msg = try!(message::generate_wire(bytes, &self.ring_key));
+        let mut bytes = try!(rumor.write_to_bytes());
+        if let Some(ref identity) = self.identity {
+            let mut proto: ProtoRumor = try!(protobuf::parse_from_bytes(&bytes));
+            try!(message::sign_rumor(&mut proto, identity));
+            bytes = try!(proto.write_to_bytes());
+        }
+        let wire_msg = try!(message::generate_wire(bytes,
+                                                     self.ring_key.as_ref(),
+                                                     self.identity.as_ref()));
         self.socket.send(&wire_msg, 0).map_err(Error::ZmqSendError)
     }
 }
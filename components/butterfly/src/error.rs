@@ -36,11 +36,14 @@ pub enum Error {
     HabitatCore(habitat_core::error::Error),
     NonExistentRumor(String, String),
     ProtobufError(protobuf::ProtobufError),
+    RevokedMember(String),
     ServiceConfigDecode(String, toml::de::Error),
     ServiceConfigNotUtf8(String, str::Utf8Error),
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    UnauthenticatedSender(String),
+    UnauthorizedSigner(String),
     ZmqConnectError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
@@ -72,6 +75,9 @@ impl fmt::Display for Error {
                         rumor_id)
             }
             Error::ProtobufError(ref err) => format!("ProtoBuf Error: {}", err),
+            Error::RevokedMember(ref member) => {
+                format!("Message signed by revoked member key {}", member)
+            }
             Error::ServiceConfigDecode(ref sg, ref err) => {
                 format!("Cannot decode service config: group={}, {:?}", sg, err)
             }
@@ -85,6 +91,12 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::UnauthenticatedSender(ref err) => {
+                format!("Cannot authenticate the sender of a message: {}", err)
+            }
+            Error::UnauthorizedSigner(ref err) => {
+                format!("Rumor signed by a member which may not start it: {}", err)
+            }
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
@@ -106,11 +118,14 @@ impl error::Error for Error {
             Error::HabitatCore(_) => "Habitat core error",
             Error::NonExistentRumor(_, _) => "Cannot write rumor to bytes because it does not exist",
             Error::ProtobufError(ref err) => err.description(),
+            Error::RevokedMember(_) => "Message signed by a revoked member key",
             Error::ServiceConfigDecode(_, _) => "Cannot decode service config into TOML",
             Error::ServiceConfigNotUtf8(_, _) => "Cannot read service config bytes to UTF-8",
             Error::SocketSetReadTimeout(_) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(_) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::UnauthenticatedSender(_) => "Cannot authenticate the sender of a message",
+            Error::UnauthorizedSigner(_) => "Rumor signed by a member which may not start it",
             Error::ZmqConnectError(_) => "Cannot connect ZMQ socket",
             Error::ZmqSendError(_) => "Cannot send message through ZMQ socket",
         }
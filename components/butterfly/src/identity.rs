@@ -0,0 +1,330 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Member identities, for rings which authenticate the sender of every message.
+//!
+//! Each member of such a ring holds a signing key, certified by the ring authority. Every message
+//! a member sends carries its certificate and is signed with its key, and so does every rumor a
+//! member starts, so that the members which pass it on cannot alter it. Receivers verify both
+//! with the authority's public key, and reject messages and rumors from members the authority
+//! has revoked.
+//!
+//! Departures and update directives act on other members, so they are only accepted from the
+//! ring operators: the members named with `set_operators`.
+//!
+//! Member keys are `SigKeyPair`s rather than `BoxKeyPair`s: a box is sealed for one recipient,
+//! while gossip is fanned out, so a message must carry a signature every member can check.
+//!
+//! Revocations are not gossiped. The authority's list of revoked members is read from the key
+//! cache, and has to be copied to every member out of band.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str;
+use std::sync::{Arc, RwLock};
+
+use habitat_core::crypto::{MemberCertificate, SigKeyPair};
+use habitat_core::crypto::member_cert;
+use time::{SteadyTime, Duration as TimeDuration};
+
+use error::{Error, Result};
+
+/// How often the authority's list of revoked members is re-read from the key cache.
+const REVOCATION_RELOAD_INTERVAL_MS: i64 = 10_000;
+
+/// The signing key and certificate of this member, and the ring authority it verifies others
+/// with. Clones share the list of revoked members.
+#[derive(Clone)]
+pub struct Identity {
+    key: SigKeyPair,
+    certificate: Vec<u8>,
+    authority: SigKeyPair,
+    cache_key_path: PathBuf,
+    operators: HashSet<String>,
+    revoked: Arc<RwLock<Revocations>>,
+}
+
+impl fmt::Debug for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Identity {{ key: {}, authority: {} }}",
+               self.key.name_with_rev(),
+               self.authority.name_with_rev())
+    }
+}
+
+struct Revocations {
+    members: HashSet<String>,
+    next_reload: SteadyTime,
+}
+
+impl Identity {
+    /// Create a new Identity from a member key with its secret, the certificate of that key, and
+    /// the public key of the authority which issued it.
+    pub fn new<P: AsRef<Path>>(key: SigKeyPair,
+                               certificate: MemberCertificate,
+                               authority: SigKeyPair,
+                               cache_key_path: P)
+                               -> Result<Identity> {
+        try!(key.secret());
+        let certified = try!(certificate.verify(&authority));
+        if certified.public != key.public {
+            return Err(Error::UnauthenticatedSender(format!("certificate {} does not certify \
+                                                             the member key",
+                                                            certificate.member)));
+        }
+        Ok(Identity {
+               key: key,
+               certificate: certificate.to_string().into_bytes(),
+               authority: authority,
+               cache_key_path: cache_key_path.as_ref().to_path_buf(),
+               operators: HashSet::new(),
+               revoked: Arc::new(RwLock::new(Revocations {
+                                                 members: HashSet::new(),
+                                                 next_reload: SteadyTime::now(),
+                                             })),
+           })
+    }
+
+    /// Load the latest revision of the member key `name`, its certificate, and the authority
+    /// which issued the certificate from the key cache.
+    pub fn load<P: AsRef<Path>>(name: &str, cache_key_path: P) -> Result<Identity> {
+        let cache_key_path = cache_key_path.as_ref();
+        let key = try!(SigKeyPair::get_latest_pair_for(name, cache_key_path));
+        let certificate = try!(MemberCertificate::get_for(&key.name_with_rev(), cache_key_path));
+        let authority = try!(SigKeyPair::get_pair_for(&certificate.authority, cache_key_path));
+        Identity::new(key, certificate, authority, cache_key_path)
+    }
+
+    /// The name of this member's key, which is the member id of whoever holds it.
+    pub fn member_name(&self) -> &str {
+        &self.key.name
+    }
+
+    /// The name with revision of this member's key.
+    pub fn name_with_rev(&self) -> String {
+        self.key.name_with_rev()
+    }
+
+    /// The certificate of this member's key, as it is sent on the wire.
+    pub fn certificate(&self) -> &[u8] {
+        &self.certificate
+    }
+
+    /// Sign a payload with this member's key.
+    pub fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        Ok(try!(self.key.sign(payload)))
+    }
+
+    /// Sign a payload with this member's key, returning only the signature.
+    pub fn sign_detached(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        Ok(try!(self.key.sign_detached(payload)))
+    }
+
+    /// Accept departures and update directives from the members with these key names.
+    pub fn set_operators(&mut self, operators: Vec<String>) {
+        self.operators = operators.into_iter().collect();
+    }
+
+    /// Returns true if the member is a ring operator.
+    pub fn is_operator(&self, member: &str) -> bool {
+        self.operators.contains(member)
+    }
+
+    /// Authenticate a signed payload sent with the given certificate, returning the name of the
+    /// certified member, which is the id it must send the payload as, and the payload.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::UnauthenticatedSender` if the certificate was not issued by our authority, or the
+    ///   payload was not signed with the certified key
+    /// * `Error::RevokedMember` if the authority has revoked the certified key
+    pub fn authenticate(&self, certificate: &[u8], signed: &[u8]) -> Result<(String, Vec<u8>)> {
+        let member = try!(self.certified(certificate));
+        let payload = try!(member.verify(signed)
+            .map_err(|e| Error::UnauthenticatedSender(e.to_string())));
+        Ok((member.name, payload))
+    }
+
+    /// Authenticate a payload signed by `sign_detached` with the key of the given certificate,
+    /// returning the name of the certified member.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::UnauthenticatedSender` if the certificate was not issued by our authority, or the
+    ///   signature was not made over the payload with the certified key
+    /// * `Error::RevokedMember` if the authority has revoked the certified key
+    pub fn authenticate_detached(&self,
+                                 certificate: &[u8],
+                                 payload: &[u8],
+                                 signature: &[u8])
+                                 -> Result<String> {
+        let member = try!(self.certified(certificate));
+        try!(member.verify_detached(payload, signature)
+            .map_err(|e| Error::UnauthenticatedSender(e.to_string())));
+        Ok(member.name)
+    }
+
+    /// Verify a certificate against our authority, returning the unrevoked member key it
+    /// certifies.
+    fn certified(&self, certificate: &[u8]) -> Result<SigKeyPair> {
+        let certificate = try!(str::from_utf8(certificate)
+            .map_err(|_| Error::UnauthenticatedSender("certificate is not UTF-8".to_string())));
+        let certificate = try!(MemberCertificate::parse(certificate)
+            .map_err(|e| Error::UnauthenticatedSender(e.to_string())));
+        let member = try!(certificate.verify(&self.authority)
+            .map_err(|e| Error::UnauthenticatedSender(e.to_string())));
+        if self.is_revoked(&certificate.member) {
+            return Err(Error::RevokedMember(certificate.member));
+        }
+        Ok(member)
+    }
+
+    /// Returns true if the authority has revoked the member key, re-reading the list of revoked
+    /// members if it is stale.
+    pub fn is_revoked(&self, member_with_rev: &str) -> bool {
+        {
+            let revoked = self.revoked.read().expect("Revocations lock poisoned");
+            if SteadyTime::now() < revoked.next_reload {
+                return member_cert::is_revoked(&revoked.members, member_with_rev);
+            }
+        }
+        let mut revoked = self.revoked.write().expect("Revocations lock poisoned");
+        if SteadyTime::now() >= revoked.next_reload {
+            match member_cert::revoked_members(&self.authority.name, &self.cache_key_path) {
+                Ok(members) => revoked.members = members,
+                Err(e) => {
+                    warn!("Cannot read the members revoked by {}: {}",
+                          self.authority.name,
+                          e)
+                }
+            }
+            revoked.next_reload = SteadyTime::now() +
+                                  TimeDuration::milliseconds(REVOCATION_RELOAD_INTERVAL_MS);
+        }
+        member_cert::is_revoked(&revoked.members, member_with_rev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::{MemberCertificate, SigKeyPair};
+    use habitat_core::crypto::member_cert;
+
+    use error::Error;
+    use identity::Identity;
+    use test_support::KeyCache;
+
+    fn identity(name: &str, authority: &SigKeyPair, cache: &KeyCache) -> Identity {
+        let key = SigKeyPair::generate_pair_for_origin(name, cache.path()).unwrap();
+        let certificate = MemberCertificate::issue(&key, authority).unwrap();
+        certificate.write_file(cache.path()).unwrap();
+        Identity::load(name, cache.path()).unwrap()
+    }
+
+    #[test]
+    fn authenticate_a_certified_member() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let alice = identity("alice", &authority, &cache);
+        let bob = identity("bob", &authority, &cache);
+
+        let signed = alice.sign(b"the ring is safe").unwrap();
+        assert_eq!(bob.authenticate(alice.certificate(), &signed).unwrap(),
+                   ("alice".to_string(), b"the ring is safe".to_vec()));
+    }
+
+    #[test]
+    fn authenticate_a_detached_signature() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let alice = identity("alice", &authority, &cache);
+        let bob = identity("bob", &authority, &cache);
+        let mallory = identity("mallory", &authority, &cache);
+
+        let signature = alice.sign_detached(b"the ring is safe").unwrap();
+        assert_eq!(bob.authenticate_detached(alice.certificate(), b"the ring is safe", &signature)
+                       .unwrap(),
+                   "alice");
+        match bob.authenticate_detached(alice.certificate(), b"the ring is lost", &signature) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted a signature over another payload"),
+        }
+        match bob.authenticate_detached(mallory.certificate(), b"the ring is safe", &signature) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted a signature made by another key"),
+        }
+    }
+
+    #[test]
+    fn operators_are_named_by_key() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let mut alice = identity("alice", &authority, &cache);
+        assert!(!alice.is_operator("ops"));
+        alice.set_operators(vec!["ops".to_string()]);
+        assert!(alice.is_operator("ops"));
+        assert!(!alice.is_operator("alice"));
+    }
+
+    #[test]
+    fn reject_a_member_of_another_authority() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let other = SigKeyPair::generate_pair_for_origin("other", cache.path()).unwrap();
+        let alice = identity("alice", &authority, &cache);
+        let mallory = identity("mallory", &other, &cache);
+
+        let signed = mallory.sign(b"let me in").unwrap();
+        match alice.authenticate(mallory.certificate(), &signed) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted a member certified by another authority"),
+        }
+    }
+
+    #[test]
+    fn reject_a_payload_signed_by_another_key() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let alice = identity("alice", &authority, &cache);
+        let mallory = identity("mallory", &authority, &cache);
+
+        let signed = mallory.sign(b"I am alice").unwrap();
+        match mallory.authenticate(alice.certificate(), &signed) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted a payload signed by another key"),
+        }
+    }
+
+    #[test]
+    fn reject_a_revoked_member() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let alice = identity("alice", &authority, &cache);
+        let mallory = identity("mallory", &authority, &cache);
+        member_cert::revoke_member("authority", "mallory", cache.path()).unwrap();
+        let bob = identity("bob", &authority, &cache);
+
+        let signed = mallory.sign(b"still here").unwrap();
+        match bob.authenticate(mallory.certificate(), &signed) {
+            Err(Error::RevokedMember(ref member)) => {
+                assert_eq!(member, &mallory.name_with_rev())
+            }
+            _ => panic!("Accepted a revoked member"),
+        }
+        let signed = alice.sign(b"still here").unwrap();
+        assert!(bob.authenticate(alice.certificate(), &signed).is_ok());
+    }
+}
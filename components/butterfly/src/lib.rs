@@ -46,6 +46,8 @@ extern crate habitat_core;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate prometheus;
 extern crate protobuf;
 extern crate rand;
 extern crate serde;
@@ -61,8 +63,11 @@ extern crate zmq;
 pub mod trace;
 pub mod client;
 pub mod error;
+pub mod identity;
 pub mod member;
 pub mod message;
+pub mod partition;
+pub mod ring_keys;
 pub mod rumor;
 pub mod server;
 
@@ -93,3 +98,33 @@ impl ServerContext {
 
 unsafe impl Send for ServerContext {}
 unsafe impl Sync for ServerContext {}
+
+#[cfg(test)]
+pub mod test_support {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use uuid::Uuid;
+
+    /// A key cache directory of its own for a test, removed when it is dropped.
+    pub struct KeyCache(PathBuf);
+
+    impl KeyCache {
+        pub fn new() -> KeyCache {
+            let path = env::temp_dir().join(format!("butterfly-keys-{}", Uuid::new_v4()));
+            fs::create_dir_all(&path).unwrap();
+            KeyCache(path)
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for KeyCache {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+}
@@ -544,6 +544,24 @@ impl MemberList {
         suspects.remove(member_id);
     }
 
+    /// Removes a member from the list entirely, along with its health and any suspicion. Returns
+    /// true if the member was known.
+    pub fn remove(&self, member_id: &str) -> bool {
+        // Take the suspect lock first, so the expire thread never sees a suspect without a member.
+        let mut suspects = self.suspect.write().expect("Suspect list lock is poisoned");
+        suspects.remove(member_id);
+        let removed = self.members
+            .write()
+            .expect("Member list lock is poisoned")
+            .remove(member_id)
+            .is_some();
+        self.health.write().expect("Health lock is poisoned").remove(member_id);
+        if removed {
+            self.increment_update_counter();
+        }
+        removed
+    }
+
     pub fn contains_member(&self, member_id: &str) -> bool {
         self.members
             .read()
@@ -604,6 +622,20 @@ mod tests {
             assert_eq!(ml.len(), 4);
         }
 
+        #[test]
+        fn remove() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let member_id = String::from(member.get_id());
+            ml.insert(member, Health::Suspect);
+            let counter = ml.get_update_counter();
+            assert!(ml.remove(&member_id));
+            assert!(!ml.contains_member(&member_id));
+            assert_eq!(ml.health_of_by_id(&member_id), None);
+            assert_eq!(ml.get_update_counter(), counter + 1);
+            assert!(!ml.remove(&member_id));
+        }
+
         #[test]
         fn check_list() {
             let ml = populated_member_list(1000);
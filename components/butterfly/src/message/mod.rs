@@ -22,33 +22,111 @@ use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use toml;
 
-use error::Result;
-use message::swim::Wire;
+use error::{Error, Result};
+use identity::Identity;
+use message::swim::{Rumor, Wire};
 use rumor::service::SysInfo;
 use protobuf::{self, Message};
 
-pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+/// Wrap a payload in a `Wire` message, encrypting it with the ring key and signing it with the
+/// identity if they are given.
+pub fn generate_wire(payload: Vec<u8>,
+                     ring_key: Option<&SymKey>,
+                     identity: Option<&Identity>)
+                     -> Result<Vec<u8>> {
     let mut wire = Wire::new();
-    if let Some(ref ring_key) = *ring_key {
+    let payload = if let Some(ring_key) = ring_key {
         wire.set_encrypted(true);
         let (nonce, encrypted_payload) = try!(ring_key.encrypt(&payload));
         wire.set_nonce(nonce);
-        wire.set_payload(encrypted_payload);
+        encrypted_payload
+    } else {
+        payload
+    };
+    if let Some(identity) = identity {
+        wire.set_certificate(identity.certificate().to_vec());
+        wire.set_payload(try!(identity.sign(&payload)));
     } else {
         wire.set_payload(payload);
     }
     Ok(try!(wire.write_to_bytes()))
 }
 
-pub fn unwrap_wire(payload: &[u8], ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+/// Unwrap a `Wire` message, returning its payload, and the name of the member which sent it if
+/// there is an identity. With an identity, the sender must be an unrevoked member certified by
+/// the same ring authority, and must have signed the payload; callers check that a SWIM message
+/// comes from that member with `check_sender`, and who started a rumor with `rumor_signer`.
+/// With ring keys, the payload is decrypted with the first of them which succeeds; the error of
+/// the last one is returned if none do.
+pub fn unwrap_wire(payload: &[u8],
+                   ring_keys: &[SymKey],
+                   identity: Option<&Identity>)
+                   -> Result<(Vec<u8>, Option<String>)> {
     let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
-    if let Some(ref ring_key) = *ring_key {
-        Ok(try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload())))
+    let (sender, payload) = if let Some(identity) = identity {
+        if !wire.has_certificate() {
+            return Err(Error::UnauthenticatedSender("message has no certificate".to_string()));
+        }
+        let (sender, payload) = try!(identity.authenticate(wire.get_certificate(),
+                                                           wire.get_payload()));
+        (Some(sender), payload)
     } else {
-        Ok(wire.take_payload())
+        (None, wire.take_payload())
+    };
+    let mut last_err = None;
+    for ring_key in ring_keys {
+        match ring_key.decrypt(wire.get_nonce(), &payload) {
+            Ok(decrypted) => return Ok((decrypted, sender)),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    match last_err {
+        Some(e) => Err(Error::from(e)),
+        None => Ok((payload, sender)),
+    }
+}
+
+/// Check that a message claiming to be from `member_id` was sent by that member, when
+/// `unwrap_wire` authenticated its sender; a certified member may not speak for another.
+pub fn check_sender(sender: Option<&str>, member_id: &str) -> Result<()> {
+    match sender {
+        Some(sender) if sender != member_id => {
+            Err(Error::UnauthenticatedSender(format!("member {} sent a message as {}",
+                                                     sender,
+                                                     member_id)))
+        }
+        _ => Ok(()),
     }
 }
 
+/// Sign a rumor as started by the member with the identity, so that the members which pass it on
+/// cannot alter it. The rumor becomes from that member, and carries its certificate.
+pub fn sign_rumor(rumor: &mut Rumor, identity: &Identity) -> Result<()> {
+    rumor.clear_certificate();
+    rumor.clear_signature();
+    rumor.set_from_id(identity.member_name().to_string());
+    let signature = try!(identity.sign_detached(&try!(rumor.write_to_bytes())));
+    rumor.set_certificate(identity.certificate().to_vec());
+    rumor.set_signature(signature);
+    Ok(())
+}
+
+/// Authenticate the member which signed a rumor with `sign_rumor`, returning its name. The
+/// rumor must be unaltered since, and still be from the member which signed it.
+pub fn rumor_signer(rumor: &Rumor, identity: &Identity) -> Result<String> {
+    if !rumor.has_certificate() || !rumor.has_signature() {
+        return Err(Error::UnauthenticatedSender("rumor is not signed".to_string()));
+    }
+    let mut unsigned = rumor.clone();
+    unsigned.clear_certificate();
+    unsigned.clear_signature();
+    let signer = try!(identity.authenticate_detached(rumor.get_certificate(),
+                                                     &try!(unsigned.write_to_bytes()),
+                                                     rumor.get_signature()));
+    try!(check_sender(Some(signer.as_str()), rumor.get_from_id()));
+    Ok(signer)
+}
+
 impl Serialize for swim::Election {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
@@ -94,7 +172,7 @@ impl Serialize for swim::Rumor {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("rumor", 8));
+        let mut strukt = try!(serializer.serialize_struct("rumor", 10));
         try!(strukt.serialize_field("type", &self.get_field_type()));
         try!(strukt.serialize_field("tag", self.get_tag()));
         try!(strukt.serialize_field("from_id", self.get_from_id()));
@@ -113,6 +191,12 @@ impl Serialize for swim::Rumor {
         if self.has_election() {
             try!(strukt.serialize_field("election", self.get_election()));
         }
+        if self.has_update_directive() {
+            try!(strukt.serialize_field("update_directive", self.get_update_directive()));
+        }
+        if self.has_departure() {
+            try!(strukt.serialize_field("departure", self.get_departure()));
+        }
         strukt.end()
     }
 }
@@ -121,7 +205,7 @@ impl Serialize for swim::Service {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("service", 11));
+        let mut strukt = try!(serializer.serialize_struct("service", 12));
         let cfg = toml::from_slice(self.get_cfg()).unwrap_or(toml::value::Table::default());
         let sys = toml::from_slice(self.get_sys()).unwrap_or(SysInfo::default());
         try!(strukt.serialize_field("member_id", self.get_member_id()));
@@ -131,6 +215,8 @@ impl Serialize for swim::Service {
         try!(strukt.serialize_field("cfg", &cfg));
         try!(strukt.serialize_field("sys", &sys));
         try!(strukt.serialize_field("initialized", &self.get_initialized()));
+        try!(strukt.serialize_field("desired_state", self.get_desired_state()));
+        try!(strukt.serialize_field("health_check", self.get_health_check()));
         strukt.end()
     }
 }
@@ -168,6 +254,37 @@ impl Serialize for swim::ServiceFile {
     }
 }
 
+impl Serialize for swim::UpdateDirective {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("update_directive", 4));
+        try!(strukt.serialize_field("service_group", self.get_service_group()));
+        try!(strukt.serialize_field("incarnation", &self.get_incarnation()));
+        try!(strukt.serialize_field("action", &self.get_action()));
+        try!(strukt.serialize_field("pkg", self.get_pkg()));
+        strukt.end()
+    }
+}
+
+impl Serialize for swim::UpdateDirective_Action {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+impl Serialize for swim::Departure {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("departure", 1));
+        try!(strukt.serialize_field("member_id", self.get_member_id()));
+        strukt.end()
+    }
+}
+
 impl Serialize for swim::Election_Status {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
@@ -191,3 +308,90 @@ impl Serialize for swim::Rumor_Type {
         serializer.serialize_u8(*self as u8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::{MemberCertificate, SigKeyPair};
+
+    use error::Error;
+    use identity::Identity;
+    use message::{check_sender, rumor_signer, sign_rumor};
+    use message::swim::{Rumor, Rumor_Type};
+    use test_support::KeyCache;
+
+    fn identity(name: &str, authority: &SigKeyPair, cache: &KeyCache) -> Identity {
+        let key = SigKeyPair::generate_pair_for_origin(name, cache.path()).unwrap();
+        MemberCertificate::issue(&key, authority).unwrap().write_file(cache.path()).unwrap();
+        Identity::load(name, cache.path()).unwrap()
+    }
+
+    fn departure(member_id: &str) -> Rumor {
+        let mut rumor = Rumor::new();
+        rumor.set_field_type(Rumor_Type::Departure);
+        rumor.mut_departure().set_member_id(member_id.to_string());
+        rumor
+    }
+
+    #[test]
+    fn check_sender_of_authenticated_message() {
+        assert!(check_sender(Some("logan"), "logan").is_ok());
+        match check_sender(Some("victor"), "logan") {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted a message sent as another member"),
+        }
+    }
+
+    #[test]
+    fn check_sender_of_unauthenticated_message() {
+        assert!(check_sender(None, "logan").is_ok());
+    }
+
+    #[test]
+    fn signer_of_a_signed_rumor() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let logan = identity("logan", &authority, &cache);
+        let jean = identity("jean", &authority, &cache);
+
+        let mut rumor = departure("victor");
+        sign_rumor(&mut rumor, &logan).unwrap();
+        assert_eq!(rumor.get_from_id(), "logan");
+        assert_eq!(rumor_signer(&rumor, &jean).unwrap(), "logan");
+    }
+
+    #[test]
+    fn reject_an_unsigned_rumor() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let jean = identity("jean", &authority, &cache);
+
+        match rumor_signer(&departure("victor"), &jean) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted an unsigned rumor"),
+        }
+    }
+
+    #[test]
+    fn reject_an_altered_rumor() {
+        let cache = KeyCache::new();
+        let authority = SigKeyPair::generate_pair_for_origin("authority", cache.path()).unwrap();
+        let logan = identity("logan", &authority, &cache);
+        let jean = identity("jean", &authority, &cache);
+
+        let mut rumor = departure("victor");
+        sign_rumor(&mut rumor, &logan).unwrap();
+        rumor.mut_departure().set_member_id("jean".to_string());
+        match rumor_signer(&rumor, &jean) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted an altered rumor"),
+        }
+
+        let mut rumor = departure("victor");
+        sign_rumor(&mut rumor, &logan).unwrap();
+        rumor.set_from_id("jean".to_string());
+        match rumor_signer(&rumor, &jean) {
+            Err(Error::UnauthenticatedSender(_)) => (),
+            _ => panic!("Accepted a rumor passed on as from another member"),
+        }
+    }
+}
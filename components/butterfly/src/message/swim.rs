@@ -1487,6 +1487,8 @@ pub struct Election {
     suitability: ::std::option::Option<u64>,
     status: ::std::option::Option<Election_Status>,
     votes: ::protobuf::RepeatedField<::std::string::String>,
+    approved_pkg: ::protobuf::SingularField<::std::string::String>,
+    approval: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -1711,6 +1713,77 @@ impl Election {
     fn mut_votes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
         &mut self.votes
     }
+
+    // optional string approved_pkg = 7;
+
+    pub fn clear_approved_pkg(&mut self) {
+        self.approved_pkg.clear();
+    }
+
+    pub fn has_approved_pkg(&self) -> bool {
+        self.approved_pkg.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approved_pkg(&mut self, v: ::std::string::String) {
+        self.approved_pkg = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_approved_pkg(&mut self) -> &mut ::std::string::String {
+        if self.approved_pkg.is_none() {
+            self.approved_pkg.set_default();
+        };
+        self.approved_pkg.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_approved_pkg(&mut self) -> ::std::string::String {
+        self.approved_pkg.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_approved_pkg(&self) -> &str {
+        match self.approved_pkg.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_approved_pkg_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.approved_pkg
+    }
+
+    fn mut_approved_pkg_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.approved_pkg
+    }
+
+    // optional uint64 approval = 8;
+
+    pub fn clear_approval(&mut self) {
+        self.approval = ::std::option::Option::None;
+    }
+
+    pub fn has_approval(&self) -> bool {
+        self.approval.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approval(&mut self, v: u64) {
+        self.approval = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_approval(&self) -> u64 {
+        self.approval.unwrap_or(0)
+    }
+
+    fn get_approval_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.approval
+    }
+
+    fn mut_approval_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.approval
+    }
 }
 
 impl ::protobuf::Message for Election {
@@ -1752,6 +1825,16 @@ impl ::protobuf::Message for Election {
                 6 => {
                     ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.votes)?;
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.approved_pkg)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.approval = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1782,6 +1865,12 @@ impl ::protobuf::Message for Election {
         for value in &self.votes {
             my_size += ::protobuf::rt::string_size(6, &value);
         };
+        if let Some(v) = self.approved_pkg.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        };
+        if let Some(v) = self.approval {
+            my_size += ::protobuf::rt::value_size(8, v, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1806,6 +1895,12 @@ impl ::protobuf::Message for Election {
         for v in &self.votes {
             os.write_string(6, &v)?;
         };
+        if let Some(v) = self.approved_pkg.as_ref() {
+            os.write_string(7, &v)?;
+        };
+        if let Some(v) = self.approval {
+            os.write_uint64(8, v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1880,6 +1975,16 @@ impl ::protobuf::MessageStatic for Election {
                     Election::get_votes_for_reflect,
                     Election::mut_votes_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "approved_pkg",
+                    Election::get_approved_pkg_for_reflect,
+                    Election::mut_approved_pkg_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "approval",
+                    Election::get_approval_for_reflect,
+                    Election::mut_approval_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Election>(
                     "Election",
                     fields,
@@ -1898,6 +2003,8 @@ impl ::protobuf::Clear for Election {
         self.clear_suitability();
         self.clear_status();
         self.clear_votes();
+        self.clear_approved_pkg();
+        self.clear_approval();
         self.unknown_fields.clear();
     }
 }
@@ -1976,6 +2083,8 @@ pub struct Service {
     pkg: ::protobuf::SingularField<::std::string::String>,
     cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     sys: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    desired_state: ::protobuf::SingularField<::std::string::String>,
+    health_check: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2272,6 +2381,94 @@ impl Service {
     fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.sys
     }
+
+    // optional string desired_state = 13;
+
+    pub fn clear_desired_state(&mut self) {
+        self.desired_state.clear();
+    }
+
+    pub fn has_desired_state(&self) -> bool {
+        self.desired_state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_desired_state(&mut self, v: ::std::string::String) {
+        self.desired_state = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_desired_state(&mut self) -> &mut ::std::string::String {
+        if self.desired_state.is_none() {
+            self.desired_state.set_default();
+        };
+        self.desired_state.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_desired_state(&mut self) -> ::std::string::String {
+        self.desired_state.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_desired_state(&self) -> &str {
+        match self.desired_state.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_desired_state_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.desired_state
+    }
+
+    fn mut_desired_state_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.desired_state
+    }
+
+    // optional string health_check = 14;
+
+    pub fn clear_health_check(&mut self) {
+        self.health_check.clear();
+    }
+
+    pub fn has_health_check(&self) -> bool {
+        self.health_check.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health_check(&mut self, v: ::std::string::String) {
+        self.health_check = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_health_check(&mut self) -> &mut ::std::string::String {
+        if self.health_check.is_none() {
+            self.health_check.set_default();
+        };
+        self.health_check.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_health_check(&mut self) -> ::std::string::String {
+        self.health_check.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_health_check(&self) -> &str {
+        match self.health_check.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_health_check_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.health_check
+    }
+
+    fn mut_health_check_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.health_check
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2312,6 +2509,12 @@ impl ::protobuf::Message for Service {
                 12 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.sys)?;
                 },
+                13 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.desired_state)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.health_check)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2345,6 +2548,12 @@ impl ::protobuf::Message for Service {
         if let Some(v) = self.sys.as_ref() {
             my_size += ::protobuf::rt::bytes_size(12, &v);
         };
+        if let Some(v) = self.desired_state.as_ref() {
+            my_size += ::protobuf::rt::string_size(13, &v);
+        };
+        if let Some(v) = self.health_check.as_ref() {
+            my_size += ::protobuf::rt::string_size(14, &v);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2372,6 +2581,12 @@ impl ::protobuf::Message for Service {
         if let Some(v) = self.sys.as_ref() {
             os.write_bytes(12, &v)?;
         };
+        if let Some(v) = self.desired_state.as_ref() {
+            os.write_string(13, &v)?;
+        };
+        if let Some(v) = self.health_check.as_ref() {
+            os.write_string(14, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2451,6 +2666,16 @@ impl ::protobuf::MessageStatic for Service {
                     Service::get_sys_for_reflect,
                     Service::mut_sys_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "desired_state",
+                    Service::get_desired_state_for_reflect,
+                    Service::mut_desired_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "health_check",
+                    Service::get_health_check_for_reflect,
+                    Service::mut_health_check_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2470,6 +2695,8 @@ impl ::protobuf::Clear for Service {
         self.clear_pkg();
         self.clear_cfg();
         self.clear_sys();
+        self.clear_desired_state();
+        self.clear_health_check();
         self.unknown_fields.clear();
     }
 }
@@ -3088,42 +3315,648 @@ impl ::protobuf::Message for ServiceFile {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.service_group.as_ref() {
+        if let Some(v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.encrypted {
+            my_size += 2;
+        };
+        if let Some(v) = self.filename.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        };
+        if let Some(v) = self.body.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(5, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service_group.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.incarnation {
+            os.write_uint64(2, v)?;
+        };
+        if let Some(v) = self.encrypted {
+            os.write_bool(3, v)?;
+        };
+        if let Some(v) = self.filename.as_ref() {
+            os.write_string(4, &v)?;
+        };
+        if let Some(v) = self.body.as_ref() {
+            os.write_bytes(5, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceFile {
+    fn new() -> ServiceFile {
+        ServiceFile::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceFile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    ServiceFile::get_service_group_for_reflect,
+                    ServiceFile::mut_service_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    ServiceFile::get_incarnation_for_reflect,
+                    ServiceFile::mut_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "encrypted",
+                    ServiceFile::get_encrypted_for_reflect,
+                    ServiceFile::mut_encrypted_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "filename",
+                    ServiceFile::get_filename_for_reflect,
+                    ServiceFile::mut_filename_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "body",
+                    ServiceFile::get_body_for_reflect,
+                    ServiceFile::mut_body_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceFile>(
+                    "ServiceFile",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceFile {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_encrypted();
+        self.clear_filename();
+        self.clear_body();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServiceFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServiceFile {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateDirective {
+    // message fields
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    action: ::std::option::Option<UpdateDirective_Action>,
+    pkg: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for UpdateDirective {}
+
+impl UpdateDirective {
+    pub fn new() -> UpdateDirective {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static UpdateDirective {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateDirective> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UpdateDirective,
+        };
+        unsafe {
+            instance.get(UpdateDirective::new)
+        }
+    }
+
+    // optional string service_group = 1;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_service_group_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.service_group
+    }
+
+    fn mut_service_group_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.service_group
+    }
+
+    // optional uint64 incarnation = 2;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    fn get_incarnation_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.incarnation
+    }
+
+    fn mut_incarnation_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.incarnation
+    }
+
+    // optional .UpdateDirective.Action action = 3;
+
+    pub fn clear_action(&mut self) {
+        self.action = ::std::option::Option::None;
+    }
+
+    pub fn has_action(&self) -> bool {
+        self.action.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_action(&mut self, v: UpdateDirective_Action) {
+        self.action = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_action(&self) -> UpdateDirective_Action {
+        self.action.unwrap_or(UpdateDirective_Action::Resume)
+    }
+
+    fn get_action_for_reflect(&self) -> &::std::option::Option<UpdateDirective_Action> {
+        &self.action
+    }
+
+    fn mut_action_for_reflect(&mut self) -> &mut ::std::option::Option<UpdateDirective_Action> {
+        &mut self.action
+    }
+
+    // optional string pkg = 4;
+
+    pub fn clear_pkg(&mut self) {
+        self.pkg.clear();
+    }
+
+    pub fn has_pkg(&self) -> bool {
+        self.pkg.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pkg(&mut self, v: ::std::string::String) {
+        self.pkg = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pkg(&mut self) -> &mut ::std::string::String {
+        if self.pkg.is_none() {
+            self.pkg.set_default();
+        };
+        self.pkg.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_pkg(&mut self) -> ::std::string::String {
+        self.pkg.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_pkg(&self) -> &str {
+        match self.pkg.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_pkg_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.pkg
+    }
+
+    fn mut_pkg_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.pkg
+    }
+}
+
+impl ::protobuf::Message for UpdateDirective {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_enum()?;
+                    self.action = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.pkg)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.service_group.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.incarnation {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.action {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        };
+        if let Some(v) = self.pkg.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service_group.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.incarnation {
+            os.write_uint64(2, v)?;
+        };
+        if let Some(v) = self.action {
+            os.write_enum(3, v.value())?;
+        };
+        if let Some(v) = self.pkg.as_ref() {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for UpdateDirective {
+    fn new() -> UpdateDirective {
+        UpdateDirective::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<UpdateDirective>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "service_group",
+                    UpdateDirective::get_service_group_for_reflect,
+                    UpdateDirective::mut_service_group_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "incarnation",
+                    UpdateDirective::get_incarnation_for_reflect,
+                    UpdateDirective::mut_incarnation_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<UpdateDirective_Action>>(
+                    "action",
+                    UpdateDirective::get_action_for_reflect,
+                    UpdateDirective::mut_action_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "pkg",
+                    UpdateDirective::get_pkg_for_reflect,
+                    UpdateDirective::mut_pkg_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateDirective>(
+                    "UpdateDirective",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for UpdateDirective {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_incarnation();
+        self.clear_action();
+        self.clear_pkg();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateDirective {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateDirective {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum UpdateDirective_Action {
+    Resume = 1,
+    Pause = 2,
+    Pin = 3,
+    UpdateNow = 4,
+}
+
+impl ::protobuf::ProtobufEnum for UpdateDirective_Action {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<UpdateDirective_Action> {
+        match value {
+            1 => ::std::option::Option::Some(UpdateDirective_Action::Resume),
+            2 => ::std::option::Option::Some(UpdateDirective_Action::Pause),
+            3 => ::std::option::Option::Some(UpdateDirective_Action::Pin),
+            4 => ::std::option::Option::Some(UpdateDirective_Action::UpdateNow),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [UpdateDirective_Action] = &[
+            UpdateDirective_Action::Resume,
+            UpdateDirective_Action::Pause,
+            UpdateDirective_Action::Pin,
+            UpdateDirective_Action::UpdateNow,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<UpdateDirective_Action>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("UpdateDirective_Action", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for UpdateDirective_Action {
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateDirective_Action {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Departure {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Departure {}
+
+impl Departure {
+    pub fn new() -> Departure {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Departure {
+        static mut instance: ::protobuf::lazy::Lazy<Departure> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Departure,
+        };
+        unsafe {
+            instance.get(Departure::new)
+        }
+    }
+
+    // optional string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+}
+
+impl ::protobuf::Message for Departure {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.member_id.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         };
-        if let Some(v) = self.incarnation {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        };
-        if let Some(v) = self.encrypted {
-            my_size += 2;
-        };
-        if let Some(v) = self.filename.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
-        };
-        if let Some(v) = self.body.as_ref() {
-            my_size += ::protobuf::rt::bytes_size(5, &v);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.service_group.as_ref() {
+        if let Some(v) = self.member_id.as_ref() {
             os.write_string(1, &v)?;
         };
-        if let Some(v) = self.incarnation {
-            os.write_uint64(2, v)?;
-        };
-        if let Some(v) = self.encrypted {
-            os.write_bool(3, v)?;
-        };
-        if let Some(v) = self.filename.as_ref() {
-            os.write_string(4, &v)?;
-        };
-        if let Some(v) = self.body.as_ref() {
-            os.write_bytes(5, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3155,12 +3988,12 @@ impl ::protobuf::Message for ServiceFile {
     }
 }
 
-impl ::protobuf::MessageStatic for ServiceFile {
-    fn new() -> ServiceFile {
-        ServiceFile::new()
+impl ::protobuf::MessageStatic for Departure {
+    fn new() -> Departure {
+        Departure::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ServiceFile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Departure>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3169,32 +4002,12 @@ impl ::protobuf::MessageStatic for ServiceFile {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "service_group",
-                    ServiceFile::get_service_group_for_reflect,
-                    ServiceFile::mut_service_group_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "incarnation",
-                    ServiceFile::get_incarnation_for_reflect,
-                    ServiceFile::mut_incarnation_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "encrypted",
-                    ServiceFile::get_encrypted_for_reflect,
-                    ServiceFile::mut_encrypted_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "filename",
-                    ServiceFile::get_filename_for_reflect,
-                    ServiceFile::mut_filename_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "body",
-                    ServiceFile::get_body_for_reflect,
-                    ServiceFile::mut_body_for_reflect,
+                    "member_id",
+                    Departure::get_member_id_for_reflect,
+                    Departure::mut_member_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ServiceFile>(
-                    "ServiceFile",
+                ::protobuf::reflect::MessageDescriptor::new::<Departure>(
+                    "Departure",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3203,24 +4016,20 @@ impl ::protobuf::MessageStatic for ServiceFile {
     }
 }
 
-impl ::protobuf::Clear for ServiceFile {
+impl ::protobuf::Clear for Departure {
     fn clear(&mut self) {
-        self.clear_service_group();
-        self.clear_incarnation();
-        self.clear_encrypted();
-        self.clear_filename();
-        self.clear_body();
+        self.clear_member_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ServiceFile {
+impl ::std::fmt::Debug for Departure {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ServiceFile {
+impl ::protobuf::reflect::ProtobufValue for Departure {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -3739,6 +4548,8 @@ pub struct Rumor {
     field_type: ::std::option::Option<Rumor_Type>,
     tag: ::protobuf::RepeatedField<::std::string::String>,
     from_id: ::protobuf::SingularField<::std::string::String>,
+    certificate: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // message oneof groups
     payload: ::std::option::Option<Rumor_oneof_payload>,
     // special fields
@@ -3756,6 +4567,8 @@ pub enum Rumor_oneof_payload {
     service_config(ServiceConfig),
     service_file(ServiceFile),
     election(Election),
+    update_directive(UpdateDirective),
+    departure(Departure),
 }
 
 impl Rumor {
@@ -4121,6 +4934,192 @@ impl Rumor {
             _ => Election::default_instance(),
         }
     }
+
+    // optional .UpdateDirective update_directive = 9;
+
+    pub fn clear_update_directive(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_update_directive(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::update_directive(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update_directive(&mut self, v: UpdateDirective) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::update_directive(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_update_directive(&mut self) -> &mut UpdateDirective {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::update_directive(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::update_directive(UpdateDirective::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::update_directive(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_update_directive(&mut self) -> UpdateDirective {
+        if self.has_update_directive() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::update_directive(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            UpdateDirective::new()
+        }
+    }
+
+    pub fn get_update_directive(&self) -> &UpdateDirective {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::update_directive(ref v)) => v,
+            _ => UpdateDirective::default_instance(),
+        }
+    }
+
+    // optional .Departure departure = 10;
+
+    pub fn clear_departure(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_departure(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_departure(&mut self, v: Departure) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_departure(&mut self) -> &mut Departure {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::departure(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(Departure::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_departure(&mut self) -> Departure {
+        if self.has_departure() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::departure(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Departure::new()
+        }
+    }
+
+    pub fn get_departure(&self) -> &Departure {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::departure(ref v)) => v,
+            _ => Departure::default_instance(),
+        }
+    }
+
+    // optional bytes certificate = 11;
+
+    pub fn clear_certificate(&mut self) {
+        self.certificate.clear();
+    }
+
+    pub fn has_certificate(&self) -> bool {
+        self.certificate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_certificate(&mut self, v: ::std::vec::Vec<u8>) {
+        self.certificate = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_certificate(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.certificate.is_none() {
+            self.certificate.set_default();
+        };
+        self.certificate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_certificate(&mut self) -> ::std::vec::Vec<u8> {
+        self.certificate.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_certificate(&self) -> &[u8] {
+        match self.certificate.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_certificate_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.certificate
+    }
+
+    fn mut_certificate_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.certificate
+    }
+
+    // optional bytes signature = 12;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        };
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_signature_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.signature
+    }
+
+    fn mut_signature_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.signature
+    }
 }
 
 impl ::protobuf::Message for Rumor {
@@ -4178,6 +5177,24 @@ impl ::protobuf::Message for Rumor {
                     };
                     self.payload = ::std::option::Option::Some(Rumor_oneof_payload::election(is.read_message()?));
                 },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::update_directive(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(is.read_message()?));
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.certificate)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4199,6 +5216,12 @@ impl ::protobuf::Message for Rumor {
         if let Some(v) = self.from_id.as_ref() {
             my_size += ::protobuf::rt::string_size(3, &v);
         };
+        if let Some(v) = self.certificate.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(11, &v);
+        };
+        if let Some(v) = self.signature.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(12, &v);
+        };
         if let ::std::option::Option::Some(ref v) = self.payload {
             match v {
                 &Rumor_oneof_payload::member(ref v) => {
@@ -4221,6 +5244,14 @@ impl ::protobuf::Message for Rumor {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Rumor_oneof_payload::update_directive(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Rumor_oneof_payload::departure(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -4238,6 +5269,12 @@ impl ::protobuf::Message for Rumor {
         if let Some(v) = self.from_id.as_ref() {
             os.write_string(3, &v)?;
         };
+        if let Some(v) = self.certificate.as_ref() {
+            os.write_bytes(11, &v)?;
+        };
+        if let Some(v) = self.signature.as_ref() {
+            os.write_bytes(12, &v)?;
+        };
         if let ::std::option::Option::Some(ref v) = self.payload {
             match v {
                 &Rumor_oneof_payload::member(ref v) => {
@@ -4265,6 +5302,16 @@ impl ::protobuf::Message for Rumor {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &Rumor_oneof_payload::update_directive(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Rumor_oneof_payload::departure(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -4351,6 +5398,26 @@ impl ::protobuf::MessageStatic for Rumor {
                     Rumor::has_election,
                     Rumor::get_election,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, UpdateDirective>(
+                    "update_directive",
+                    Rumor::has_update_directive,
+                    Rumor::get_update_directive,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Departure>(
+                    "departure",
+                    Rumor::has_departure,
+                    Rumor::get_departure,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "certificate",
+                    Rumor::get_certificate_for_reflect,
+                    Rumor::mut_certificate_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "signature",
+                    Rumor::get_signature_for_reflect,
+                    Rumor::mut_signature_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
                     "Rumor",
                     fields,
@@ -4371,6 +5438,10 @@ impl ::protobuf::Clear for Rumor {
         self.clear_service_config();
         self.clear_service_file();
         self.clear_election();
+        self.clear_update_directive();
+        self.clear_departure();
+        self.clear_certificate();
+        self.clear_signature();
         self.unknown_fields.clear();
     }
 }
@@ -4397,6 +5468,8 @@ pub enum Rumor_Type {
     Fake = 6,
     Fake2 = 7,
     ElectionUpdate = 8,
+    UpdateDirective = 9,
+    Departure = 10,
 }
 
 impl ::protobuf::ProtobufEnum for Rumor_Type {
@@ -4414,6 +5487,8 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             6 => ::std::option::Option::Some(Rumor_Type::Fake),
             7 => ::std::option::Option::Some(Rumor_Type::Fake2),
             8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
+            9 => ::std::option::Option::Some(Rumor_Type::UpdateDirective),
+            10 => ::std::option::Option::Some(Rumor_Type::Departure),
             _ => ::std::option::Option::None
         }
     }
@@ -4428,6 +5503,8 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             Rumor_Type::Fake,
             Rumor_Type::Fake2,
             Rumor_Type::ElectionUpdate,
+            Rumor_Type::UpdateDirective,
+            Rumor_Type::Departure,
         ];
         values
     }
@@ -4460,6 +5537,7 @@ pub struct Wire {
     encrypted: ::std::option::Option<bool>,
     nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    certificate: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -4597,6 +5675,50 @@ impl Wire {
     fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.payload
     }
+
+    // optional bytes certificate = 4;
+
+    pub fn clear_certificate(&mut self) {
+        self.certificate.clear();
+    }
+
+    pub fn has_certificate(&self) -> bool {
+        self.certificate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_certificate(&mut self, v: ::std::vec::Vec<u8>) {
+        self.certificate = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_certificate(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.certificate.is_none() {
+            self.certificate.set_default();
+        };
+        self.certificate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_certificate(&mut self) -> ::std::vec::Vec<u8> {
+        self.certificate.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_certificate(&self) -> &[u8] {
+        match self.certificate.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_certificate_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.certificate
+    }
+
+    fn mut_certificate_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.certificate
+    }
 }
 
 impl ::protobuf::Message for Wire {
@@ -4621,6 +5743,9 @@ impl ::protobuf::Message for Wire {
                 3 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.certificate)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4642,6 +5767,9 @@ impl ::protobuf::Message for Wire {
         if let Some(v) = self.payload.as_ref() {
             my_size += ::protobuf::rt::bytes_size(3, &v);
         };
+        if let Some(v) = self.certificate.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(4, &v);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4657,6 +5785,9 @@ impl ::protobuf::Message for Wire {
         if let Some(v) = self.payload.as_ref() {
             os.write_bytes(3, &v)?;
         };
+        if let Some(v) = self.certificate.as_ref() {
+            os.write_bytes(4, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4716,6 +5847,11 @@ impl ::protobuf::MessageStatic for Wire {
                     Wire::get_payload_for_reflect,
                     Wire::mut_payload_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "certificate",
+                    Wire::get_certificate_for_reflect,
+                    Wire::mut_certificate_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Wire>(
                     "Wire",
                     fields,
@@ -4731,6 +5867,7 @@ impl ::protobuf::Clear for Wire {
         self.clear_encrypted();
         self.clear_nonce();
         self.clear_payload();
+        self.clear_certificate();
         self.unknown_fields.clear();
     }
 }
@@ -4784,7 +5921,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x22, 0x2f, 0x0a, 0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c,
     0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54,
     0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10,
-    0x03, 0x22, 0xf5, 0x01, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b,
+    0x03, 0x22, 0xb4, 0x02, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b,
     0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
     0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73,
     0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01,
@@ -4796,391 +5933,430 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
     0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
     0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x09, 0x52,
-    0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x22, 0x31, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
-    0x12, 0x0b, 0x0a, 0x07, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a,
-    0x08, 0x4e, 0x6f, 0x51, 0x75, 0x6f, 0x72, 0x75, 0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46,
-    0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x10, 0x03, 0x22, 0xc5, 0x01, 0x0a, 0x07, 0x53, 0x65,
-    0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f,
-    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
-    0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72,
-    0x6f, 0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72,
-    0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e,
-    0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x69,
-    0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b,
-    0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x70,
-    0x6b, 0x67, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x70, 0x6b, 0x67, 0x12, 0x10, 0x0a,
-    0x03, 0x63, 0x66, 0x67, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x63, 0x66, 0x67, 0x12,
-    0x10, 0x0a, 0x03, 0x73, 0x79, 0x73, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x73, 0x79,
-    0x73, 0x22, 0x8c, 0x01, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e,
-    0x66, 0x69, 0x67, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67,
-    0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76,
-    0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61,
-    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69,
-    0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e,
-    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65,
-    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66,
-    0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
-    0x22, 0xa2, 0x01, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65,
+    0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x12, 0x21, 0x0a, 0x0c, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x64, 0x5f, 0x70, 0x6b, 0x67, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x61, 0x70,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x50, 0x6b, 0x67, 0x12, 0x1a, 0x0a, 0x08, 0x61, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x61, 0x6c, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x61, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x61, 0x6c, 0x22, 0x31, 0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12,
+    0x0b, 0x0a, 0x07, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08,
+    0x4e, 0x6f, 0x51, 0x75, 0x6f, 0x72, 0x75, 0x6d, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x69,
+    0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x10, 0x03, 0x22, 0x8d, 0x02, 0x0a, 0x07, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49,
+    0x64, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f,
+    0x75, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63,
+    0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x69, 0x74,
+    0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x69,
+    0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x70, 0x6b,
+    0x67, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x70, 0x6b, 0x67, 0x12, 0x10, 0x0a, 0x03,
+    0x63, 0x66, 0x67, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x63, 0x66, 0x67, 0x12, 0x10,
+    0x0a, 0x03, 0x73, 0x79, 0x73, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x73, 0x79, 0x73,
+    0x12, 0x23, 0x0a, 0x0d, 0x64, 0x65, 0x73, 0x69, 0x72, 0x65, 0x64, 0x5f, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x64, 0x65, 0x73, 0x69, 0x72, 0x65, 0x64,
+    0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x5f,
+    0x63, 0x68, 0x65, 0x63, 0x6b, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x68, 0x65, 0x61,
+    0x6c, 0x74, 0x68, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x22, 0x8c, 0x01, 0x0a, 0x0d, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12,
+    0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12,
+    0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x22, 0xa2, 0x01, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c,
+    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b,
+    0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c,
+    0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x1a, 0x0a, 0x08,
+    0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08,
+    0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x22, 0xd4, 0x01, 0x0a,
+    0x0f, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65,
     0x12, 0x23, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72, 0x6f, 0x75,
     0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
     0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61, 0x72, 0x6e, 0x61,
     0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x61,
-    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79,
-    0x70, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72,
-    0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d,
-    0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x66, 0x69, 0x6c, 0x65, 0x6e, 0x61, 0x6d,
-    0x65, 0x12, 0x12, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52,
-    0x04, 0x62, 0x6f, 0x64, 0x79, 0x22, 0xe3, 0x01, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1e,
-    0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x53,
-    0x77, 0x69, 0x6d, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1b,
-    0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x50,
-    0x69, 0x6e, 0x67, 0x48, 0x00, 0x52, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x12, 0x18, 0x0a, 0x03, 0x61,
-    0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x04, 0x2e, 0x41, 0x63, 0x6b, 0x48, 0x00,
-    0x52, 0x03, 0x61, 0x63, 0x6b, 0x12, 0x24, 0x0a, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71,
-    0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71,
-    0x48, 0x00, 0x52, 0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71, 0x12, 0x2b, 0x0a, 0x0a, 0x6d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32,
-    0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x52, 0x0a, 0x6d, 0x65,
-    0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x22, 0x26, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65,
-    0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43,
-    0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03,
-    0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0xbc, 0x03, 0x0a, 0x05,
-    0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x1f, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65,
-    0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x02, 0x20,
-    0x03, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61, 0x67, 0x12, 0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d,
-    0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x49,
-    0x64, 0x12, 0x25, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x48, 0x00,
-    0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x24, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76,
-    0x69, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76,
-    0x69, 0x63, 0x65, 0x48, 0x00, 0x52, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x37,
-    0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
-    0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00, 0x52, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
-    0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x31, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e,
-    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x73,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x27, 0x0a, 0x08, 0x65, 0x6c,
-    0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45,
-    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74,
-    0x69, 0x6f, 0x6e, 0x22, 0x7a, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x4d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e,
-    0x66, 0x69, 0x67, 0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
-    0x46, 0x69, 0x6c, 0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61, 0x6b, 0x65, 0x10, 0x06,
-    0x12, 0x09, 0x0a, 0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12, 0x12, 0x0a, 0x0e, 0x45,
-    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x10, 0x08, 0x42,
-    0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x22, 0x54, 0x0a, 0x04, 0x57, 0x69,
-    0x72, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64,
-    0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
-    0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61,
-    0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64,
-    0x4a, 0xce, 0x25, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x6e, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c,
-    0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x09,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x03, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03,
-    0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x04, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x03, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x06, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x06, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d,
-    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x11, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
-    0x03, 0x12, 0x03, 0x07, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03,
-    0x08, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x05, 0x08, 0x12, 0x03, 0x08, 0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x05, 0x07, 0x12, 0x03, 0x08, 0x2a, 0x2f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b,
-    0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x0c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02,
-    0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
-    0x04, 0x10, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x10, 0x08,
-    0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x11, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x12, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x03, 0x12, 0x04, 0x15, 0x00, 0x18, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
-    0x15, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01,
-    0x12, 0x03, 0x17, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03,
-    0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x12, 0x18, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x04, 0x12, 0x04, 0x1a, 0x00, 0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01,
-    0x12, 0x03, 0x1a, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x03, 0x1b,
-    0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x07, 0x0d,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x10, 0x1a, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x10, 0x15, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1b, 0x18, 0x19, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x27, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x22, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1b, 0x25, 0x26, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x28, 0x36, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x28, 0x31, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x34, 0x35, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x06, 0x12, 0x03, 0x1d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x1d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d,
-    0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x1e, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x21,
-    0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x21, 0x08, 0x10, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00, 0x12, 0x03, 0x22, 0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x22, 0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x22, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x02, 0x12, 0x03, 0x22, 0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00,
-    0x02, 0x02, 0x12, 0x03, 0x22, 0x2b, 0x38, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x22, 0x2b, 0x33, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x02, 0x12, 0x03, 0x22, 0x36, 0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12,
-    0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x24,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x12, 0x1b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x25, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x01, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05,
-    0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x25, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x25, 0x22,
-    0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x26, 0x02, 0x1b, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x26, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x26, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03,
-    0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x27, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x27, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x05, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x06, 0x12,
-    0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x28,
-    0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x28, 0x1b, 0x1c,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x05, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x05, 0x01, 0x12, 0x03, 0x29, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03,
-    0x12, 0x03, 0x29, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2c, 0x00, 0x34,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x2c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x2d, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d,
-    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x24, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x2e, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12,
-    0x03, 0x2f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2f,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x30, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x03, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x30, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x30, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x30, 0x1e,
-    0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x04, 0x01, 0x12, 0x03, 0x31, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04,
-    0x03, 0x12, 0x03, 0x31, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03,
-    0x32, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03, 0x32, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x10, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x32, 0x11, 0x14, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x32, 0x17, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x06, 0x02, 0x06, 0x12, 0x03, 0x33, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06,
-    0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05, 0x12,
-    0x03, 0x33, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x33,
-    0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x33, 0x17, 0x19,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x36, 0x00, 0x3b, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x07, 0x01, 0x12, 0x03, 0x36, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00,
-    0x12, 0x03, 0x37, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x37, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x37, 0x12, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x37, 0x22, 0x23, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x38, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x01, 0x04, 0x12, 0x03, 0x38, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
-    0x05, 0x12, 0x03, 0x38, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x38, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x39, 0x02, 0x1e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x39, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x39, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12,
-    0x03, 0x3a, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x3a,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3a, 0x11, 0x17, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3a, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x08, 0x12, 0x04, 0x3d, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12,
-    0x03, 0x3d, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02,
-    0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3e, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3e, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02,
-    0x01, 0x12, 0x03, 0x3f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x3f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3f,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3f, 0x12, 0x1d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3f, 0x20, 0x21, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x40, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x40, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x40, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x40, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x40, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x41, 0x02, 0x1f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x41, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x41, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04,
-    0x12, 0x03, 0x42, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03,
-    0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x42, 0x0b,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x42, 0x11, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x42, 0x18, 0x19, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x09, 0x12, 0x04, 0x45, 0x00, 0x50, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01,
-    0x12, 0x03, 0x45, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x04, 0x00, 0x12, 0x03, 0x46,
-    0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03, 0x46, 0x07, 0x0b,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x46, 0x0e, 0x17, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x0e, 0x12, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x46, 0x15, 0x16, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x46, 0x18, 0x20, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x46, 0x18, 0x1b, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x46, 0x1e, 0x1f, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x46, 0x21, 0x2d, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x46, 0x21, 0x28, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x09, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x46, 0x2b, 0x2c, 0x0a, 0x33, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x49, 0x02, 0x19, 0x1a, 0x26, 0x20, 0x49, 0x64, 0x65,
-    0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x69,
-    0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x49, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x03, 0x49, 0x0b, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x49, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x49, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09,
-    0x08, 0x00, 0x12, 0x04, 0x4a, 0x02, 0x4e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00,
-    0x01, 0x12, 0x03, 0x4a, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03,
-    0x4b, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b, 0x04,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4b, 0x09, 0x0d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4b, 0x10, 0x11, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x4c, 0x04, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x02, 0x06, 0x12, 0x03, 0x4c, 0x04, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x4c, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x4c, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x4d, 0x04,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4d, 0x04, 0x0b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4d, 0x0c, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4d, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x09, 0x02, 0x04, 0x12, 0x03, 0x4f, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x4f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12,
-    0x03, 0x4f, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4f,
-    0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x4f, 0x23, 0x24,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x52, 0x00, 0x68, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x0a, 0x01, 0x12, 0x03, 0x52, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x04, 0x00,
-    0x12, 0x04, 0x53, 0x02, 0x5c, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04, 0x00, 0x01, 0x12,
-    0x03, 0x53, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x54, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x54, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
-    0x54, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x55,
-    0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55,
-    0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x55,
-    0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x56, 0x04,
-    0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x56, 0x04,
-    0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x56, 0x0f,
-    0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x57, 0x04, 0x16,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x57, 0x04, 0x11,
-    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x57, 0x14, 0x15,
-    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x58, 0x04, 0x14, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x58, 0x04, 0x0f, 0x0a,
-    0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x58, 0x12, 0x13, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x59, 0x04, 0x0d, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x59, 0x04, 0x08, 0x0a, 0x0e,
-    0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x59, 0x0b, 0x0c, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x5a, 0x04, 0x0e, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5a, 0x04, 0x09, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x5a, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a,
-    0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x5b, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5b, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x0a, 0x04, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x5b, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x5e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x5e, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e, 0x17,
-    0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x5f, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03,
-    0x60, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x60, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x60, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x60, 0x12, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x60, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x0a, 0x08, 0x00, 0x12, 0x04, 0x61, 0x02, 0x67, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x08,
-    0x00, 0x01, 0x12, 0x03, 0x61, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12,
-    0x03, 0x62, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06, 0x12, 0x03, 0x62,
-    0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x62, 0x0f, 0x15,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x62, 0x18, 0x19, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x63, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0a, 0x02, 0x04, 0x06, 0x12, 0x03, 0x63, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x63, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x63, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x03, 0x64,
-    0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03, 0x64, 0x04, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x64, 0x12, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x03, 0x64, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0a, 0x02, 0x06, 0x12, 0x03, 0x65, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x06, 0x06, 0x12, 0x03, 0x65, 0x04, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01,
-    0x12, 0x03, 0x65, 0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x03,
-    0x65, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x03, 0x66, 0x04, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x06, 0x12, 0x03, 0x66, 0x04, 0x0c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x01, 0x12, 0x03, 0x66, 0x0d, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x0a, 0x02, 0x07, 0x03, 0x12, 0x03, 0x66, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b,
-    0x12, 0x04, 0x6a, 0x00, 0x6e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x6a,
-    0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6b, 0x02, 0x1e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6b, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x6b, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12,
-    0x03, 0x6c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6c,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6c, 0x0b, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6c, 0x11, 0x16, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x6d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x6d, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x6d, 0x11, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6d, 0x1b,
-    0x1c,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x2f, 0x0a, 0x06, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65,
+    0x44, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x2e, 0x41, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x52, 0x06, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x10, 0x0a, 0x03, 0x70, 0x6b, 0x67, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x70, 0x6b, 0x67, 0x22, 0x37, 0x0a, 0x06, 0x41, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x0a, 0x0a, 0x06, 0x52, 0x65, 0x73, 0x75, 0x6d, 0x65, 0x10, 0x01,
+    0x12, 0x09, 0x0a, 0x05, 0x50, 0x61, 0x75, 0x73, 0x65, 0x10, 0x02, 0x12, 0x07, 0x0a, 0x03, 0x50,
+    0x69, 0x6e, 0x10, 0x03, 0x12, 0x0d, 0x0a, 0x09, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x6f,
+    0x77, 0x10, 0x04, 0x22, 0x28, 0x0a, 0x09, 0x44, 0x65, 0x70, 0x61, 0x72, 0x74, 0x75, 0x72, 0x65,
+    0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x08, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x49, 0x64, 0x22, 0xe3, 0x01,
+    0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1e, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x53, 0x77, 0x69, 0x6d, 0x2e, 0x54, 0x79, 0x70, 0x65,
+    0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1b, 0x0a, 0x04, 0x70, 0x69, 0x6e, 0x67, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x50, 0x69, 0x6e, 0x67, 0x48, 0x00, 0x52, 0x04, 0x70,
+    0x69, 0x6e, 0x67, 0x12, 0x18, 0x0a, 0x03, 0x61, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x04, 0x2e, 0x41, 0x63, 0x6b, 0x48, 0x00, 0x52, 0x03, 0x61, 0x63, 0x6b, 0x12, 0x24, 0x0a,
+    0x07, 0x70, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x71, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x08,
+    0x2e, 0x50, 0x69, 0x6e, 0x67, 0x52, 0x65, 0x71, 0x48, 0x00, 0x52, 0x07, 0x70, 0x69, 0x6e, 0x67,
+    0x72, 0x65, 0x71, 0x12, 0x2b, 0x0a, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69,
+    0x70, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x73, 0x68, 0x69, 0x70, 0x52, 0x0a, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70,
+    0x22, 0x26, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x49, 0x4e, 0x47,
+    0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43, 0x4b, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x50,
+    0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c,
+    0x6f, 0x61, 0x64, 0x22, 0x8c, 0x05, 0x0a, 0x05, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x12, 0x1f, 0x0a,
+    0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0b, 0x2e, 0x52, 0x75,
+    0x6d, 0x6f, 0x72, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x10,
+    0x0a, 0x03, 0x74, 0x61, 0x67, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x03, 0x74, 0x61, 0x67,
+    0x12, 0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x49, 0x64, 0x12, 0x25, 0x0a, 0x06, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x4d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x48, 0x00, 0x52, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x12, 0x24, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x08, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x48, 0x00, 0x52, 0x07, 0x73,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x37, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e,
+    0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x00,
+    0x52, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12,
+    0x31, 0x0a, 0x0c, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18,
+    0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46,
+    0x69, 0x6c, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x46, 0x69,
+    0x6c, 0x65, 0x12, 0x27, 0x0a, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48,
+    0x00, 0x52, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x3d, 0x0a, 0x10, 0x75,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x5f, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x18,
+    0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x44, 0x69,
+    0x72, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x48, 0x00, 0x52, 0x0f, 0x75, 0x70, 0x64, 0x61, 0x74,
+    0x65, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x12, 0x2a, 0x0a, 0x09, 0x64, 0x65,
+    0x70, 0x61, 0x72, 0x74, 0x75, 0x72, 0x65, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e,
+    0x44, 0x65, 0x70, 0x61, 0x72, 0x74, 0x75, 0x72, 0x65, 0x48, 0x00, 0x52, 0x09, 0x64, 0x65, 0x70,
+    0x61, 0x72, 0x74, 0x75, 0x72, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x63, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e,
+    0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67,
+    0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x9e, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12,
+    0x0a, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x10, 0x04, 0x12, 0x0f, 0x0a, 0x0b, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x46, 0x69, 0x6c, 0x65, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x61,
+    0x6b, 0x65, 0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x46, 0x61, 0x6b, 0x65, 0x32, 0x10, 0x07, 0x12,
+    0x12, 0x0a, 0x0e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74,
+    0x65, 0x10, 0x08, 0x12, 0x13, 0x0a, 0x0f, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x44, 0x69, 0x72,
+    0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x10, 0x09, 0x12, 0x0d, 0x0a, 0x09, 0x44, 0x65, 0x70, 0x61,
+    0x72, 0x74, 0x75, 0x72, 0x65, 0x10, 0x0a, 0x42, 0x09, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f,
+    0x61, 0x64, 0x22, 0x76, 0x0a, 0x04, 0x57, 0x69, 0x72, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x6e,
+    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x65,
+    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12, 0x18,
+    0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x63, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x63,
+    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x4a, 0xce, 0x25, 0x0a, 0x06, 0x12,
+    0x04, 0x00, 0x00, 0x6e, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x02, 0x00, 0x09, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x02, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12,
+    0x03, 0x03, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x03,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x03, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x12, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x03, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x01, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x04, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x04, 0x20,
+    0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x05, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
+    0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x06, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x11, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
+    0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12,
+    0x03, 0x07, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07,
+    0x11, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x07, 0x1f, 0x20,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x31, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x05, 0x01, 0x12, 0x03, 0x08, 0x10, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03,
+    0x12, 0x03, 0x08, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x08, 0x12, 0x03,
+    0x08, 0x1f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x07, 0x12, 0x03, 0x08, 0x2a,
+    0x2f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x00, 0x12, 0x03, 0x0c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0c,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x19, 0x1a, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x01, 0x06, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x0d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x0d, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x10, 0x00, 0x13, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x10, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11,
+    0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x19, 0x1a,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x12, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x12, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x15, 0x00, 0x18,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x15, 0x08, 0x0f, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
+    0x06, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x16, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16,
+    0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x17, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x17, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x1a,
+    0x00, 0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x12, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x03, 0x1b, 0x02, 0x38, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x10, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x10, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1b, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x27, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x1b, 0x22, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00,
+    0x02, 0x01, 0x02, 0x12, 0x03, 0x1b, 0x25, 0x26, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x1b, 0x28, 0x36, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x1b, 0x28, 0x31, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x1b, 0x34, 0x35, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12,
+    0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1d,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x01, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x1e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1e, 0x1b,
+    0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x21, 0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x21, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x04,
+    0x00, 0x12, 0x03, 0x22, 0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x22, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
+    0x22, 0x10, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x22, 0x10, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x22, 0x1a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x22,
+    0x1d, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22,
+    0x1d, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x22,
+    0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x22, 0x2b,
+    0x38, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x2b,
+    0x33, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x22, 0x36,
+    0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x24, 0x02, 0x20, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x24, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x24, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03,
+    0x25, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x25, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x25, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x25, 0x12, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x25, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x05, 0x02, 0x02, 0x12, 0x03, 0x26, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x26,
+    0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x26, 0x19, 0x1a,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x27, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x28,
+    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x06, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x28, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x28, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x04,
+    0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03,
+    0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x29, 0x12,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x29, 0x1a, 0x1b, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x2c, 0x00, 0x34, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x06, 0x01, 0x12, 0x03, 0x2c, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12,
+    0x03, 0x2d, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2d,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x01, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x2e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x22,
+    0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x22, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x2f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03,
+    0x30, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x30, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x30, 0x10, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x30, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x06, 0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04,
+    0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x05, 0x12,
+    0x03, 0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x31,
+    0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x31, 0x18, 0x19,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03, 0x32, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x05, 0x01, 0x12, 0x03, 0x32, 0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03,
+    0x12, 0x03, 0x32, 0x17, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x33,
+    0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05, 0x12, 0x03, 0x33, 0x0b, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x33, 0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x33, 0x17, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07,
+    0x12, 0x04, 0x36, 0x00, 0x3b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x36,
+    0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x37, 0x02, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x37, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x37, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x37, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12,
+    0x03, 0x38, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x38,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x38, 0x12, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x39, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x39, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x39, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x39, 0x1c,
+    0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x3a, 0x02, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x3a, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x3a, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x3d, 0x00,
+    0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x3d, 0x08, 0x13, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x3e, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x3e, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x3f, 0x02, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3f, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x3f, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02,
+    0x12, 0x03, 0x40, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x40, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x40, 0x0b,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x40, 0x10, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x40, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x41, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x03, 0x04, 0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x41, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x41,
+    0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x42, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x42, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x42, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x45,
+    0x00, 0x50, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x45, 0x08, 0x0c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x09, 0x04, 0x00, 0x12, 0x03, 0x46, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x04, 0x00, 0x01, 0x12, 0x03, 0x46, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x46, 0x0e, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x0e, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x46, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x46, 0x18, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x46, 0x18, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00,
+    0x02, 0x01, 0x02, 0x12, 0x03, 0x46, 0x1e, 0x1f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x46, 0x21, 0x2d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x46, 0x21, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x09, 0x04, 0x00, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x46, 0x2b, 0x2c, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12,
+    0x03, 0x49, 0x02, 0x19, 0x1a, 0x26, 0x20, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65,
+    0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73,
+    0x20, 0x66, 0x69, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x49, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x49, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x49, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x49, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x08, 0x00, 0x12, 0x04, 0x4a, 0x02,
+    0x4e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x0f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x4b, 0x04, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b, 0x04, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4b, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x4b, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12,
+    0x03, 0x4c, 0x04, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x06, 0x12, 0x03, 0x4c,
+    0x04, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x0b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4c, 0x0e, 0x0f, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x4d, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x03, 0x06, 0x12, 0x03, 0x4d, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x4d, 0x0c, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x4d, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x04, 0x12, 0x03, 0x4f,
+    0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x04, 0x12, 0x03, 0x4f, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12, 0x03, 0x4f, 0x0b, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4f, 0x16, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x4f, 0x23, 0x24, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a,
+    0x12, 0x04, 0x52, 0x00, 0x68, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x52,
+    0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x04, 0x00, 0x12, 0x04, 0x53, 0x02, 0x5c, 0x03,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x04, 0x00, 0x01, 0x12, 0x03, 0x53, 0x07, 0x0b, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x54, 0x04, 0x0f, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x54, 0x04, 0x0a, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x54, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x55, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x0a, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x55, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x0a, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x56, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x0a, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x56, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x0a, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x56, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x0a, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x57, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a,
+    0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x57, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a,
+    0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x57, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a,
+    0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x58, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04,
+    0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x58, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04,
+    0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x58, 0x12, 0x13, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04,
+    0x00, 0x02, 0x05, 0x12, 0x03, 0x59, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x59, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00,
+    0x02, 0x05, 0x02, 0x12, 0x03, 0x59, 0x0b, 0x0c, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00,
+    0x02, 0x06, 0x12, 0x03, 0x5a, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02,
+    0x06, 0x01, 0x12, 0x03, 0x5a, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02,
+    0x06, 0x02, 0x12, 0x03, 0x5a, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x04, 0x00, 0x02,
+    0x07, 0x12, 0x03, 0x5b, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07,
+    0x01, 0x12, 0x03, 0x5b, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x0a, 0x04, 0x00, 0x02, 0x07,
+    0x02, 0x12, 0x03, 0x5b, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03,
+    0x5e, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5e, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x03, 0x5e, 0x0b, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x10, 0x14, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5e, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0a, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x5f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f,
+    0x12, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5f, 0x18, 0x19,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x60, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x60, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x60, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x60, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x08, 0x00, 0x12, 0x04, 0x61,
+    0x02, 0x67, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x08, 0x00, 0x01, 0x12, 0x03, 0x61, 0x08,
+    0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12, 0x03, 0x62, 0x04, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06, 0x12, 0x03, 0x62, 0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x62, 0x0f, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x62, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04,
+    0x12, 0x03, 0x63, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x03,
+    0x63, 0x04, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x63, 0x0c,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x63, 0x16, 0x17, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x03, 0x64, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03, 0x64, 0x04, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x64, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x64, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06, 0x12, 0x03,
+    0x65, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x06, 0x12, 0x03, 0x65, 0x04,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x03, 0x65, 0x10, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x03, 0x65, 0x1f, 0x20, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x03, 0x66, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x07, 0x06, 0x12, 0x03, 0x66, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07,
+    0x01, 0x12, 0x03, 0x66, 0x0d, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x03, 0x12,
+    0x03, 0x66, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x6a, 0x00, 0x6e, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x6a, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x6b, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x6b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x6b, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6b, 0x1c,
+    0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x6c, 0x02, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6c, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x6c, 0x11, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x6c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03,
+    0x6d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6d, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6d, 0x0b, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x11, 0x18, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6d, 0x1b, 0x1c,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -0,0 +1,178 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detects when this member is on the minority side of a network partition.
+//!
+//! Persistent members are never forgotten by the ring, which makes them a stable yardstick for
+//! how much of the ring we can still reach. We count the persistent members, including ourselves
+//! if we are one, and how many of them are not confirmed dead. When we reach half of them or
+//! fewer, we are in a minority partition; both halves of an even split count as a minority, so
+//! that neither side believes it is authoritative.
+
+use std::result;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use time::SteadyTime;
+
+/// How much of the persistent members of the ring this member can reach.
+#[derive(Debug)]
+pub struct Partition {
+    state: RwLock<State>,
+    step_down: AtomicBool,
+}
+
+#[derive(Debug)]
+struct State {
+    persistent: usize,
+    reachable: usize,
+    minority_since: Option<SteadyTime>,
+}
+
+impl Partition {
+    /// Create a new Partition, which believes every persistent member is reachable until it is
+    /// told otherwise.
+    pub fn new() -> Partition {
+        Partition {
+            state: RwLock::new(State {
+                                   persistent: 0,
+                                   reachable: 0,
+                                   minority_since: None,
+                               }),
+            step_down: AtomicBool::new(false),
+        }
+    }
+
+    /// Record how many persistent members there are, and how many of them are reachable. Returns
+    /// the new minority status if it changed.
+    pub fn update(&self, persistent: usize, reachable: usize) -> Option<bool> {
+        let mut state = self.state.write().expect("Partition lock poisoned");
+        state.persistent = persistent;
+        state.reachable = reachable;
+        let minority = persistent > 0 && reachable * 2 <= persistent;
+        match (minority, state.minority_since.is_some()) {
+            (true, false) => {
+                state.minority_since = Some(SteadyTime::now());
+                Some(true)
+            }
+            (false, true) => {
+                state.minority_since = None;
+                Some(false)
+            }
+            _ => None,
+        }
+    }
+
+    /// The number of persistent members in the ring.
+    pub fn persistent_members(&self) -> usize {
+        self.state.read().expect("Partition lock poisoned").persistent
+    }
+
+    /// The number of persistent members which are not confirmed dead.
+    pub fn reachable_members(&self) -> usize {
+        self.state.read().expect("Partition lock poisoned").reachable
+    }
+
+    /// The fraction of persistent members which are reachable; 1.0 if there are none.
+    pub fn reachable_fraction(&self) -> f64 {
+        let state = self.state.read().expect("Partition lock poisoned");
+        if state.persistent == 0 {
+            1.0
+        } else {
+            state.reachable as f64 / state.persistent as f64
+        }
+    }
+
+    /// Returns true if this member can reach half of the persistent members or fewer.
+    pub fn in_minority(&self) -> bool {
+        self.state.read().expect("Partition lock poisoned").minority_since.is_some()
+    }
+
+    /// How many seconds this member has been in a minority partition, if it is in one.
+    pub fn minority_secs(&self) -> Option<i64> {
+        self.state
+            .read()
+            .expect("Partition lock poisoned")
+            .minority_since
+            .map(|since| (SteadyTime::now() - since).num_seconds())
+    }
+
+    /// Whether this member gives up quorum in every service group while it is in a minority
+    /// partition, so that its leaders step down.
+    pub fn set_step_down(&self, step_down: bool) {
+        self.step_down.store(step_down, Ordering::Relaxed);
+    }
+
+    /// Returns true if this member must give up quorum: it steps down in a minority partition,
+    /// and is in one.
+    pub fn stepping_down(&self) -> bool {
+        self.step_down.load(Ordering::Relaxed) && self.in_minority()
+    }
+}
+
+impl Serialize for Partition {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("partition", 6));
+        try!(strukt.serialize_field("persistent_members", &self.persistent_members()));
+        try!(strukt.serialize_field("reachable_members", &self.reachable_members()));
+        try!(strukt.serialize_field("reachable_fraction", &self.reachable_fraction()));
+        try!(strukt.serialize_field("minority", &self.in_minority()));
+        try!(strukt.serialize_field("minority_secs", &self.minority_secs()));
+        try!(strukt.serialize_field("step_down", &self.stepping_down()));
+        strukt.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use partition::Partition;
+
+    #[test]
+    fn no_persistent_members_is_never_a_minority() {
+        let partition = Partition::new();
+        assert_eq!(partition.update(0, 0), None);
+        assert!(!partition.in_minority());
+        assert_eq!(partition.reachable_fraction(), 1.0);
+    }
+
+    #[test]
+    fn half_or_fewer_reachable_is_a_minority() {
+        let partition = Partition::new();
+        assert_eq!(partition.update(5, 3), None);
+        assert!(!partition.in_minority());
+        assert_eq!(partition.update(5, 2), Some(true));
+        assert!(partition.in_minority());
+        assert!(partition.minority_secs().is_some());
+        assert_eq!(partition.update(4, 2), None);
+        assert!(partition.in_minority());
+        assert_eq!(partition.update(4, 3), Some(false));
+        assert!(!partition.in_minority());
+        assert_eq!(partition.minority_secs(), None);
+    }
+
+    #[test]
+    fn stepping_down_requires_a_minority_and_step_down() {
+        let partition = Partition::new();
+        partition.update(3, 1);
+        assert!(!partition.stepping_down());
+        partition.set_step_down(true);
+        assert!(partition.stepping_down());
+        partition.update(3, 2);
+        assert!(!partition.stepping_down());
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ring keys a member accepts, which lets a ring rotate its key without downtime.
+//!
+//! When watching the key cache, a member encrypts with the newest revision of the ring key which
+//! was generated at least the activation period ago. Revisions are the UTC time they were
+//! generated at, so every member with the revision switches to it at the same time, regardless of
+//! when it reached its key cache; this gives operators the activation period to copy a new
+//! revision to every member. A member accepts the revision it encrypts with, the one before it
+//! for members whose clocks lag behind, and any newer revisions for members whose clocks run
+//! ahead. Older revisions are no longer accepted.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::result;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use habitat_core::crypto::SymKey;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use time;
+
+/// How long after a new revision of the ring key was generated it is used to encrypt.
+pub const RING_KEY_ACTIVATION_DEFAULT_MS: u64 = 300_000;
+/// How often the key cache is checked for new or removed revisions of the ring key.
+const RING_KEY_RELOAD_INTERVAL_MS: u64 = 10_000;
+
+/// The ring key revisions a member accepts, and the one it encrypts with.
+pub struct RingKeys {
+    keys: RwLock<Keys>,
+}
+
+struct Keys {
+    active: Option<SymKey>,
+    accepted: Vec<SymKey>,
+    watch: Option<Watch>,
+}
+
+struct Watch {
+    cache_key_path: PathBuf,
+    activation: Duration,
+    next_reload: Instant,
+}
+
+impl RingKeys {
+    /// Create a new RingKeys, which accepts and encrypts with only the given ring key.
+    pub fn new(ring_key: Option<SymKey>) -> RingKeys {
+        RingKeys {
+            keys: RwLock::new(Keys {
+                                  accepted: ring_key.iter().cloned().collect(),
+                                  active: ring_key,
+                                  watch: None,
+                              }),
+        }
+    }
+
+    /// Re-read the revisions of the ring key in the key cache periodically, and encrypt with the
+    /// newest one generated at least the `activation` period ago. Does nothing without a ring key.
+    pub fn watch<P: Into<PathBuf>>(&self, cache_key_path: P, activation: Duration) {
+        let mut keys = self.keys.write().expect("Ring keys lock poisoned");
+        if keys.active.is_none() {
+            return;
+        }
+        keys.watch = Some(Watch {
+                              cache_key_path: cache_key_path.into(),
+                              activation: activation,
+                              next_reload: Instant::now(),
+                          });
+    }
+
+    /// The ring key to encrypt with, if any.
+    pub fn active(&self) -> Option<SymKey> {
+        self.reload_if_stale();
+        self.keys.read().expect("Ring keys lock poisoned").active.clone()
+    }
+
+    /// Every ring key a message may be encrypted with, newest first.
+    pub fn accepted(&self) -> Vec<SymKey> {
+        self.reload_if_stale();
+        self.keys.read().expect("Ring keys lock poisoned").accepted.clone()
+    }
+
+    fn reload_if_stale(&self) {
+        {
+            let keys = self.keys.read().expect("Ring keys lock poisoned");
+            match keys.watch {
+                Some(ref watch) if Instant::now() >= watch.next_reload => (),
+                _ => return,
+            }
+        }
+        let mut keys = self.keys.write().expect("Ring keys lock poisoned");
+        let name = match keys.active {
+            Some(ref key) => key.name.clone(),
+            None => return,
+        };
+        let (cache_key_path, activation) = match keys.watch {
+            Some(ref mut watch) if Instant::now() >= watch.next_reload => {
+                watch.next_reload = Instant::now() +
+                                    Duration::from_millis(RING_KEY_RELOAD_INTERVAL_MS);
+                (watch.cache_key_path.clone(), watch.activation)
+            }
+            _ => return,
+        };
+        let mut accepted = match SymKey::get_pairs_for(&name, &cache_key_path) {
+            Ok(ref accepted) if accepted.is_empty() => {
+                warn!("No revisions of ring key {} found, keeping the ones we have",
+                      name);
+                return;
+            }
+            Ok(accepted) => accepted,
+            Err(e) => {
+                warn!("Cannot read the revisions of ring key {}: {}", name, e);
+                return;
+            }
+        };
+        let active_idx = accepted.iter()
+            .position(|key| Self::is_activated(key, activation))
+            .unwrap_or(accepted.len() - 1);
+        // Revisions are newest first: keep the newer ones, the active one and the one before it
+        accepted.truncate(active_idx + 2);
+        let active = accepted.get(active_idx).cloned();
+        if let (Some(new), Some(old)) = (active.as_ref(), keys.active.as_ref()) {
+            if new.rev != old.rev {
+                info!("Encrypting with ring key {}", new.name_with_rev());
+            }
+        }
+        keys.active = active;
+        keys.accepted = accepted;
+    }
+
+    fn is_activated(key: &SymKey, activation: Duration) -> bool {
+        time::strptime(&key.rev, "%Y%m%d%H%M%S")
+            .ok()
+            .and_then(|generated| (time::get_time() - generated.to_timespec()).to_std().ok())
+            .map(|age| age >= activation)
+            .unwrap_or(false)
+    }
+}
+
+impl fmt::Debug for RingKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keys = self.keys.read().expect("Ring keys lock poisoned");
+        write!(f,
+               "RingKeys {{ active: {:?}, accepted: {:?} }}",
+               keys.active.as_ref().map(|k| k.name_with_rev()),
+               keys.accepted.iter().map(|k| k.name_with_rev()).collect::<Vec<String>>())
+    }
+}
+
+impl Serialize for RingKeys {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let keys = self.keys.read().expect("Ring keys lock poisoned");
+        let mut strukt = try!(serializer.serialize_struct("ring_keys", 2));
+        try!(strukt.serialize_field("active",
+                                    &keys.active.as_ref().map(|k| k.name_with_rev())));
+        try!(strukt.serialize_field("accepted",
+                                    &keys.accepted
+                                         .iter()
+                                         .map(|k| k.name_with_rev())
+                                         .collect::<Vec<String>>()));
+        strukt.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use habitat_core::crypto::SymKey;
+
+    use ring_keys::RingKeys;
+    use test_support::KeyCache;
+
+    fn rotate(cache: &KeyCache) -> SymKey {
+        // Revisions are timestamps, so wait for a new one.
+        thread::sleep(Duration::from_millis(1100));
+        SymKey::generate_pair_for_ring("wolverine", cache.path()).unwrap()
+    }
+
+    #[test]
+    fn without_watching_only_the_given_key_is_used() {
+        let cache = KeyCache::new();
+        let old = SymKey::generate_pair_for_ring("wolverine", cache.path()).unwrap();
+        let ring_keys = RingKeys::new(Some(old.clone()));
+        rotate(&cache);
+
+        assert_eq!(ring_keys.active().unwrap().name_with_rev(), old.name_with_rev());
+        assert_eq!(ring_keys.accepted().len(), 1);
+    }
+
+    #[test]
+    fn new_revisions_are_accepted_before_they_are_active() {
+        let cache = KeyCache::new();
+        let old = SymKey::generate_pair_for_ring("wolverine", cache.path()).unwrap();
+        let new = rotate(&cache);
+        let ring_keys = RingKeys::new(Some(new.clone()));
+        ring_keys.watch(cache.path(), Duration::from_secs(3600));
+
+        assert_eq!(ring_keys.active().unwrap().name_with_rev(), old.name_with_rev());
+        let accepted: Vec<String> =
+            ring_keys.accepted().iter().map(|k| k.name_with_rev()).collect();
+        assert_eq!(accepted, vec![new.name_with_rev(), old.name_with_rev()]);
+    }
+
+    #[test]
+    fn the_newest_activated_revision_is_active() {
+        let cache = KeyCache::new();
+        SymKey::generate_pair_for_ring("wolverine", cache.path()).unwrap();
+        let new = rotate(&cache);
+        thread::sleep(Duration::from_millis(100));
+        let ring_keys = RingKeys::new(Some(new.clone()));
+        ring_keys.watch(cache.path(), Duration::from_millis(50));
+
+        assert_eq!(ring_keys.active().unwrap().name_with_rev(), new.name_with_rev());
+    }
+
+    #[test]
+    fn revisions_before_the_previous_one_are_dropped() {
+        let cache = KeyCache::new();
+        SymKey::generate_pair_for_ring("wolverine", cache.path()).unwrap();
+        let previous = rotate(&cache);
+        let new = rotate(&cache);
+        let ring_keys = RingKeys::new(Some(new.clone()));
+        ring_keys.watch(cache.path(), Duration::from_millis(0));
+
+        assert_eq!(ring_keys.active().unwrap().name_with_rev(), new.name_with_rev());
+        let accepted: Vec<String> =
+            ring_keys.accepted().iter().map(|k| k.name_with_rev()).collect();
+        assert_eq!(accepted, vec![new.name_with_rev(), previous.name_with_rev()]);
+    }
+
+    #[test]
+    fn watching_without_a_ring_key_does_nothing() {
+        let cache = KeyCache::new();
+        SymKey::generate_pair_for_ring("wolverine", cache.path()).unwrap();
+        let ring_keys = RingKeys::new(None);
+        ring_keys.watch(cache.path(), Duration::from_millis(0));
+
+        assert!(ring_keys.active().is_none());
+        assert!(ring_keys.accepted().is_empty());
+    }
+}
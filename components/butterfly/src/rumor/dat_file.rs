@@ -24,10 +24,11 @@ use tempfile::NamedTempFile;
 use error::{Result, Error};
 use member::{Health, Member, MemberList};
 use message::swim::Membership as ProtoMembership;
-use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile};
+use rumor::{Departure, Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig,
+            ServiceFile, UpdateDirective};
 use server::Server;
 
-const HEADER_VERSION: u8 = 1;
+const HEADER_VERSION: u8 = 3;
 
 /// A versioned binary file containing rumors exchanged by the butterfly server which have
 /// been periodically persisted to disk.
@@ -42,6 +43,7 @@ const HEADER_VERSION: u8 = 1;
 pub struct DatFile {
     header: Header,
     path: PathBuf,
+    version: u8,
 }
 
 impl DatFile {
@@ -49,6 +51,7 @@ impl DatFile {
         DatFile {
             path: data_path.as_ref().join(format!("{}.rst", member_id)),
             header: Header::default(),
+            version: HEADER_VERSION,
         }
     }
 
@@ -68,8 +71,9 @@ impl DatFile {
         let mut reader = BufReader::new(file);
         reader.read_exact(&mut version).map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
         debug!("Header Version: {}", version[0]);
-        self.header =
-            Header::from_file(&mut reader).map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+        self.version = version[0];
+        self.header = Header::from_file(&mut reader, self.version)
+            .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
         debug!("Header: {:?}", self.header);
 
         reader.seek(SeekFrom::Start(self.member_offset()))
@@ -178,6 +182,41 @@ impl DatFile {
             server.insert_update_election(rumor);
             bytes_read += size_buf.len() as u64 + rumor_size;
         }
+
+        debug!("Reading update directive rumors from {}",
+               self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.update_directive_len {
+                break;
+            }
+            reader.read_exact(&mut size_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader.read_exact(&mut rumor_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor = UpdateDirective::from_bytes(&rumor_buf)?;
+            server.insert_update_directive(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
+
+        debug!("Reading departure rumors from {}", self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.departure_len {
+                break;
+            }
+            reader.read_exact(&mut size_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader.read_exact(&mut rumor_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor = Departure::from_bytes(&rumor_buf)?;
+            server.insert_departure(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
         Ok(())
     }
 
@@ -195,6 +234,9 @@ impl DatFile {
                 self.write_rumor_store(&mut writer, &server.service_file_store)?;
             header.election_len = self.write_rumor_store(&mut writer, &server.election_store)?;
             header.update_len = self.write_rumor_store(&mut writer, &server.update_store)?;
+            header.update_directive_len =
+                self.write_rumor_store(&mut writer, &server.update_directive_store)?;
+            header.departure_len = self.write_rumor_store(&mut writer, &server.departure_store)?;
             writer.seek(SeekFrom::Start(1)).map_err(|err| {
                 Error::DatFileIO(self.path.clone(), err)
             })?;
@@ -218,7 +260,7 @@ impl DatFile {
     }
 
     fn member_offset(&self) -> u64 {
-        1 + mem::size_of::<Header>() as u64
+        1 + Header::size(self.version) as u64
     }
 
     #[allow(dead_code)]
@@ -246,6 +288,16 @@ impl DatFile {
         self.election_offset() + self.header.election_len
     }
 
+    #[allow(dead_code)]
+    fn update_directive_offset(&self) -> u64 {
+        self.update_offset() + self.header.update_len
+    }
+
+    #[allow(dead_code)]
+    fn departure_offset(&self) -> u64 {
+        self.update_directive_offset() + self.header.update_directive_len
+    }
+
     fn write_header<W>(&self, writer: &mut W, header: &Header) -> Result<usize>
         where W: Write
     {
@@ -324,18 +376,40 @@ pub struct Header {
     pub service_file_len: u64,
     pub election_len: u64,
     pub update_len: u64,
+    pub update_directive_len: u64,
+    pub departure_len: u64,
 }
 
 impl Header {
-    pub fn from_file<R>(reader: &mut R) -> io::Result<Self>
+    /// Returns the size of the header written by the given header version. Version 1 headers
+    /// predate update directives, and version 2 headers predate departures.
+    pub fn size(version: u8) -> usize {
+        match version {
+            1 => mem::size_of::<Self>() - 16,
+            2 => mem::size_of::<Self>() - 8,
+            _ => mem::size_of::<Self>(),
+        }
+    }
+
+    pub fn from_file<R>(reader: &mut R, version: u8) -> io::Result<Self>
         where R: Read
     {
-        let mut bytes = vec![0; mem::size_of::<Self>()];
+        let mut bytes = vec![0; Self::size(version)];
         reader.read_exact(&mut bytes)?;
         Ok(Self::from_bytes(&bytes))
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Self {
+        let update_directive_len = if bytes.len() >= 56 {
+            LittleEndian::read_u64(&bytes[48..56])
+        } else {
+            0
+        };
+        let departure_len = if bytes.len() >= 64 {
+            LittleEndian::read_u64(&bytes[56..64])
+        } else {
+            0
+        };
         Header {
             member_len: LittleEndian::read_u64(&bytes[0..8]),
             service_len: LittleEndian::read_u64(&bytes[8..16]),
@@ -343,6 +417,8 @@ impl Header {
             service_file_len: LittleEndian::read_u64(&bytes[24..32]),
             election_len: LittleEndian::read_u64(&bytes[32..40]),
             update_len: LittleEndian::read_u64(&bytes[40..48]),
+            update_directive_len: update_directive_len,
+            departure_len: departure_len,
         }
     }
 
@@ -354,6 +430,8 @@ impl Header {
         LittleEndian::write_u64(&mut bytes[24..32], self.service_file_len);
         LittleEndian::write_u64(&mut bytes[32..40], self.election_len);
         LittleEndian::write_u64(&mut bytes[40..48], self.update_len);
+        LittleEndian::write_u64(&mut bytes[48..56], self.update_directive_len);
+        LittleEndian::write_u64(&mut bytes[56..64], self.departure_len);
         Ok(bytes)
     }
 }
@@ -373,9 +451,33 @@ mod tests {
         original.service_file_len = rand::random::<u64>();
         original.election_len = rand::random::<u64>();
         original.update_len = rand::random::<u64>();
+        original.update_directive_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
         let bytes = original.write_to_bytes().unwrap();
         let restored = Header::from_bytes(&bytes);
         assert_eq!(bytes.len(), mem::size_of::<Header>());
         assert_eq!(original, restored);
     }
+
+    #[test]
+    fn read_version_one_header() {
+        let mut original = Header::default();
+        original.election_len = rand::random::<u64>();
+        original.update_len = rand::random::<u64>();
+        let bytes = original.write_to_bytes().unwrap();
+        let mut reader = &bytes[..Header::size(1)];
+        let restored = Header::from_file(&mut reader, 1).unwrap();
+        assert_eq!(original, restored);
+    }
+
+    #[test]
+    fn read_version_two_header() {
+        let mut original = Header::default();
+        original.update_len = rand::random::<u64>();
+        original.update_directive_len = rand::random::<u64>();
+        let bytes = original.write_to_bytes().unwrap();
+        let mut reader = &bytes[..Header::size(2)];
+        let restored = Header::from_file(&mut reader, 2).unwrap();
+        assert_eq!(original, restored);
+    }
 }
@@ -0,0 +1,136 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Departure rumor.
+//!
+//! Announces that a member has left the ring for good. Every member receiving it forgets the
+//! departed member and its services, and refuses to let it back in.
+
+use std::ops::{Deref, DerefMut};
+
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{Departure as ProtoDeparture, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Departure(ProtoRumor);
+
+impl PartialEq for Departure {
+    fn eq(&self, other: &Departure) -> bool {
+        self.get_member_id() == other.get_member_id()
+    }
+}
+
+impl From<ProtoRumor> for Departure {
+    fn from(pr: ProtoRumor) -> Departure {
+        Departure(pr)
+    }
+}
+
+impl From<Departure> for ProtoRumor {
+    fn from(departure: Departure) -> ProtoRumor {
+        departure.0
+    }
+}
+
+impl Deref for Departure {
+    type Target = ProtoDeparture;
+
+    fn deref(&self) -> &ProtoDeparture {
+        self.0.get_departure()
+    }
+}
+
+impl DerefMut for Departure {
+    fn deref_mut(&mut self) -> &mut ProtoDeparture {
+        self.0.mut_departure()
+    }
+}
+
+impl Departure {
+    /// Creates a new Departure for the given member, sent from `from_id`.
+    pub fn new<S1, S2>(from_id: S1, member_id: S2) -> Self
+        where S1: Into<String>,
+              S2: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        rumor.set_from_id(from_id.into());
+        rumor.set_field_type(ProtoRumor_Type::Departure);
+
+        let mut proto = ProtoDeparture::new();
+        proto.set_member_id(member_id.into());
+        rumor.set_departure(proto);
+        Departure(rumor)
+    }
+}
+
+impl Rumor for Departure {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(Departure::from(rumor))
+    }
+
+    /// A departure is permanent, so there is never anything to merge.
+    fn merge(&mut self, _other: Departure) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::Departure
+    }
+
+    fn id(&self) -> &str {
+        "departure"
+    }
+
+    fn key(&self) -> &str {
+        self.get_member_id()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Departure;
+    use rumor::Rumor;
+
+    #[test]
+    fn identical_departures_are_equal() {
+        let d1 = Departure::new("adam", "neurosis");
+        let d2 = Departure::new("eve", "neurosis");
+        assert_eq!(d1, d2);
+    }
+
+    #[test]
+    fn merge_never_changes_anything() {
+        let mut d1 = Departure::new("adam", "neurosis");
+        let d2 = Departure::new("eve", "neurosis");
+        assert_eq!(d1.merge(d2), false);
+        assert_eq!(d1.0.get_from_id(), "adam");
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let d1 = Departure::new("adam", "neurosis");
+        let bytes = d1.write_to_bytes().unwrap();
+        let d2 = Departure::from_bytes(&bytes).unwrap();
+        assert_eq!(d2.get_member_id(), "neurosis");
+    }
+}
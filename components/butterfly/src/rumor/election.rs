@@ -120,6 +120,14 @@ impl Election {
     pub fn is_finished(&self) -> bool {
         self.get_status() == Election_Status::Finished
     }
+
+    /// Approve the given package for the rest of the service group to update to. Each approval
+    /// supersedes the previous one for the same election.
+    pub fn approve<S: Into<String>>(&mut self, pkg: S) {
+        let approval = self.get_approval() + 1;
+        self.set_approved_pkg(pkg.into());
+        self.set_approval(approval);
+    }
 }
 
 impl PartialEq for Election {
@@ -129,7 +137,9 @@ impl PartialEq for Election {
         self.get_member_id() == other.get_member_id() &&
         self.get_suitability() == other.get_suitability() &&
         self.get_votes() == other.get_votes() && self.get_status() == other.get_status() &&
-        self.get_term() == other.get_term()
+        self.get_term() == other.get_term() &&
+        self.get_approved_pkg() == other.get_approved_pkg() &&
+        self.get_approval() == other.get_approval()
     }
 }
 
@@ -145,6 +155,17 @@ impl Rumor for Election {
             // If we are the same object, just return false
             // println!("Equal: {:?} {:?}", self, other);
             false
+        } else if other.get_term() == self.get_term() && self.is_finished() &&
+                  other.is_finished() &&
+                  self.get_member_id() == other.get_member_id() {
+            // If we both agree on the outcome of the election, the rumor carrying the latest
+            // approval wins.
+            if other.get_approval() > self.get_approval() {
+                *self = other;
+                true
+            } else {
+                false
+            }
         } else if other.get_term() >= self.get_term() &&
                   other.get_status() == Election_Status::Finished {
             // If the new rumors term is bigger or equal to ours, and it has a leader, we take it as
@@ -325,4 +346,19 @@ mod tests {
         assert_eq!(e1.get_member_id(), "d");
         assert_eq!(e1.get_votes().len(), 4);
     }
+
+    #[test]
+    fn merge_finished_election_takes_latest_approval() {
+        let mut e1 = create_election("a", 0);
+        e1.finish();
+        let mut e2 = e1.clone();
+        e2.approve("core/redis/3.2.9/20170602173812");
+        let stale = e1.clone();
+
+        assert_eq!(e1.merge(e2), true);
+        assert_eq!(e1.get_approved_pkg(), "core/redis/3.2.9/20170602173812");
+        assert_eq!(e1.get_approval(), 1);
+        assert_eq!(e1.merge(stale), false);
+        assert_eq!(e1.get_approved_pkg(), "core/redis/3.2.9/20170602173812");
+    }
 }
@@ -24,15 +24,19 @@
 //! new rumors, and dispatch them according to their `kind`.
 
 pub mod dat_file;
+pub mod departure;
 pub mod election;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod update_directive;
 
+pub use self::departure::Departure;
 pub use self::election::{Election, ElectionUpdate};
 pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
+pub use self::update_directive::UpdateDirective;
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
@@ -41,6 +45,7 @@ use std::ops::Deref;
 use std::result;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
@@ -94,10 +99,14 @@ impl<'a, T: Rumor> From<&'a T> for RumorKey {
 /// Storage for Rumors. It takes a rumor and stores it according to the member that produced it,
 /// and the service group it is related to.
 ///
+/// Expired rumors leave a tombstone behind, so that members which haven't expired them yet can't
+/// gossip them back in; only a newer version of the rumor replaces its tombstone.
+///
 /// Generic over the type of rumor it stores.
 #[derive(Debug, Clone)]
 pub struct RumorStore<T: Rumor> {
     pub list: Arc<RwLock<HashMap<String, HashMap<String, T>>>>,
+    tombstones: Arc<RwLock<HashMap<String, HashMap<String, (T, Instant)>>>>,
     update_counter: Arc<AtomicUsize>,
 }
 
@@ -105,6 +114,7 @@ impl<T: Rumor> Default for RumorStore<T> {
     fn default() -> RumorStore<T> {
         RumorStore {
             list: Arc::new(RwLock::new(HashMap::new())),
+            tombstones: Arc::new(RwLock::new(HashMap::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
         }
     }
@@ -140,6 +150,7 @@ impl<T: Rumor> RumorStore<T> {
     pub fn clear(&self) -> usize {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
         list.clear();
+        self.tombstones.write().expect("Rumor store lock poisoned").clear();
         self.update_counter.swap(0, Ordering::Relaxed)
     }
 
@@ -166,6 +177,10 @@ impl<T: Rumor> RumorStore<T> {
     /// Insert a rumor into the Rumor Store. Returns true if the value didn't exist or if it was
     /// mutated; if nothing changed, returns false.
     pub fn insert(&self, rumor: T) -> bool {
+        let rumor = match self.past_tombstone(rumor) {
+            Some(rumor) => rumor,
+            None => return false,
+        };
         let mut list = self.list.write().expect("Rumor store lock poisoned");
         let mut rumors = list.entry(String::from(rumor.key())).or_insert(HashMap::new());
         // Result reveals if there was a change so we can increment the counter if needed.
@@ -187,6 +202,126 @@ impl<T: Rumor> RumorStore<T> {
         list.get_mut(key).and_then(|mut r| r.remove(id));
     }
 
+    /// Remove the rumors with the given id from every key, returning the keys they were removed
+    /// from. Used to forget everything a member said once it has departed.
+    pub fn remove_id(&self, id: &str) -> Vec<String> {
+        self.take_id(id).into_iter().map(|(key, _)| key).collect()
+    }
+
+    /// Expire the rumors with the given id from every key, leaving tombstones behind. Returns the
+    /// keys they were expired from.
+    pub fn expire_id(&self, id: &str) -> Vec<String> {
+        let expired = self.take_id(id);
+        let mut tombstones = self.tombstones.write().expect("Rumor store lock poisoned");
+        let now = Instant::now();
+        expired.into_iter()
+            .map(|(key, rumor)| {
+                     tombstones.entry(key.clone())
+                         .or_insert(HashMap::new())
+                         .insert(String::from(id), (rumor, now));
+                     key
+                 })
+            .collect()
+    }
+
+    /// Expire every rumor stored under the given key, leaving tombstones behind. Returns the ids
+    /// of the expired rumors.
+    pub fn expire_key(&self, key: &str) -> Vec<String> {
+        let expired = {
+            let mut list = self.list.write().expect("Rumor store lock poisoned");
+            list.remove(key).unwrap_or(HashMap::new())
+        };
+        if expired.is_empty() {
+            return Vec::new();
+        }
+        self.increment_update_counter();
+        let mut tombstones = self.tombstones.write().expect("Rumor store lock poisoned");
+        let now = Instant::now();
+        let key_tombstones = tombstones.entry(String::from(key)).or_insert(HashMap::new());
+        expired.into_iter()
+            .map(|(id, rumor)| {
+                     key_tombstones.insert(id.clone(), (rumor, now));
+                     id
+                 })
+            .collect()
+    }
+
+    /// Forget the tombstones under the given key, letting any version of their rumors back in.
+    pub fn clear_tombstones(&self, key: &str) {
+        if !self.tombstones.read().expect("Rumor store lock poisoned").contains_key(key) {
+            return;
+        }
+        self.tombstones.write().expect("Rumor store lock poisoned").remove(key);
+    }
+
+    /// Forget the tombstones older than `ttl`, by which time every member has expired their
+    /// rumors too. Returns how many were forgotten.
+    pub fn purge_tombstones(&self, ttl: Duration) -> usize {
+        let mut tombstones = self.tombstones.write().expect("Rumor store lock poisoned");
+        let mut purged = Vec::new();
+        for (key, rumors) in tombstones.iter() {
+            for (id, &(_, expired_at)) in rumors.iter() {
+                if expired_at.elapsed() >= ttl {
+                    purged.push((key.clone(), id.clone()));
+                }
+            }
+        }
+        for &(ref key, ref id) in purged.iter() {
+            remove_nested(&mut tombstones, key, id);
+        }
+        purged.len()
+    }
+
+    /// Returns the count of tombstones left by expired rumors.
+    pub fn tombstones_len(&self) -> usize {
+        self.tombstones
+            .read()
+            .expect("Rumor store lock poisoned")
+            .values()
+            .map(|rumors| rumors.len())
+            .sum()
+    }
+
+    fn take_id(&self, id: &str) -> Vec<(String, T)> {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let keys: Vec<String> = list.iter()
+            .filter(|&(_, rumors)| rumors.contains_key(id))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut removed = Vec::new();
+        for key in keys.into_iter() {
+            if let Some(rumor) = remove_nested(&mut list, &key, id) {
+                removed.push((key, rumor));
+            }
+        }
+        if removed.len() > 0 {
+            self.increment_update_counter();
+        }
+        removed
+    }
+
+    /// Check a rumor against the tombstone of an expired rumor with the same key and id, if there
+    /// is one. The rumor is only let back in if it is newer than the expired one.
+    fn past_tombstone(&self, rumor: T) -> Option<T> {
+        {
+            let tombstones = self.tombstones.read().expect("Rumor store lock poisoned");
+            if !tombstones.get(rumor.key()).map_or(false, |r| r.contains_key(rumor.id())) {
+                return Some(rumor);
+            }
+        }
+        let key = String::from(rumor.key());
+        let id = String::from(rumor.id());
+        let mut tombstones = self.tombstones.write().expect("Rumor store lock poisoned");
+        let newer = match tombstones.get_mut(&key).and_then(|r| r.get_mut(&id)) {
+            Some(&mut (ref mut expired, _)) => expired.merge(rumor),
+            None => return Some(rumor),
+        };
+        if !newer {
+            return None;
+        }
+        remove_nested(&mut tombstones, &key, &id).map(|(rumor, _)| rumor)
+    }
+
     pub fn with_keys<F>(&self, mut with_closure: F)
         where F: FnMut((&String, &HashMap<String, T>))
     {
@@ -239,6 +374,24 @@ impl<T: Rumor> RumorStore<T> {
     }
 }
 
+/// Remove the value under `key` and `id` from a map of maps, dropping the inner map once it's empty.
+fn remove_nested<V>(map: &mut HashMap<String, HashMap<String, V>>,
+                    key: &str,
+                    id: &str)
+                    -> Option<V> {
+    let (value, emptied) = match map.get_mut(key) {
+        Some(inner) => {
+            let value = inner.remove(id);
+            (value, inner.is_empty())
+        }
+        None => return None,
+    };
+    if emptied {
+        map.remove(key);
+    }
+    value
+}
+
 /// The number of times a rumor will be shared before it goes cold for that member.
 pub const RUMOR_MAX: usize = 2;
 
@@ -269,6 +422,12 @@ impl RumorList {
         rumors.insert(rk, HashMap::new());
     }
 
+    /// Stop sharing a rumor.
+    pub fn remove(&self, rk: &RumorKey) {
+        let mut rumors = self.rumor_list.write().expect("Rumor Map lock poisoned");
+        rumors.remove(rk);
+    }
+
     /// Return a list of rumors, along with their current heat, sorted by heat. Lowest to highest.
     /// So all the "0" rumors sort higher than the "2" rumors.
     pub fn rumors(&self, id: &str) -> RumorVec {
@@ -417,6 +576,7 @@ mod tests {
         use super::FakeRumor;
         use rumor::RumorStore;
         use rumor::Rumor;
+        use std::time::Duration;
         use std::usize;
 
         fn create_rumor_store() -> RumorStore<FakeRumor> {
@@ -522,12 +682,76 @@ mod tests {
             let rs = create_rumor_store();
             rs.with_rumor("bar", "foo", |o| assert!(o.is_none()));
         }
+
+        #[test]
+        fn remove_id_removes_rumors_from_every_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let mut f2 = f1.clone();
+            f2.key = String::from("gossip");
+            let id = f1.id.clone();
+            rs.insert(f1);
+            rs.insert(f2);
+            rs.insert(FakeRumor::default());
+            let counter = rs.get_update_counter();
+            let mut removed = rs.remove_id(&id);
+            removed.sort();
+            assert_eq!(removed, vec![String::from("fakerton"), String::from("gossip")]);
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.get_update_counter(), counter + 1);
+        }
+
+        #[test]
+        fn expire_key_removes_every_rumor_for_the_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let f1_id = f1.id.clone();
+            rs.insert(f1);
+            let mut f2 = FakeRumor::default();
+            f2.key = String::from("gossip");
+            rs.insert(f2);
+            let counter = rs.get_update_counter();
+            assert_eq!(rs.expire_key("fakerton"), vec![f1_id]);
+            assert_eq!(rs.len_for_key("fakerton"), 0);
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.get_update_counter(), counter + 1);
+            assert_eq!(rs.expire_key("fakerton").len(), 0);
+            assert_eq!(rs.get_update_counter(), counter + 1);
+        }
+
+        #[test]
+        fn expired_rumors_are_not_inserted_again() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let id = f1.id.clone();
+            rs.insert(f1.clone());
+            assert_eq!(rs.expire_id(&id), vec![String::from("fakerton")]);
+            assert_eq!(rs.tombstones_len(), 1);
+            assert!(!rs.insert(f1.clone()));
+            assert_eq!(rs.len(), 0);
+
+            rs.clear_tombstones("fakerton");
+            assert!(rs.insert(f1));
+            assert_eq!(rs.len(), 1);
+        }
+
+        #[test]
+        fn purge_tombstones_forgets_old_tombstones() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            rs.insert(f1.clone());
+            rs.expire_key("fakerton");
+            assert_eq!(rs.purge_tombstones(Duration::from_secs(3600)), 0);
+            assert_eq!(rs.purge_tombstones(Duration::from_secs(0)), 1);
+            assert_eq!(rs.tombstones_len(), 0);
+            assert!(rs.insert(f1));
+        }
     }
 
     mod rumor_list {
         use super::{FakeRumor, TrumpRumor};
         use message::swim::Rumor_Type;
-        use rumor::{RumorList, RUMOR_MAX};
+        use rumor::{RumorKey, RumorList, RUMOR_MAX};
 
         #[test]
         fn insert() {
@@ -536,6 +760,15 @@ mod tests {
             rl.insert(&rumor);
         }
 
+        #[test]
+        fn remove() {
+            let rl = RumorList::default();
+            let rumor = FakeRumor::default();
+            rl.insert(&rumor);
+            rl.remove(&RumorKey::from(&rumor));
+            assert_eq!(rl.rumors("fake").len(), 0);
+        }
+
         #[test]
         fn rumors() {
             let rl = RumorList::default();
@@ -0,0 +1,208 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The UpdateDirective rumor.
+//!
+//! Tells every member of a service group to pause, resume, pin or immediately check for updates of
+//! their service.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{UpdateDirective as ProtoUpdateDirective, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+pub use message::swim::UpdateDirective_Action;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateDirective(ProtoRumor);
+
+impl PartialOrd for UpdateDirective {
+    fn partial_cmp(&self, other: &UpdateDirective) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for UpdateDirective {
+    fn eq(&self, other: &UpdateDirective) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_action() == other.get_action() && self.get_pkg() == other.get_pkg()
+    }
+}
+
+impl From<ProtoRumor> for UpdateDirective {
+    fn from(pr: ProtoRumor) -> UpdateDirective {
+        UpdateDirective(pr)
+    }
+}
+
+impl From<UpdateDirective> for ProtoRumor {
+    fn from(update_directive: UpdateDirective) -> ProtoRumor {
+        update_directive.0
+    }
+}
+
+impl Deref for UpdateDirective {
+    type Target = ProtoUpdateDirective;
+
+    fn deref(&self) -> &ProtoUpdateDirective {
+        self.0.get_update_directive()
+    }
+}
+
+impl DerefMut for UpdateDirective {
+    fn deref_mut(&mut self) -> &mut ProtoUpdateDirective {
+        self.0.mut_update_directive()
+    }
+}
+
+impl UpdateDirective {
+    /// Creates a new UpdateDirective. The package ident is only meaningful when pinning, or when
+    /// updating now to a pinned package.
+    pub fn new<S1>(member_id: S1,
+                   service_group: ServiceGroup,
+                   action: UpdateDirective_Action,
+                   pkg: Option<String>)
+                   -> Self
+        where S1: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::UpdateDirective);
+
+        let mut proto = ProtoUpdateDirective::new();
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_incarnation(0);
+        proto.set_action(action);
+        if let Some(pkg) = pkg {
+            proto.set_pkg(pkg);
+        }
+
+        rumor.set_update_directive(proto);
+        UpdateDirective(rumor)
+    }
+
+    /// Returns whether updates of the service group are paused.
+    pub fn is_paused(&self) -> bool {
+        self.get_action() == UpdateDirective_Action::Pause
+    }
+
+    /// Returns the package ident updates of the service group are pinned to, if any. The package
+    /// of an update-now directive only holds until the service group has updated to it.
+    pub fn pinned_pkg(&self) -> Option<&str> {
+        match self.get_action() {
+            UpdateDirective_Action::Pin |
+            UpdateDirective_Action::UpdateNow if self.has_pkg() => Some(self.get_pkg()),
+            _ => None,
+        }
+    }
+}
+
+impl Rumor for UpdateDirective {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(UpdateDirective::from(rumor))
+    }
+
+    /// The directive with the newest incarnation wins, so the last command given to the service
+    /// group is the one every member follows.
+    fn merge(&mut self, mut other: UpdateDirective) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::UpdateDirective
+    }
+
+    fn id(&self) -> &str {
+        "update_directive"
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::service::ServiceGroup;
+
+    use super::{UpdateDirective, UpdateDirective_Action};
+    use rumor::Rumor;
+
+    fn create_update_directive(action: UpdateDirective_Action,
+                               pkg: Option<&str>,
+                               incarnation: u64)
+                               -> UpdateDirective {
+        let mut ud = UpdateDirective::new("adam",
+                                          ServiceGroup::new("neurosis", "production", None)
+                                              .unwrap(),
+                                          action,
+                                          pkg.map(String::from));
+        ud.set_incarnation(incarnation);
+        ud
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut ud1 = create_update_directive(UpdateDirective_Action::Pause, None, 1);
+        let ud2 = create_update_directive(UpdateDirective_Action::Resume, None, 2);
+        let ud2_check = ud2.clone();
+        assert_eq!(ud1.merge(ud2), true);
+        assert_eq!(ud1, ud2_check);
+        assert!(!ud1.is_paused());
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let mut ud1 = create_update_directive(UpdateDirective_Action::Pause, None, 2);
+        let ud1_check = ud1.clone();
+        let ud2 = create_update_directive(UpdateDirective_Action::Resume, None, 1);
+        assert_eq!(ud1.merge(ud2), false);
+        assert_eq!(ud1, ud1_check);
+        assert!(ud1.is_paused());
+    }
+
+    #[test]
+    fn pinned_pkg_is_only_set_for_pin_and_update_now() {
+        let pin = create_update_directive(UpdateDirective_Action::Pin, Some("core/redis/3.2.4"), 1);
+        assert_eq!(pin.pinned_pkg(), Some("core/redis/3.2.4"));
+        let now = create_update_directive(UpdateDirective_Action::UpdateNow, None, 1);
+        assert_eq!(now.pinned_pkg(), None);
+        let resume =
+            create_update_directive(UpdateDirective_Action::Resume, Some("core/redis/3.2.4"), 1);
+        assert_eq!(resume.pinned_pkg(), None);
+    }
+}
@@ -12,26 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Expire suspected members.
+//! Expire suspected members, and the rumors nobody needs anymore.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. It also expires the service rumors of members which stay confirmed dead
+//! longer than the rumor TTL, and the configuration, files and elections of service groups which
+//! stay without any services longer than the rumor TTL. Expired rumors leave tombstones, which are forgotten once they are
+//! older than the rumor TTL too. Once members are confirmed, it checks whether we are left in a
+//! minority partition.
 
+use std::collections::HashMap;
 use std::thread;
 use std::time::Duration;
 
-use time::SteadyTime;
+use time::{SteadyTime, Duration as TimeDuration};
 
 use message::swim::Rumor_Type;
 use member::Health;
 use rumor::RumorKey;
-use server::Server;
+use server::{Server, metrics};
 use server::timing::Timing;
 use trace::TraceKind;
 
+/// How often we look for rumors to expire.
+const RUMOR_EXPIRY_INTERVAL_MS: i64 = 10_000;
+
 pub struct Expire {
     pub server: Server,
     pub timing: Timing,
+    // When each confirmed member was first seen confirmed.
+    confirmed: HashMap<String, SteadyTime>,
+    // When each service group without services was first seen empty.
+    orphaned: HashMap<String, SteadyTime>,
+    next_rumor_expiry: SteadyTime,
 }
 
 impl Expire {
@@ -40,17 +53,21 @@ impl Expire {
         Expire {
             server: server,
             timing: timing,
+            confirmed: HashMap::new(),
+            orphaned: HashMap::new(),
+            next_rumor_expiry: SteadyTime::now(),
         }
     }
 
     /// Run the expire thread.
-    pub fn run(&self) {
+    pub fn run(&mut self) {
         loop {
             let mut expired_list: Vec<String> = Vec::new();
             self.server.member_list.with_suspects(|(id, suspect)| {
                 let now = SteadyTime::now();
                 if *suspect + self.timing.suspicion_timeout_duration() > now {
                     expired_list.push(String::from(id));
+                    metrics::health_transition("confirmed");
                     self.server.member_list.insert_health_by_id(id, Health::Confirmed);
                     self.server.member_list.with_member(id, |has_member| {
                         let member = has_member.expect("Member does not exist when expiring it");
@@ -65,7 +82,81 @@ impl Expire {
                 self.server.member_list.expire(mid);
                 self.server.rumor_list.insert(RumorKey::new(Rumor_Type::Member, mid.clone(), ""));
             }
+            self.server.check_partition();
+            metrics::rumors(&self.server);
+            if SteadyTime::now() > self.next_rumor_expiry {
+                self.expire_rumors();
+                self.next_rumor_expiry = SteadyTime::now() +
+                                         TimeDuration::milliseconds(RUMOR_EXPIRY_INTERVAL_MS);
+            }
             thread::sleep(Duration::from_millis(500));
         }
     }
+
+    /// Expire the service rumors of members confirmed dead for longer than the rumor TTL, and the
+    /// configuration, files and elections of service groups which have had no services for longer
+    /// than the rumor TTL. Configuration and files pushed to a service group before it has any
+    /// services are only expired once the rumor TTL has passed too.
+    fn expire_rumors(&mut self) {
+        let now = SteadyTime::now();
+        let ttl = self.timing.rumor_ttl_duration();
+
+        let mut confirmed = HashMap::new();
+        {
+            let server = &self.server;
+            let previous = &self.confirmed;
+            server.member_list.with_members(|member| if
+                server.member_list.check_health_of(member, Health::Confirmed) {
+                let since = previous.get(member.get_id()).cloned().unwrap_or(now);
+                confirmed.insert(String::from(member.get_id()), since);
+            });
+        }
+        for (member_id, since) in confirmed.iter() {
+            if *since + ttl < now {
+                let expired = self.server.expire_services_of(member_id);
+                if expired > 0 {
+                    debug!("Expired {} service rumors of confirmed member {}",
+                           expired,
+                           member_id);
+                }
+            }
+        }
+        self.confirmed = confirmed;
+
+        let mut orphaned = HashMap::new();
+        {
+            let server = &self.server;
+            let previous = &self.orphaned;
+            let mut check_orphan = |service_group: &String| if
+                server.service_store.len_for_key(service_group) == 0 {
+                let since = previous.get(service_group).cloned().unwrap_or(now);
+                orphaned.insert(service_group.clone(), since);
+            };
+            server.service_config_store.with_keys(|(sg, _)| check_orphan(sg));
+            server.service_file_store.with_keys(|(sg, _)| check_orphan(sg));
+            server.election_store.with_keys(|(sg, _)| check_orphan(sg));
+            server.update_store.with_keys(|(sg, _)| check_orphan(sg));
+        }
+        let mut expired_groups = Vec::new();
+        for (service_group, since) in orphaned.iter() {
+            if *since + ttl < now {
+                let expired = self.server.expire_service_group(service_group);
+                debug!("Expired {} rumors of empty service group {}",
+                       expired,
+                       service_group);
+                expired_groups.push(service_group.clone());
+            }
+        }
+        for service_group in expired_groups.iter() {
+            orphaned.remove(service_group);
+        }
+        self.orphaned = orphaned;
+
+        if let Ok(ttl) = ttl.to_std() {
+            let purged = self.server.purge_tombstones(ttl);
+            if purged > 0 {
+                debug!("Forgot {} tombstones of expired rumors", purged);
+            }
+        }
+    }
 }
@@ -24,9 +24,11 @@ use std::time::Duration;
 
 use protobuf;
 
+use error::{Error, Result};
 use member::{Member, Health};
+use message;
 use message::swim::{Swim, Swim_Type};
-use server::{Server, outbound};
+use server::{Server, metrics, outbound};
 use trace::TraceKind;
 
 /// Takes the Server and a channel to send received Acks to the outbound thread.
@@ -49,9 +51,10 @@ impl Inbound {
         }
     }
 
-    /// Run the thread. Listens for messages up to 1k in size, and then processes them accordingly.
+    /// Run the thread. Listens for messages up to 2k in size, and then processes them accordingly.
+    /// Messages carrying a member certificate are larger than the 1k unauthenticated ones.
     pub fn run(&self) {
-        let mut recv_buffer: Vec<u8> = vec![0; 1024];
+        let mut recv_buffer: Vec<u8> = vec![0; 2048];
         loop {
             if self.server.pause.load(Ordering::Relaxed) {
                 thread::sleep(Duration::from_millis(100));
@@ -59,15 +62,26 @@ impl Inbound {
             }
             match self.socket.recv_from(&mut recv_buffer[..]) {
                 Ok((length, addr)) => {
-                    let swim_payload = match self.server.unwrap_wire(&recv_buffer[0..length]) {
-                        Ok(swim_payload) => swim_payload,
-                        Err(e) => {
-                            // NOTE: In the future, we might want to blacklist people who send us
-                            // garbage all the time.
-                            error!("Error parsing protobuf: {:?}", e);
-                            continue;
-                        }
-                    };
+                    let (swim_payload, sender) =
+                        match self.server.unwrap_wire(&recv_buffer[0..length]) {
+                            Ok(unwrapped) => unwrapped,
+                            Err(Error::RevokedMember(member)) => {
+                                warn!("Rejecting message from {} - member key {} is revoked",
+                                      addr,
+                                      member);
+                                continue;
+                            }
+                            Err(Error::UnauthenticatedSender(e)) => {
+                                warn!("Rejecting message from {} - {}", addr, e);
+                                continue;
+                            }
+                            Err(e) => {
+                                // NOTE: In the future, we might want to blacklist people who send
+                                // us garbage all the time.
+                                error!("Error parsing protobuf: {:?}", e);
+                                continue;
+                            }
+                        };
 
                     let msg: Swim = match protobuf::parse_from_bytes(&swim_payload) {
                         Ok(msg) => msg,
@@ -78,7 +92,17 @@ impl Inbound {
                             continue;
                         }
                     };
+                    if let Err(e) = self.check_sender(&msg, sender.as_ref().map(|s| s.as_str())) {
+                        warn!("Rejecting message from {} - {}", addr, e);
+                        metrics::rejected(&e);
+                        continue;
+                    }
                     debug!("SWIM Message: {:?}", msg);
+                    match msg.get_field_type() {
+                        Swim_Type::PING => metrics::swim_message("ping", "received", length),
+                        Swim_Type::ACK => metrics::swim_message("ack", "received", length),
+                        Swim_Type::PINGREQ => metrics::swim_message("pingreq", "received", length),
+                    }
                     match msg.get_field_type() {
                         Swim_Type::PING => {
                             if self.server.check_blacklist(msg.get_ping().get_from().get_id()) {
@@ -125,6 +149,24 @@ impl Inbound {
         }
     }
 
+    /// Check that a message comes from the member it claims to, if the ring authenticates its
+    /// members. An ack forwarded to us was passed on by the member we sent a pingreq to, rather
+    /// than sent by its target, so only the forwarding member is authenticated.
+    fn check_sender(&self, msg: &Swim, sender: Option<&str>) -> Result<()> {
+        let from = match msg.get_field_type() {
+            Swim_Type::PING => msg.get_ping().get_from(),
+            Swim_Type::ACK => {
+                if msg.get_ack().has_forward_to() &&
+                   msg.get_ack().get_forward_to().get_id() == self.server.member_id() {
+                    return Ok(());
+                }
+                msg.get_ack().get_from()
+            }
+            Swim_Type::PINGREQ => msg.get_pingreq().get_from(),
+        };
+        message::check_sender(sender, from.get_id())
+    }
+
     /// Process pingreq messages.
     fn process_pingreq(&self, addr: SocketAddr, mut msg: Swim) {
         trace_it!(SWIM: &self.server,
@@ -0,0 +1,170 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus metrics for the gossip layer.
+//!
+//! The metrics are registered with the default registry, so a process running a `Server` serves
+//! them alongside its own.
+
+use prometheus::{CounterVec, GaugeVec, Histogram, HistogramVec};
+use time::SteadyTime;
+
+use error::Error;
+use server::Server;
+
+/// Buckets for message sizes, in bytes; SWIM messages must fit in one datagram, rumors need not.
+const MESSAGE_BYTES_BUCKETS: &'static [f64] = &[64.0, 128.0, 256.0, 512.0, 1024.0, 2048.0,
+                                                 4096.0, 16384.0, 65536.0];
+
+lazy_static! {
+    static ref SWIM_MESSAGES: CounterVec = register_counter_vec!(
+        opts!(
+            "butterfly_swim_messages_total",
+            "Total number of SWIM messages sent and received."),
+        &["type", "direction"]).unwrap();
+
+    static ref SWIM_MESSAGE_BYTES: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "butterfly_swim_message_bytes",
+            "Size of the SWIM messages sent and received, in bytes.",
+            MESSAGE_BYTES_BUCKETS.to_vec()),
+        &["direction"]).unwrap();
+
+    static ref GOSSIP_MESSAGE_BYTES: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "butterfly_gossip_message_bytes",
+            "Size of the rumors pushed to and pulled from other members, in bytes.",
+            MESSAGE_BYTES_BUCKETS.to_vec()),
+        &["direction"]).unwrap();
+
+    static ref PROBE_DURATION: Histogram = register_histogram!(
+        histogram_opts!(
+            "butterfly_probe_duration_seconds",
+            "Time from pinging a member until it acknowledges, directly or through a pingreq.")
+        ).unwrap();
+
+    static ref PROBES: CounterVec = register_counter_vec!(
+        opts!(
+            "butterfly_probes_total",
+            "Total number of members probed, by how the probe ended."),
+        &["result"]).unwrap();
+
+    static ref HEALTH_TRANSITIONS: CounterVec = register_counter_vec!(
+        opts!(
+            "butterfly_member_health_transitions_total",
+            "Total number of members this member marked suspect or confirmed."),
+        &["health"]).unwrap();
+
+    static ref ROUND_DURATION: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "butterfly_round_duration_seconds",
+            "Time taken by each round of the SWIM and gossip protocols."),
+        &["protocol"]).unwrap();
+
+    static ref REJECTED_MESSAGES: CounterVec = register_counter_vec!(
+        opts!(
+            "butterfly_rejected_messages_total",
+            "Total number of received messages which could not be unwrapped, by reason."),
+        &["reason"]).unwrap();
+
+    static ref RUMORS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "butterfly_rumors",
+            "Number of rumors held, by type."),
+        &["type"]).unwrap();
+}
+
+/// The seconds elapsed since `start`, as metrics record durations.
+pub fn seconds_since(start: SteadyTime) -> f64 {
+    let elapsed = SteadyTime::now() - start;
+    match elapsed.num_microseconds() {
+        Some(us) => us as f64 / 1_000_000.0,
+        None => elapsed.num_seconds() as f64,
+    }
+}
+
+/// Count a SWIM message of `kind` ("ping", "ack", "pingreq" or "forward_ack"), sent or received
+/// in `direction`, and its size.
+pub fn swim_message(kind: &str, direction: &str, bytes: usize) {
+    SWIM_MESSAGES.with_label_values(&[kind, direction]).inc();
+    SWIM_MESSAGE_BYTES.with_label_values(&[direction]).observe(bytes as f64);
+}
+
+/// Record the size of a rumor pushed ("sent") or pulled ("received").
+pub fn gossip_message(direction: &str, bytes: usize) {
+    GOSSIP_MESSAGE_BYTES.with_label_values(&[direction]).observe(bytes as f64);
+}
+
+/// Record the end of a probe: "ack" and "pingreq_ack" probes took `seconds`, "failed" probes
+/// timed out.
+pub fn probe(result: &str, seconds: Option<f64>) {
+    PROBES.with_label_values(&[result]).inc();
+    if let Some(seconds) = seconds {
+        PROBE_DURATION.observe(seconds);
+    }
+}
+
+/// Count a member marked "suspect" or "confirmed" by this member.
+pub fn health_transition(health: &str) {
+    HEALTH_TRANSITIONS.with_label_values(&[health]).inc();
+}
+
+/// Record how long a round of the "swim" or "gossip" protocol took.
+pub fn round(protocol: &str, seconds: f64) {
+    ROUND_DURATION.with_label_values(&[protocol]).observe(seconds);
+}
+
+/// Count a received message rejected with `err`.
+pub fn rejected(err: &Error) {
+    let reason = match *err {
+        Error::RevokedMember(_) => "revoked",
+        Error::UnauthenticatedSender(_) => "unauthenticated",
+        Error::UnauthorizedSigner(_) => "unauthorized",
+        Error::HabitatCore(_) => "decrypt",
+        _ => "malformed",
+    };
+    REJECTED_MESSAGES.with_label_values(&[reason]).inc();
+}
+
+/// Record how many rumors of each type the server holds.
+pub fn rumors(server: &Server) {
+    RUMORS.with_label_values(&["member"]).set(server.member_list.len() as f64);
+    RUMORS.with_label_values(&["service"]).set(server.service_store.len() as f64);
+    RUMORS.with_label_values(&["service_config"])
+        .set(server.service_config_store.len() as f64);
+    RUMORS.with_label_values(&["service_file"]).set(server.service_file_store.len() as f64);
+    RUMORS.with_label_values(&["election"]).set(server.election_store.len() as f64);
+    RUMORS.with_label_values(&["election_update"]).set(server.update_store.len() as f64);
+    RUMORS.with_label_values(&["update_directive"])
+        .set(server.update_directive_store.len() as f64);
+    RUMORS.with_label_values(&["departure"]).set(server.departure_store.len() as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use error::Error;
+    use server::metrics::{REJECTED_MESSAGES, rejected};
+
+    #[test]
+    fn rejected_messages_are_counted_by_reason() {
+        let revoked = REJECTED_MESSAGES.with_label_values(&["revoked"]).get();
+        let malformed = REJECTED_MESSAGES.with_label_values(&["malformed"]).get();
+        rejected(&Error::RevokedMember("mallory-20170101000000".to_string()));
+        rejected(&Error::SocketCloneError);
+        assert_eq!(REJECTED_MESSAGES.with_label_values(&["revoked"]).get(),
+                   revoked + 1.0);
+        assert_eq!(REJECTED_MESSAGES.with_label_values(&["malformed"]).get(),
+                   malformed + 1.0);
+    }
+}
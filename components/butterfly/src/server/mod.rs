@@ -21,6 +21,7 @@
 
 pub mod expire;
 pub mod inbound;
+pub mod metrics;
 pub mod outbound;
 pub mod pull;
 pub mod push;
@@ -36,7 +37,7 @@ use std::path::PathBuf;
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
 use std::sync::mpsc::channel;
 use std::time::{Instant, Duration};
 use std::thread;
@@ -48,14 +49,20 @@ use serde::ser::SerializeStruct;
 use toml;
 
 use error::{Result, Error};
+use identity::Identity;
 use member::{Member, Health, MemberList};
 use message;
+use message::swim::{Rumor as ProtoRumor, Rumor_Type};
+use partition::Partition;
+use ring_keys::RingKeys;
 use rumor::{Rumor, RumorList, RumorKey, RumorStore};
 use rumor::dat_file::DatFile;
+use rumor::departure::Departure;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use rumor::election::{Election, ElectionUpdate};
+use rumor::update_directive::UpdateDirective;
 use trace::{Trace, TraceKind};
 
 pub trait Suitability: Debug + Send + Sync {
@@ -69,13 +76,17 @@ pub struct Server {
     pub member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    pub ring_key: Arc<Option<SymKey>>,
+    pub ring_keys: Arc<RingKeys>,
+    pub partition: Arc<Partition>,
+    pub identity: Arc<Option<Identity>>,
     pub rumor_list: RumorList,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
     pub service_file_store: RumorStore<ServiceFile>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    pub update_directive_store: RumorStore<UpdateDirective>,
+    pub departure_store: RumorStore<Departure>,
     pub swim_addr: Arc<RwLock<SocketAddr>>,
     pub gossip_addr: Arc<RwLock<SocketAddr>>,
     pub suitability_lookup: Arc<Box<Suitability>>,
@@ -87,6 +98,7 @@ pub struct Server {
     pub swim_rounds: Arc<AtomicIsize>,
     pub gossip_rounds: Arc<AtomicIsize>,
     pub blacklist: Arc<RwLock<HashSet<String>>>,
+    pub expired_rumors: Arc<AtomicUsize>,
 }
 
 impl Server {
@@ -115,13 +127,17 @@ impl Server {
                        member_id: Arc::new(String::from(member.get_id())),
                        member: Arc::new(RwLock::new(member)),
                        member_list: MemberList::new(),
-                       ring_key: Arc::new(ring_key),
+                       ring_keys: Arc::new(RingKeys::new(ring_key)),
+                       partition: Arc::new(Partition::new()),
+                       identity: Arc::new(None),
                        rumor_list: RumorList::default(),
                        service_store: RumorStore::default(),
                        service_config_store: RumorStore::default(),
                        service_file_store: RumorStore::default(),
                        election_store: RumorStore::default(),
                        update_store: RumorStore::default(),
+                       update_directive_store: RumorStore::default(),
+                       departure_store: RumorStore::default(),
                        swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                        gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                        suitability_lookup: Arc::new(suitability_lookup),
@@ -132,6 +148,7 @@ impl Server {
                        swim_rounds: Arc::new(AtomicIsize::new(0)),
                        gossip_rounds: Arc::new(AtomicIsize::new(0)),
                        blacklist: Arc::new(RwLock::new(HashSet::new())),
+                       expired_rumors: Arc::new(AtomicUsize::new(0)),
                    })
             }
             (Err(e), _) | (_, Err(e)) => Err(Error::CannotBind(e)),
@@ -142,6 +159,66 @@ impl Server {
         }
     }
 
+    /// Sign every message we send and every rumor we start with a member identity, and only
+    /// accept messages and rumors from unrevoked members certified by the same ring authority.
+    /// Our member id becomes the name of the member key, since receivers check that messages and
+    /// rumors are from the member which signed them.
+    /// Must be called before `start`, as the running threads keep the identity they were started
+    /// with.
+    pub fn set_identity(&mut self, identity: Identity) {
+        let member_id = identity.member_name().to_string();
+        self.member
+            .write()
+            .expect("Member lock is poisoned")
+            .set_id(member_id.clone());
+        self.member_id = Arc::new(member_id);
+        self.identity = Arc::new(Some(identity));
+    }
+
+    /// Accept every revision of our ring key in the key cache, and encrypt with the newest one
+    /// which has been there for the `activation` period, so the ring key can be rotated without
+    /// restarting the ring. Does nothing without a ring key.
+    pub fn watch_ring_keys<P: Into<PathBuf>>(&self, cache_key_path: P, activation: Duration) {
+        self.ring_keys.watch(cache_key_path, activation);
+    }
+
+    /// Give up quorum in every service group while we are in a minority partition, so that the
+    /// leaders we elected step down rather than compete with the leaders of the majority.
+    pub fn set_partition_step_down(&self, step_down: bool) {
+        self.partition.set_step_down(step_down);
+    }
+
+    /// Count the persistent members of the ring, and how many of them are reachable, warning when
+    /// we enter or leave a minority partition.
+    pub fn check_partition(&self) {
+        let mut persistent = 0;
+        let mut reachable = 0;
+        if self.member.read().expect("Member lock is poisoned").get_persistent() {
+            persistent += 1;
+            reachable += 1;
+        }
+        self.member_list.with_members(|member| if member.get_persistent() &&
+                                                   member.get_id() != self.member_id() {
+            persistent += 1;
+            if !self.member_list.check_health_of(member, Health::Confirmed) {
+                reachable += 1;
+            }
+        });
+        match self.partition.update(persistent, reachable) {
+            Some(true) => {
+                warn!("In a minority partition; {} of {} persistent members are reachable",
+                      reachable,
+                      persistent)
+            }
+            Some(false) => {
+                warn!("Left a minority partition; {} of {} persistent members are reachable",
+                      reachable,
+                      persistent)
+            }
+            None => (),
+        }
+    }
+
     /// Every iteration of the outbound protocol (which means every member has been pinged if they
     /// are available) increments the round. If we exceed an isize in rounds, we reset to 0.
     ///
@@ -351,6 +428,9 @@ impl Server {
 
     /// Insert a member to the `MemberList`, and update its `RumorKey` appropriately.
     pub fn insert_member(&self, member: Member, health: Health) {
+        if self.is_departed(member.get_id()) {
+            return;
+        }
         let rk: RumorKey = RumorKey::from(&member);
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
         // of just how the logic goes. The value of the trace is really high, though, so we suck it
@@ -389,6 +469,9 @@ impl Server {
 
     /// Given a membership record and some health, insert it into the Member List.
     pub fn insert_member_from_rumor(&self, member: Member, mut health: Health) {
+        if self.is_departed(member.get_id()) {
+            return;
+        }
         let mut incremented_incarnation = false;
         let rk: RumorKey = RumorKey::from(&member);
         if member.get_id() == self.member_id() {
@@ -401,6 +484,21 @@ impl Server {
                 incremented_incarnation = true;
             }
         }
+        if incremented_incarnation {
+            // The rest of the ring thought we were dead, and may have expired our services in
+            // the meantime; share them again, with a newer incarnation to get past the
+            // tombstones they left.
+            let mut services = Vec::new();
+            self.service_store.with_keys(|(_, rumors)| if let Some(service) =
+                rumors.get(self.member_id()) {
+                services.push(service.clone());
+            });
+            for mut service in services.into_iter() {
+                let incarnation = service.get_incarnation() + 1;
+                service.set_incarnation(incarnation);
+                self.insert_service(service);
+            }
+        }
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
         // of just how the logic goes. The value of the trace is really high, though, so we suck it
         // for now.
@@ -427,9 +525,17 @@ impl Server {
 
     /// Insert a service rumor into the service store.
     pub fn insert_service(&self, service: Service) {
+        if self.is_departed(service.get_member_id()) {
+            return;
+        }
         let rk = RumorKey::from(&service);
+        let service_group = String::from(service.get_service_group());
         if self.service_store.insert(service) {
             self.rumor_list.insert(rk);
+            // A service group with services again holds new elections, which mustn't lose to
+            // the tombstones of the ones it held before it was empty.
+            self.election_store.clear_tombstones(&service_group);
+            self.update_store.clear_tombstones(&service_group);
         }
     }
 
@@ -449,6 +555,105 @@ impl Server {
         }
     }
 
+    /// Insert an update directive rumor into the update directive store.
+    pub fn insert_update_directive(&self, update_directive: UpdateDirective) {
+        let rk = RumorKey::from(&update_directive);
+        if self.update_directive_store.insert(update_directive) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert a departure rumor into the departure store, and forget everything we know about the
+    /// departed member: its membership, and the services it was running. A departed member is
+    /// never allowed back into the ring.
+    pub fn insert_departure(&self, departure: Departure) {
+        let rk = RumorKey::from(&departure);
+        let member_id = String::from(departure.get_member_id());
+        if !self.departure_store.insert(departure) {
+            return;
+        }
+        self.rumor_list.insert(rk);
+        if member_id == self.member_id() {
+            warn!("This member has departed the ring, and will no longer take part in it");
+        } else {
+            self.add_to_blacklist(member_id.clone());
+        }
+        if self.member_list.remove(&member_id) {
+            debug!("Removed departed member {} from the member list", member_id);
+        }
+        self.rumor_list.remove(&RumorKey::new(Rumor_Type::Member, member_id.as_str(), ""));
+        for service_group in self.service_store.remove_id(&member_id) {
+            self.rumor_list
+                .remove(&RumorKey::new(Rumor_Type::Service, member_id.as_str(), service_group));
+        }
+    }
+
+    /// Expire the service rumors of a member, returning how many were expired. They leave
+    /// tombstones behind, so only newer service rumors from the member are accepted again.
+    pub fn expire_services_of(&self, member_id: &str) -> usize {
+        let service_groups = self.service_store.expire_id(member_id);
+        for service_group in service_groups.iter() {
+            self.rumor_list
+                .remove(&RumorKey::new(Rumor_Type::Service, member_id, service_group.as_str()));
+        }
+        self.expired_rumors.fetch_add(service_groups.len(), Ordering::Relaxed);
+        service_groups.len()
+    }
+
+    /// Expire the configuration, files and elections of a service group which has no services
+    /// left, returning how many rumors were expired. Its update directives are kept for when the
+    /// service group is started again.
+    pub fn expire_service_group(&self, service_group: &str) -> usize {
+        let expired =
+            self.expire_rumors_for_key(&self.service_config_store,
+                                       Rumor_Type::ServiceConfig,
+                                       service_group) +
+            self.expire_rumors_for_key(&self.service_file_store,
+                                       Rumor_Type::ServiceFile,
+                                       service_group) +
+            self.expire_rumors_for_key(&self.election_store, Rumor_Type::Election, service_group) +
+            self.expire_rumors_for_key(&self.update_store,
+                                       Rumor_Type::ElectionUpdate,
+                                       service_group);
+        self.expired_rumors.fetch_add(expired, Ordering::Relaxed);
+        expired
+    }
+
+    /// Forget the tombstones of rumors which expired longer than `ttl` ago.
+    pub fn purge_tombstones(&self, ttl: Duration) -> usize {
+        self.service_store.purge_tombstones(ttl) +
+        self.service_config_store.purge_tombstones(ttl) +
+        self.service_file_store.purge_tombstones(ttl) + self.election_store.purge_tombstones(ttl) +
+        self.update_store.purge_tombstones(ttl)
+    }
+
+    /// Returns how many rumors have expired since this server started.
+    pub fn expired_rumors(&self) -> usize {
+        self.expired_rumors.load(Ordering::Relaxed)
+    }
+
+    fn expire_rumors_for_key<T: Rumor>(&self,
+                                       store: &RumorStore<T>,
+                                       kind: Rumor_Type,
+                                       key: &str)
+                                       -> usize {
+        let ids = store.expire_key(key);
+        for id in ids.iter() {
+            self.rumor_list.remove(&RumorKey::new(kind, id.as_str(), key));
+        }
+        ids.len()
+    }
+
+    /// Returns true if the member has departed the ring.
+    pub fn is_departed(&self, member_id: &str) -> bool {
+        self.departure_store.contains_rumor(member_id, "departure")
+    }
+
+    /// Returns true if this server's own member has departed the ring.
+    pub fn departed(&self) -> bool {
+        self.is_departed(self.member_id())
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     pub fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -462,8 +667,14 @@ impl Server {
     /// Check if a given service group has quorum to run an election.
     ///
     /// A given group has quorum if, from this servers perspective, it has an alive population that
-    /// is over 50%, and at least 3 members.
+    /// is over 50%, and at least 3 members. No group has quorum while we step down in a minority
+    /// partition.
     pub fn check_quorum(&self, key: &str) -> bool {
+        if self.partition.stepping_down() {
+            info!("In a minority partition - election cannot complete");
+            return false;
+        }
+
         let electorate = self.get_electorate(key);
 
         let total_population = self.service_store.len_for_key(key);
@@ -503,6 +714,28 @@ impl Server {
         self.rumor_list.insert(ek);
     }
 
+    /// Approve the given package for the rest of the service group to update to, spreading the
+    /// approval with the finished update election. Returns false if the service group has no
+    /// finished update election to approve.
+    pub fn approve_update_election(&self, service_group: &str, pkg: &str) -> bool {
+        let mut election = None;
+        self.update_store.with_rumor(service_group, "election", |e| if let Some(e) = e {
+            if e.is_finished() {
+                election = Some(e.clone());
+            }
+        });
+        match election {
+            Some(mut election) => {
+                election.approve(pkg);
+                let ek = RumorKey::from(&election);
+                self.update_store.insert(election);
+                self.rumor_list.insert(ek);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Check to see if this server needs to restart a given election. This happens when:
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
@@ -749,10 +982,12 @@ impl Server {
         }
     }
 
+    /// Returns (incarnation, filename, body, encrypted) for each service file of the service group
+    /// which is newer than the incarnation given for it.
     pub fn service_files_for(&self,
                              service_group: &str,
                              current_service_files: &HashMap<String, u64>)
-                             -> Vec<(u64, String, Vec<u8>)> {
+                             -> Vec<(u64, String, Vec<u8>, bool)> {
         let mut service_files = Vec::new();
 
         self.service_file_store.with_rumors(service_group, |sf| {
@@ -763,7 +998,8 @@ impl Server {
                     Ok(body) => {
                         service_files.push((sf.get_incarnation(),
                                             String::from(sf.get_filename()),
-                                            body))
+                                            body,
+                                            sf.get_encrypted()))
                     }
                     Err(e) => {
                         warn!("Cannot decrypt service file for {} {} {}: {}",
@@ -778,11 +1014,11 @@ impl Server {
         service_files
     }
 
-    /// Returns (incarnation, config) if the service group has a configuration.
+    /// Returns (incarnation, config, encrypted) if the service group has a configuration.
     pub fn service_config_for(&self,
                               service_group: &str,
                               incarnation: Option<u64>)
-                              -> Option<(u64, toml::Value)> {
+                              -> Option<(u64, toml::Value, bool)> {
         let mut result = None;
         self.service_config_store.with_rumor(service_group,
                                              "service_config",
@@ -792,7 +1028,8 @@ impl Server {
                                                      match sc.config() {
                                                          Ok(config) => {
                                                              result = Some((sc.get_incarnation(),
-                                                                            config))
+                                                                            config,
+                                                                            sc.get_encrypted()))
                                                          }
                                                          Err(err) => warn!("{}", err),
                                                      }
@@ -801,12 +1038,61 @@ impl Server {
         result
     }
 
+    /// Returns the update directive of the service group, if one was given.
+    pub fn update_directive_for(&self, service_group: &str) -> Option<UpdateDirective> {
+        let mut result = None;
+        self.update_directive_store.with_rumor(service_group,
+                                               "update_directive",
+                                               |maybe_ud| result = maybe_ud.cloned());
+        result
+    }
+
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, &self.ring_key)
+        message::generate_wire(payload,
+                               self.ring_keys.active().as_ref(),
+                               (*self.identity).as_ref())
     }
 
-    fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, &self.ring_key)
+    fn unwrap_wire(&self, payload: &[u8]) -> Result<(Vec<u8>, Option<String>)> {
+        message::unwrap_wire(payload, &self.ring_keys.accepted(), (*self.identity).as_ref())
+            .map_err(|e| {
+                         metrics::rejected(&e);
+                         e
+                     })
+    }
+
+    /// Authenticate the member which started a rumor, when we have an identity, and check that
+    /// it may start it. Service rumors must be about the member which signed them, and so must
+    /// elections, unless they were signed by a member of the service group, since its members
+    /// vote by passing the election on with their votes added. Update directives must be signed
+    /// by a ring operator, and departures too, unless a member departs itself.
+    fn authorize_rumor(&self, rumor: &ProtoRumor) -> Result<()> {
+        let identity = match *self.identity {
+            Some(ref identity) => identity,
+            None => return Ok(()),
+        };
+        let signer = try!(message::rumor_signer(rumor, identity));
+        let authorized = match rumor.get_field_type() {
+            Rumor_Type::Service => rumor.get_service().get_member_id() == signer,
+            Rumor_Type::Election |
+            Rumor_Type::ElectionUpdate => {
+                let election = rumor.get_election();
+                election.get_member_id() == signer ||
+                self.service_store.contains_rumor(election.get_service_group(), &signer)
+            }
+            Rumor_Type::UpdateDirective => identity.is_operator(&signer),
+            Rumor_Type::Departure => {
+                identity.is_operator(&signer) || rumor.get_departure().get_member_id() == signer
+            }
+            _ => true,
+        };
+        if authorized {
+            Ok(())
+        } else {
+            Err(Error::UnauthorizedSigner(format!("member {} started a {:?} rumor",
+                                                  signer,
+                                                  rumor.get_field_type())))
+        }
     }
 
     fn persist_data(&self) {
@@ -822,13 +1108,18 @@ impl Serialize for Server {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("butterfly", 6));
+        let mut strukt = try!(serializer.serialize_struct("butterfly", 11));
         try!(strukt.serialize_field("member", &self.member_list));
         try!(strukt.serialize_field("service", &self.service_store));
         try!(strukt.serialize_field("service_config", &self.service_config_store));
         try!(strukt.serialize_field("service_file", &self.service_file_store));
         try!(strukt.serialize_field("election", &self.election_store));
         try!(strukt.serialize_field("election_update", &self.update_store));
+        try!(strukt.serialize_field("update_directive", &self.update_directive_store));
+        try!(strukt.serialize_field("departure", &self.departure_store));
+        try!(strukt.serialize_field("expired_rumors", &self.expired_rumors()));
+        try!(strukt.serialize_field("ring_keys", &*self.ring_keys));
+        try!(strukt.serialize_field("partition", &*self.partition));
         strukt.end()
     }
 }
@@ -861,12 +1152,20 @@ fn persist_loop(server: Server) {
 #[cfg(test)]
 mod tests {
     mod server {
+        use habitat_core::package::{Identifiable, PackageIdent};
         use habitat_core::service::ServiceGroup;
         use server::{Server, Suitability};
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
+        use rumor::departure::Departure;
+        use rumor::election::Election;
+        use rumor::service::{Service, SysInfo};
+        use rumor::service_config::ServiceConfig;
+        use rumor::service_file::ServiceFile;
+        use rumor::update_directive::{UpdateDirective, UpdateDirective_Action};
         use trace::Trace;
         use std::path::PathBuf;
+        use std::str::FromStr;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
         static SWIM_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
@@ -927,5 +1226,127 @@ mod tests {
             let mut server = start_server();
             server.start(Timing::default()).expect("Server failed to start");
         }
+
+        #[test]
+        fn insert_departure_purges_the_member() {
+            let server = start_server();
+            let member = Member::default();
+            let member_id = String::from(member.get_id());
+            server.insert_member(member.clone(), Health::Alive);
+            let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
+            let sg = ServiceGroup::new(pkg.name(), "production", None).unwrap();
+            server.insert_service(Service::new(member_id.as_str(),
+                                               &pkg,
+                                               &sg,
+                                               &SysInfo::default(),
+                                               None));
+            assert_eq!(server.service_store.len(), 1);
+
+            server.insert_departure(Departure::new("butterflyclient", member_id.as_str()));
+            assert!(server.is_departed(&member_id));
+            assert!(!server.member_list.contains_member(&member_id));
+            assert_eq!(server.service_store.len(), 0);
+            assert!(server.check_blacklist(&member_id));
+
+            server.insert_member(member, Health::Alive);
+            assert!(!server.member_list.contains_member(&member_id));
+        }
+
+        #[test]
+        fn expire_services_of_counts_expired_rumors() {
+            let server = start_server();
+            let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
+            let sg = ServiceGroup::new(pkg.name(), "production", None).unwrap();
+            server.insert_service(Service::new("adam", &pkg, &sg, &SysInfo::default(), None));
+            server.insert_service(Service::new("eve", &pkg, &sg, &SysInfo::default(), None));
+            assert_eq!(server.expire_services_of("adam"), 1);
+            assert_eq!(server.service_store.len(), 1);
+            assert_eq!(server.expire_services_of("adam"), 0);
+            assert_eq!(server.expired_rumors(), 1);
+        }
+
+        #[test]
+        fn expired_services_come_back_only_with_a_newer_incarnation() {
+            let server = start_server();
+            let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
+            let sg = ServiceGroup::new(pkg.name(), "production", None).unwrap();
+            let service = Service::new("adam", &pkg, &sg, &SysInfo::default(), None);
+            server.insert_service(service.clone());
+            server.expire_services_of("adam");
+            server.insert_service(service.clone());
+            assert_eq!(server.service_store.len(), 0);
+
+            let mut newer = service;
+            newer.set_incarnation(1);
+            server.insert_service(newer);
+            assert_eq!(server.service_store.len(), 1);
+        }
+
+        #[test]
+        fn expire_service_group_keeps_only_its_update_directives() {
+            let server = start_server();
+            let sg = ServiceGroup::new("neurosis", "production", None).unwrap();
+            server.insert_service_config(ServiceConfig::new("adam",
+                                                            sg.clone(),
+                                                            Vec::from("port = 6379")));
+            server.insert_service_file(ServiceFile::new("adam",
+                                                        sg.clone(),
+                                                        "yep",
+                                                        Vec::from("tcp-backlog = 128")));
+            server.election_store.insert(Election::new("adam", sg.clone(), 0));
+            server.insert_update_directive(UpdateDirective::new("adam",
+                                                                sg.clone(),
+                                                                UpdateDirective_Action::Pause,
+                                                                None));
+            assert_eq!(server.expire_service_group("neurosis.production"), 3);
+            assert_eq!(server.election_store.len(), 0);
+            assert_eq!(server.service_config_store.len(), 0);
+            assert_eq!(server.service_file_store.len(), 0);
+            assert_eq!(server.update_directive_store.len(), 1);
+            assert_eq!(server.expired_rumors(), 3);
+        }
+
+        #[test]
+        fn check_partition_counts_reachable_persistent_members() {
+            let server = start_server();
+            server.member.write().expect("Member lock is poisoned").set_persistent(true);
+            for health in vec![Health::Alive, Health::Confirmed, Health::Confirmed] {
+                let mut member = Member::default();
+                member.set_persistent(true);
+                server.insert_member(member, health);
+            }
+            server.check_partition();
+            assert_eq!(server.partition.persistent_members(), 4);
+            assert_eq!(server.partition.reachable_members(), 2);
+            assert!(server.partition.in_minority());
+        }
+
+        #[test]
+        fn stepping_down_in_a_minority_partition_gives_up_quorum() {
+            let server = start_server();
+            let pkg = PackageIdent::from_str("core/neurosis/1.2.3/20161208121212").unwrap();
+            let sg = ServiceGroup::new(pkg.name(), "production", None).unwrap();
+            for _ in 0..3 {
+                let mut member = Member::default();
+                member.set_persistent(true);
+                server.insert_service(Service::new(member.get_id(),
+                                                   &pkg,
+                                                   &sg,
+                                                   &SysInfo::default(),
+                                                   None));
+                server.insert_member(member, Health::Alive);
+            }
+            for _ in 0..3 {
+                let mut member = Member::default();
+                member.set_persistent(true);
+                server.insert_member(member, Health::Confirmed);
+            }
+            server.check_partition();
+            assert!(server.partition.in_minority());
+            assert!(server.check_quorum("neurosis.production"));
+
+            server.set_partition_step_down(true);
+            assert!(!server.check_quorum("neurosis.production"));
+        }
     }
 }
@@ -27,7 +27,7 @@ use time::SteadyTime;
 use protobuf::{Message, RepeatedField};
 
 use message::swim::{Ack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
-use server::Server;
+use server::{Server, metrics};
 use server::timing::Timing;
 use member::{Member, Health};
 use trace::TraceKind;
@@ -106,6 +106,7 @@ impl Outbound {
             }
 
             self.server.update_swim_round();
+            let round_start = SteadyTime::now();
 
             let long_wait = self.timing.next_protocol_period();
 
@@ -134,6 +135,7 @@ impl Outbound {
                     }
                 }
             }
+            metrics::round("swim", metrics::seconds_since(round_start));
 
             if SteadyTime::now() <= long_wait {
                 let wait_time = (long_wait - SteadyTime::now()).num_milliseconds();
@@ -166,8 +168,10 @@ impl Outbound {
         trace_it!(PROBE: &self.server, TraceKind::ProbeBegin, member.get_id(), addr);
 
         // Ping the member, and wait for the ack.
+        let probe_start = SteadyTime::now();
         ping(&self.server, &self.socket, &member, addr, None);
         if self.recv_ack(&member, addr, AckFrom::Ping) {
+            metrics::probe("ack", Some(metrics::seconds_since(probe_start)));
             trace_it!(PROBE: &self.server, TraceKind::ProbeAckReceived, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             return;
@@ -187,10 +191,13 @@ impl Outbound {
             // into the suspicion phase, where anyone marked as suspect has a certain number of
             // protocol periods to recover.
             warn!("Marking {} as Suspect", member.get_id());
+            metrics::probe("failed", None);
+            metrics::health_transition("suspect");
             trace_it!(PROBE: &self.server, TraceKind::ProbeSuspect, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             self.server.insert_member(member, Health::Suspect);
         } else {
+            metrics::probe("pingreq_ack", Some(metrics::seconds_since(probe_start)));
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
         }
     }
@@ -252,7 +259,10 @@ pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut S
     }
     let rumors = server.rumor_list.take_by_kind(target.get_id(), 5, Rumor_Type::Member);
     for &(ref rkey, _heat) in rumors.iter() {
-        membership_entries.push(server.member_list.membership_for(&rkey.key()));
+        // A departed member may have been removed since the rumors were taken.
+        if server.member_list.contains_member(&rkey.key()) {
+            membership_entries.push(server.member_list.membership_for(&rkey.key()));
+        }
     }
     // We don't want to update the heat for rumors that we know we are sending to a target that is
     // confirmed dead; the odds are, they won't receive them. Lets spam them a little harder with
@@ -292,6 +302,7 @@ pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, tar
     };
     match socket.send_to(&payload, addr) {
         Ok(_s) => {
+            metrics::swim_message("pingreq", "sent", payload.len());
             info!("Sent PingReq to {}@{} for {}@{}",
                   pingreq_target.get_id(),
                   addr,
@@ -351,6 +362,7 @@ pub fn ping(server: &Server,
 
     match socket.send_to(&payload, addr) {
         Ok(_s) => {
+            metrics::swim_message("ping", "sent", payload.len());
             if forward_to.is_some() {
                 info!("Sent Ping to {} on behalf of {}@{}",
                       addr,
@@ -394,6 +406,7 @@ pub fn forward_ack(server: &Server, socket: &UdpSocket, addr: SocketAddr, swim:
 
     match socket.send_to(&payload, addr) {
         Ok(_s) => {
+            metrics::swim_message("forward_ack", "sent", payload.len());
             info!("Forwarded ack to {}@{}",
                   swim.get_ack().get_from().get_id(),
                   addr)
@@ -444,6 +457,7 @@ pub fn ack(server: &Server,
 
     match socket.send_to(&payload, addr) {
         Ok(_s) => {
+            metrics::swim_message("ack", "sent", payload.len());
             info!("Sent ack to {}@{}",
                   swim.get_ack().get_from().get_id(),
                   addr)
@@ -24,7 +24,8 @@ use protobuf;
 use zmq;
 
 use ZMQ_CONTEXT;
-use server::Server;
+use error::Error;
+use server::{Server, metrics};
 use message::swim::{Rumor, Rumor_Type};
 use trace::TraceKind;
 
@@ -63,8 +64,17 @@ impl Pull {
                     continue 'recv;
                 }
             };
+            metrics::gossip_message("received", msg.len());
             let payload = match self.server.unwrap_wire(&msg) {
-                Ok(payload) => payload,
+                Ok((payload, _)) => payload,
+                Err(Error::RevokedMember(member)) => {
+                    warn!("Rejecting rumor - member key {} is revoked", member);
+                    continue 'recv;
+                }
+                Err(Error::UnauthenticatedSender(e)) => {
+                    warn!("Rejecting rumor - {}", e);
+                    continue 'recv;
+                }
                 Err(e) => {
                     // NOTE: In the future, we might want to blacklist people who send us
                     // garbage all the time.
@@ -79,6 +89,11 @@ impl Pull {
                     continue 'recv;
                 }
             };
+            if let Err(e) = self.server.authorize_rumor(&proto) {
+                warn!("Rejecting rumor - {}", e);
+                metrics::rejected(&e);
+                continue 'recv;
+            }
             if self.server.check_blacklist(proto.get_from_id()) {
                 warn!("Not processing message from {} - it is blacklisted",
                       proto.get_from_id());
@@ -106,6 +121,12 @@ impl Pull {
                 Rumor_Type::ElectionUpdate => {
                     self.server.insert_update_election(proto.into());
                 }
+                Rumor_Type::UpdateDirective => {
+                    self.server.insert_update_directive(proto.into());
+                }
+                Rumor_Type::Departure => {
+                    self.server.insert_departure(proto.into());
+                }
                 Rumor_Type::Fake |
                 Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
             }
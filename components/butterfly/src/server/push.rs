@@ -21,16 +21,18 @@ use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
 
-use protobuf::Message;
+use protobuf::{self, Message};
 use time::SteadyTime;
 use zmq;
 
 use ZMQ_CONTEXT;
+use error::{Error, Result};
+use message;
 use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Member as ProtoMember,
                     Membership as ProtoMembership};
 use rumor::{RumorKey, RumorVec};
 use member::Member;
-use server::Server;
+use server::{Server, metrics};
 use server::timing::Timing;
 use trace::TraceKind;
 
@@ -64,6 +66,7 @@ impl Push {
             }
 
             self.server.update_gossip_round();
+            let round_start = SteadyTime::now();
 
             let mut check_list = self.server.member_list.check_list(self.server.member_id());
             let long_wait = self.timing.gossip_timeout();
@@ -120,6 +123,7 @@ impl Push {
                     }
                 }
             }
+            metrics::round("gossip", metrics::seconds_since(round_start));
             if SteadyTime::now() < long_wait {
                 let wait_time = (long_wait - SteadyTime::now()).num_milliseconds();
                 if wait_time > 0 {
@@ -256,12 +260,43 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::UpdateDirective => {
+                    match self.server.update_directive_store.write_to_bytes(&rumor_key.key,
+                                                                            &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::Departure => {
+                    match self.server.departure_store.write_to_bytes(&rumor_key.key,
+                                                                     &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Fake |
                 ProtoRumor_Type::Fake2 => {
                     debug!("You have fake rumors; how odd!");
                     continue 'rumorlist;
                 }
             };
+            let rumor_as_bytes = match self.signed(rumor_as_bytes) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    error!("Cannot pass on rumor {:?}: {}", rumor_key, e);
+                    continue 'rumorlist;
+                }
+            };
             let payload = match self.server.generate_wire(rumor_as_bytes) {
                 Ok(payload) => payload,
                 Err(e) => {
@@ -270,13 +305,34 @@ impl PushWorker {
                 }
             };
             match socket.send(&payload, 0) {
-                Ok(()) => debug!("Sent rumor {:?} to {:?}", rumor_key, member),
+                Ok(()) => {
+                    metrics::gossip_message("sent", payload.len());
+                    debug!("Sent rumor {:?} to {:?}", rumor_key, member)
+                }
                 Err(e) => println!("Could not send rumor to {:?}; ZMQ said: {:?}", member, e),
             }
         }
         self.server.rumor_list.update_heat(member.get_id(), &rumors);
     }
 
+    /// In a ring which authenticates its members, every rumor is signed by the member which
+    /// started it. A rumor still signed by that member is passed on as it is; one we started, or
+    /// changed since, such as an election we added our vote to, is signed by us. We do not pass
+    /// on rumors started by members which have since been revoked.
+    fn signed(&self, rumor_as_bytes: Vec<u8>) -> Result<Vec<u8>> {
+        let identity = match *self.server.identity {
+            Some(ref identity) => identity,
+            None => return Ok(rumor_as_bytes),
+        };
+        let mut rumor: ProtoRumor = try!(protobuf::parse_from_bytes(&rumor_as_bytes));
+        match message::rumor_signer(&rumor, identity) {
+            Ok(_) => return Ok(rumor_as_bytes),
+            Err(Error::RevokedMember(member)) => return Err(Error::RevokedMember(member)),
+            Err(_) => try!(message::sign_rumor(&mut rumor, identity)),
+        }
+        Ok(try!(rumor.write_to_bytes()))
+    }
+
     /// Given a rumorkey, creates a protobuf rumor for sharing.
     fn create_member_rumor(&self, rumor_key: &RumorKey) -> ProtoRumor {
         let mut member: ProtoMember = ProtoMember::new();
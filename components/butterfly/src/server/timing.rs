@@ -22,6 +22,8 @@ const PINGREQ_TIMING_DEFAULT_MS: i64 = 2100;
 const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
+/// How long the rumors of dead members and empty service groups are kept before they expire.
+const RUMOR_TTL_DEFAULT_MS: i64 = 86_400_000;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -30,6 +32,7 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    pub rumor_ttl_ms: i64,
 }
 
 impl Default for Timing {
@@ -39,6 +42,7 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
         }
     }
 }
@@ -55,6 +59,7 @@ impl Timing {
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
         }
     }
 
@@ -88,4 +93,9 @@ impl Timing {
         TimeDuration::milliseconds(self.protocol_period_ms() *
                                    self.suspicion_timeout_protocol_periods)
     }
+
+    /// How long before the rumors of a dead member or an empty service group expire
+    pub fn rumor_ttl_duration(&self) -> TimeDuration {
+        TimeDuration::milliseconds(self.rumor_ttl_ms)
+    }
 }
@@ -386,6 +386,16 @@ macro_rules! trace_it {
                                 $payload.get_election().get_status(),
                                 $payload.get_election().get_votes())
                     }
+                    Rumor_Type::UpdateDirective => {
+                        format!("{}-{}-{:?}-{}",
+                                $payload.get_update_directive().get_service_group(),
+                                $payload.get_update_directive().get_incarnation(),
+                                $payload.get_update_directive().get_action(),
+                                $payload.get_update_directive().get_pkg())
+                    }
+                    Rumor_Type::Departure => {
+                        format!("{}", $payload.get_departure().get_member_id())
+                    }
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
@@ -12,11 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use habitat_butterfly::identity::Identity;
 use habitat_butterfly::member::Health;
+use habitat_butterfly::rumor::departure::Departure;
+use habitat_core::crypto::{MemberCertificate, SigKeyPair};
 use habitat_core::crypto::keys::sym_key::SymKey;
+use habitat_core::crypto::member_cert;
+use time;
 
 use btest;
 
+fn key_cache() -> PathBuf {
+    let path = env::temp_dir().join(format!("butterfly-keys-{}", time::precise_time_ns()));
+    fs::create_dir_all(&path).expect("Failed to create a key cache");
+    path
+}
+
+fn member_identity(name: &str, authority: &SigKeyPair, cache: &PathBuf) -> Identity {
+    let key = SigKeyPair::generate_pair_for_origin(name, cache).expect("Failed to generate a key");
+    MemberCertificate::issue(&key, authority)
+        .and_then(|cert| cert.write_file(cache))
+        .expect("Failed to issue a member certificate");
+    Identity::load(name, cache).expect("Failed to load a member identity")
+}
+
 #[test]
 fn symmetric_encryption_of_wire_payloads() {
     let ring_key =
@@ -28,3 +53,103 @@ fn symmetric_encryption_of_wire_payloads() {
     net.wait_for_gossip_rounds(2);
     net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
 }
+
+#[test]
+fn member_identity_authentication_of_wire_payloads() {
+    let cache = key_cache();
+    let authority = SigKeyPair::generate_pair_for_origin("wolverine-authority", &cache)
+        .expect("Failed to generate an authority key");
+    let identities = vec![member_identity("logan", &authority, &cache),
+                          member_identity("jean", &authority, &cache)];
+    let mut net = btest::SwimNet::new_member_identities(identities);
+    net.connect(0, 1);
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+    let _ = fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn member_identity_rumors_are_passed_on_as_signed() {
+    let cache = key_cache();
+    let authority = SigKeyPair::generate_pair_for_origin("wolverine-authority", &cache)
+        .expect("Failed to generate an authority key");
+    let identities = vec![member_identity("logan", &authority, &cache),
+                          member_identity("jean", &authority, &cache),
+                          member_identity("scott", &authority, &cache)];
+    let mut net = btest::SwimNet::new_member_identities(identities);
+    net.connect(0, 1);
+    net.connect(1, 2);
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(3);
+    net[2].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+    let _ = fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn member_identity_departures_are_only_accepted_from_ring_operators() {
+    let cache = key_cache();
+    let authority = SigKeyPair::generate_pair_for_origin("wolverine-authority", &cache)
+        .expect("Failed to generate an authority key");
+    let mut identities = vec![member_identity("logan", &authority, &cache),
+                              member_identity("jean", &authority, &cache)];
+    for identity in identities.iter_mut() {
+        identity.set_operators(vec!["logan".to_string()]);
+    }
+    let mut net = btest::SwimNet::new_member_identities(identities);
+    net.connect(0, 1);
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+    net[0].insert_departure(Departure::new(net[0].member_id(), "victor"));
+    net[1].insert_departure(Departure::new(net[1].member_id(), "sabretooth"));
+    net.wait_for_gossip_rounds(2);
+    assert!(net[1].departure_store.contains_rumor("victor", "departure"));
+    assert!(!net[0].departure_store.contains_rumor("sabretooth", "departure"));
+    let _ = fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn member_identity_rejects_revoked_members() {
+    let cache = key_cache();
+    let authority = SigKeyPair::generate_pair_for_origin("wolverine-authority", &cache)
+        .expect("Failed to generate an authority key");
+    let identities = vec![member_identity("logan", &authority, &cache),
+                          member_identity("jean", &authority, &cache),
+                          member_identity("victor", &authority, &cache)];
+    member_cert::revoke_member("wolverine-authority", "victor", &cache)
+        .expect("Failed to revoke a member");
+    let mut net = btest::SwimNet::new_member_identities(identities);
+    net.connect(0, 1);
+    net.connect(2, 0);
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+    net.wait_for_rounds(2);
+    assert_eq!(net.health_of(0, 2), None);
+    assert_eq!(net.health_of(1, 2), None);
+    let _ = fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn rotated_ring_keys_are_accepted_before_they_are_active() {
+    let cache = key_cache();
+    let old_key = SymKey::generate_pair_for_ring("wolverine", &cache)
+        .expect("Failed to generate a ring key");
+    thread::sleep(Duration::from_millis(1100));
+    let new_key = SymKey::generate_pair_for_ring("wolverine", &cache)
+        .expect("Failed to rotate the ring key");
+    let mut net = btest::SwimNet::new_ring_encryption(2, Some(new_key.clone()));
+    net[0].watch_ring_keys(cache.clone(), Duration::from_secs(3600));
+    net[1].watch_ring_keys(cache.clone(), Duration::from_millis(0));
+    assert_eq!(net[0].ring_keys.active().map(|k| k.name_with_rev()),
+               Some(old_key.name_with_rev()));
+    assert_eq!(net[1].ring_keys.active().map(|k| k.name_with_rev()),
+               Some(new_key.name_with_rev()));
+    net.connect(0, 1);
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.add_service(1, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[1].service_store.with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+    net[0].service_store.with_rumor("beast.prod", net[1].member_id(), |u| assert!(u.is_some()));
+    let _ = fs::remove_dir_all(&cache);
+}
@@ -0,0 +1,31 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use btest;
+use habitat_butterfly::client::Client;
+
+#[test]
+fn departure_via_client() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+
+    net.wait_for_gossip_rounds(1);
+    let departed_id = String::from(net[2].member_id());
+    let mut client =
+        Client::new(net[0].gossip_addr(), None).expect("Cannot create Butterfly Client");
+    client.send_departure(departed_id.as_str()).expect("Cannot send the departure");
+    net.wait_for_gossip_rounds(1);
+    assert!(net[1].is_departed(&departed_id));
+    assert!(!net[1].member_list.contains_member(&departed_id));
+}
@@ -16,3 +16,5 @@ pub mod service;
 pub mod service_config;
 pub mod service_file;
 pub mod election;
+pub mod update_directive;
+pub mod departure;
@@ -45,6 +45,36 @@ impl SigKeyPair {
                      Some(secret_key)))
     }
 
+    /// Signs `data` with the secret key, returning the signed message.
+    pub fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(sign::sign(data, try!(self.secret())))
+    }
+
+    /// Verifies a signed message with the public key, returning the original data.
+    pub fn verify(&self, signed: &[u8]) -> Result<Vec<u8>> {
+        sign::verify(signed, try!(self.public())).map_err(|_| {
+            Error::CryptoError(format!("Signature verification failed for {}",
+                                       self.name_with_rev()))
+        })
+    }
+
+    /// Signs `data` with the secret key, returning only the signature.
+    pub fn sign_detached(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(sign::sign_detached(data, try!(self.secret())).0.to_vec())
+    }
+
+    /// Verifies a signature made by `sign_detached` over `data` with the public key.
+    pub fn verify_detached(&self, data: &[u8], signature: &[u8]) -> Result<()> {
+        let public = try!(self.public());
+        match sign::Signature::from_slice(signature) {
+            Some(ref signature) if sign::verify_detached(signature, data, public) => Ok(()),
+            _ => {
+                Err(Error::CryptoError(format!("Signature verification failed for {}",
+                                               self.name_with_rev())))
+            }
+        }
+    }
+
     fn mk_key_name(name: &str, revision: &str) -> String {
         format!("{}-{}", name, revision)
     }
@@ -467,6 +497,30 @@ mod test {
         assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
     }
 
+    #[test]
+    fn sign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signed = pair.sign(b"I wish to buy a shrubbery").unwrap();
+
+        assert_eq!(pair.verify(&signed).unwrap(), b"I wish to buy a shrubbery".to_vec());
+        let other = SigKeyPair::generate_pair_for_origin("dragon", cache.path()).unwrap();
+        assert!(other.verify(&signed).is_err());
+    }
+
+    #[test]
+    fn sign_and_verify_detached() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let signature = pair.sign_detached(b"I wish to buy a shrubbery").unwrap();
+
+        assert!(pair.verify_detached(b"I wish to buy a shrubbery", &signature).is_ok());
+        assert!(pair.verify_detached(b"I wish to buy a herring", &signature).is_err());
+        assert!(pair.verify_detached(b"I wish to buy a shrubbery", b"Ni!").is_err());
+        let other = SigKeyPair::generate_pair_for_origin("dragon", cache.path()).unwrap();
+        assert!(other.verify_detached(b"I wish to buy a shrubbery", &signature).is_err());
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
@@ -0,0 +1,316 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Certificates binding a ring member's key to the ring authority which issued it, and the lists
+//! of members an authority has revoked.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::str;
+
+use base64;
+use sodiumoxide::crypto::sign::ed25519::PublicKey as SigPublicKey;
+
+use error::{Error, Result};
+use super::{MEMBER_CERT_FORMAT_VERSION, MEMBER_CERT_SUFFIX, REVOKED_MEMBERS_SUFFIX, SigKeyPair};
+use super::keys::parse_name_with_rev;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemberCertificate {
+    /// The name with revision of the authority key which issued the certificate
+    pub authority: String,
+    /// The name with revision of the member key which is certified
+    pub member: String,
+    signed: Vec<u8>,
+}
+
+impl MemberCertificate {
+    /// Certifies the public key of `member` with the secret key of `authority`.
+    pub fn issue(member: &SigKeyPair, authority: &SigKeyPair) -> Result<Self> {
+        let content = format!("{}\n{}",
+                              member.name_with_rev(),
+                              base64::encode(&try!(member.public())[..]));
+        Ok(MemberCertificate {
+               authority: authority.name_with_rev(),
+               member: member.name_with_rev(),
+               signed: try!(authority.sign(content.as_bytes())),
+           })
+    }
+
+    /// Reads the certificate of a member key from the key cache.
+    pub fn get_for<P: AsRef<Path> + ?Sized>(member_with_rev: &str,
+                                            cache_key_path: &P)
+                                            -> Result<Self> {
+        let path = Self::mk_cert_filename(member_with_rev, cache_key_path.as_ref());
+        let mut content = String::new();
+        let mut file = try!(File::open(&path).map_err(|e| {
+            Error::CryptoError(format!("Can't read member certificate {}: {}",
+                                       path.display(),
+                                       e))
+        }));
+        try!(file.read_to_string(&mut content));
+        Self::parse(&content)
+    }
+
+    /// Parses the string form of a certificate, as written by its `Display` implementation.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        match lines.next() {
+            Some(val) if val == MEMBER_CERT_FORMAT_VERSION => (),
+            Some(val) => {
+                return Err(Error::CryptoError(format!("Unsupported member certificate \
+                                                       version: {}",
+                                                      val)))
+            }
+            None => return Err(Error::CryptoError("Can't read format version".to_string())),
+        }
+        let authority = match lines.next() {
+            Some(val) => try!(Self::parse_key_name(val)),
+            None => return Err(Error::CryptoError("Can't read authority key name".to_string())),
+        };
+        let member = match lines.next() {
+            Some(val) => try!(Self::parse_key_name(val)),
+            None => return Err(Error::CryptoError("Can't read member key name".to_string())),
+        };
+        let signed = match lines.nth(1) {
+            Some(val) => {
+                try!(base64::decode(val.trim()).map_err(|e| {
+                    Error::CryptoError(format!("Can't decode member certificate: {}", e))
+                }))
+            }
+            None => return Err(Error::CryptoError("Can't read signed member key".to_string())),
+        };
+        Ok(MemberCertificate {
+               authority: authority,
+               member: member,
+               signed: signed,
+           })
+    }
+
+    /// Verifies the certificate with the public key of `authority`, returning the certified member
+    /// key.
+    ///
+    /// # Errors
+    ///
+    /// * If the certificate was issued by a different authority key
+    /// * If the signature does not verify with the authority's public key
+    /// * If the signed content does not name the member key of the certificate
+    pub fn verify(&self, authority: &SigKeyPair) -> Result<SigKeyPair> {
+        if self.authority != authority.name_with_rev() {
+            return Err(Error::CryptoError(format!("Member certificate for {} was issued by {}, \
+                                                   not {}",
+                                                  self.member,
+                                                  self.authority,
+                                                  authority.name_with_rev())));
+        }
+        let content = try!(authority.verify(&self.signed));
+        let content = try!(str::from_utf8(&content).map_err(|_| {
+            Error::CryptoError(format!("Malformed member certificate for {}", self.member))
+        }));
+        let mut lines = content.lines();
+        if lines.next() != Some(self.member.as_str()) {
+            return Err(Error::CryptoError(format!("Member certificate for {} certifies a \
+                                                   different key",
+                                                  self.member)));
+        }
+        let public = match lines.next()
+                  .and_then(|val| base64::decode(val).ok())
+                  .and_then(|bytes| SigPublicKey::from_slice(&bytes)) {
+            Some(pk) => pk,
+            None => {
+                return Err(Error::CryptoError(format!("Can't read member public key for {}",
+                                                      self.member)))
+            }
+        };
+        let (name, rev) = try!(parse_name_with_rev(&self.member));
+        Ok(SigKeyPair::new(name, rev, Some(public), None))
+    }
+
+    /// Writes the certificate to the key cache, returning the path of the written file.
+    pub fn write_file<P: AsRef<Path> + ?Sized>(&self, cache_key_path: &P) -> Result<PathBuf> {
+        let path = Self::mk_cert_filename(&self.member, cache_key_path.as_ref());
+        let mut file = try!(File::create(&path));
+        try!(file.write_all(self.to_string().as_bytes()));
+        Ok(path)
+    }
+
+    fn mk_cert_filename(member_with_rev: &str, cache_key_path: &Path) -> PathBuf {
+        cache_key_path.join(format!("{}.{}", member_with_rev, MEMBER_CERT_SUFFIX))
+    }
+
+    fn parse_key_name(val: &str) -> Result<String> {
+        let _ = try!(parse_name_with_rev(val.trim()));
+        Ok(val.trim().to_string())
+    }
+}
+
+impl fmt::Display for MemberCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{}\n{}\n{}\n\n{}",
+               MEMBER_CERT_FORMAT_VERSION,
+               self.authority,
+               self.member,
+               base64::encode(&self.signed))
+    }
+}
+
+/// Adds a member key to the revocation list of the authority named `authority`. The member may be
+/// a key name with a revision, or a key name to revoke every revision of it.
+pub fn revoke_member<P: AsRef<Path> + ?Sized>(authority: &str,
+                                              member: &str,
+                                              cache_key_path: &P)
+                                              -> Result<()> {
+    if try!(revoked_members(authority, cache_key_path)).contains(member) {
+        return Ok(());
+    }
+    let mut file = try!(OpenOptions::new()
+                            .create(true)
+                            .append(true)
+                            .open(mk_revoked_filename(authority, cache_key_path.as_ref())));
+    try!(writeln!(file, "{}", member));
+    Ok(())
+}
+
+/// Returns the member keys revoked by the authority named `authority`. An authority without a
+/// revocation list has revoked nothing.
+pub fn revoked_members<P: AsRef<Path> + ?Sized>(authority: &str,
+                                                cache_key_path: &P)
+                                                -> Result<HashSet<String>> {
+    let path = mk_revoked_filename(authority, cache_key_path.as_ref());
+    let mut revoked = HashSet::new();
+    if !path.is_file() {
+        return Ok(revoked);
+    }
+    let mut content = String::new();
+    try!(try!(File::open(&path)).read_to_string(&mut content));
+    for line in content.lines() {
+        let line = line.trim();
+        if !line.is_empty() {
+            revoked.insert(line.to_string());
+        }
+    }
+    Ok(revoked)
+}
+
+/// Returns true if a member key name with revision is in a list of revoked members, either by
+/// itself or by its name.
+pub fn is_revoked(revoked: &HashSet<String>, member_with_rev: &str) -> bool {
+    if revoked.contains(member_with_rev) {
+        return true;
+    }
+    match parse_name_with_rev(member_with_rev) {
+        Ok((name, _)) => revoked.contains(&name),
+        Err(_) => false,
+    }
+}
+
+fn mk_revoked_filename(authority: &str, cache_key_path: &Path) -> PathBuf {
+    cache_key_path.join(format!("{}.{}", authority, REVOKED_MEMBERS_SUFFIX))
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use super::{MemberCertificate, is_revoked, revoke_member, revoked_members};
+    use super::super::SigKeyPair;
+    use super::super::test_support::*;
+
+    #[test]
+    fn issue_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let authority = SigKeyPair::generate_pair_for_origin("staging-authority", cache.path())
+            .unwrap();
+        let member = SigKeyPair::generate_pair_for_origin("web01", cache.path()).unwrap();
+        let cert = MemberCertificate::issue(&member, &authority).unwrap();
+
+        let verified = cert.verify(&authority).unwrap();
+        assert_eq!(verified.name_with_rev(), member.name_with_rev());
+        assert_eq!(verified.public().unwrap(), member.public().unwrap());
+    }
+
+    #[test]
+    fn write_and_read_file() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let authority = SigKeyPair::generate_pair_for_origin("staging-authority", cache.path())
+            .unwrap();
+        let member = SigKeyPair::generate_pair_for_origin("web01", cache.path()).unwrap();
+        let cert = MemberCertificate::issue(&member, &authority).unwrap();
+        cert.write_file(cache.path()).unwrap();
+
+        let read = MemberCertificate::get_for(&member.name_with_rev(), cache.path()).unwrap();
+        assert_eq!(read, cert);
+        assert!(read.verify(&authority).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "was issued by")]
+    fn verify_with_another_authority() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let authority = SigKeyPair::generate_pair_for_origin("staging-authority", cache.path())
+            .unwrap();
+        let other = SigKeyPair::generate_pair_for_origin("prod-authority", cache.path()).unwrap();
+        let member = SigKeyPair::generate_pair_for_origin("web01", cache.path()).unwrap();
+        let cert = MemberCertificate::issue(&member, &authority).unwrap();
+        cert.verify(&other).unwrap();
+    }
+
+    #[test]
+    fn verify_forged_certificate() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let authority = SigKeyPair::generate_pair_for_origin("staging-authority", cache.path())
+            .unwrap();
+        let member = SigKeyPair::generate_pair_for_origin("web01", cache.path()).unwrap();
+        let mut cert = MemberCertificate::issue(&member, &authority).unwrap();
+        cert.member = "web02-20160405144945".to_string();
+        assert!(cert.verify(&authority).is_err());
+
+        let forger = match wait_until_ok(|| {
+            SigKeyPair::generate_pair_for_origin("staging-authority", cache.path())
+        }) {
+            Some(pair) => pair,
+            None => panic!("Failed to generate another keypair after waiting"),
+        };
+        let mut forged = MemberCertificate::issue(&member, &forger).unwrap();
+        forged.authority = authority.name_with_rev();
+        assert!(forged.verify(&authority).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported member certificate version")]
+    fn parse_unsupported_version() {
+        MemberCertificate::parse("RING-CERT-0\na-20160405144945\nb-20160405144945\n\nAAAA")
+            .unwrap();
+    }
+
+    #[test]
+    fn revoke_members() {
+        let cache = TempDir::new("key_cache").unwrap();
+        assert!(revoked_members("staging-authority", cache.path()).unwrap().is_empty());
+
+        revoke_member("staging-authority", "web01-20160405144945", cache.path()).unwrap();
+        revoke_member("staging-authority", "web02", cache.path()).unwrap();
+        revoke_member("staging-authority", "web02", cache.path()).unwrap();
+        let revoked = revoked_members("staging-authority", cache.path()).unwrap();
+        assert_eq!(revoked.len(), 2);
+        assert!(is_revoked(&revoked, "web01-20160405144945"));
+        assert!(!is_revoked(&revoked, "web01-20170405144945"));
+        assert!(is_revoked(&revoked, "web02-20170405144945"));
+        assert!(!is_revoked(&revoked, "web03-20170405144945"));
+    }
+}
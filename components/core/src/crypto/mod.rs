@@ -118,6 +118,28 @@
 //! staging-201603312016.sym.key
 //! ```
 //!
+//! ## Ring member keys
+//!
+//! Ring authorities and ring members use "sig" keys, so that every member of a ring can verify
+//! the messages of every other member. A member key is certified by its ring authority with a
+//! member certificate, and the authority keeps a list of revoked members.
+//!
+//! ```text
+//! <member_name>-<revision>.pub
+//! <member_name>-<revision>.sig.key
+//! <member_name>-<revision>.cert
+//! <authority_name>.revoked
+//! ```
+//!
+//! Example Ring member keys:
+//!
+//! ```text
+//! staging-authority-201603312016.pub
+//! staging-authority.revoked
+//! web01-201603312016.sig.key
+//! web01-201603312016.cert
+//! ```
+//!
 //! # File formats
 //!
 //! ## Habitat artifacts
@@ -223,6 +245,29 @@
 //!
 //! <symkey_base64>
 //! ```
+//!
+//! ## Member certificates
+//!
+//! There are 4 lines, separated by a newline character `\n`. They are as follows:
+//!
+//! 1. The certificate format version
+//! 1. The ring authority key name, including revision
+//! 1. The member key name, including revision
+//! 1. The member key name and the Base64 member public key, separated by a newline character and
+//!    *signed* with the secret authority key, in Base64 format
+//!
+//! ```text
+//! RING-CERT-1
+//! staging-authority-20160405144945
+//! web01-20160405144945
+//!
+//! <signed_member_key_base64>
+//! ```
+//!
+//! ## Revoked members
+//!
+//! A plain text file with one member key name per line. A name with a revision revokes only that
+//! revision of the member key, while a name without one revokes every revision.
 
 use std::path::{Path, PathBuf};
 
@@ -243,6 +288,12 @@ pub static SECRET_BOX_KEY_SUFFIX: &'static str = "box.key";
 /// The suffix on the end of a secret symmetric key file
 pub static SECRET_SYM_KEY_SUFFIX: &'static str = "sym.key";
 
+/// The suffix on the end of a ring member certificate file
+pub static MEMBER_CERT_SUFFIX: &'static str = "cert";
+
+/// The suffix on the end of a ring authority's list of revoked members
+pub static REVOKED_MEMBERS_SUFFIX: &'static str = "revoked";
+
 /// The hashing function we're using during sign/verify
 /// See also: https://download.libsodium.org/doc/hashing/generic_hashing.html
 pub static SIG_HASH_TYPE: &'static str = "BLAKE2b";
@@ -257,6 +308,7 @@ static SECRET_KEY_PERMISSIONS: u32 = 0o400;
 
 pub static HART_FORMAT_VERSION: &'static str = "HART-1";
 pub static BOX_FORMAT_VERSION: &'static str = "BOX-1";
+pub static MEMBER_CERT_FORMAT_VERSION: &'static str = "RING-CERT-1";
 
 pub const PUBLIC_SIG_KEY_VERSION: &'static str = "SIG-PUB-1";
 pub const SECRET_SIG_KEY_VERSION: &'static str = "SIG-SEC-1";
@@ -267,10 +319,12 @@ pub const SECRET_SYM_KEY_VERSION: &'static str = "SYM-SEC-1";
 pub use self::keys::box_key_pair::BoxKeyPair;
 pub use self::keys::sym_key::SymKey;
 pub use self::keys::sig_key_pair::SigKeyPair;
+pub use self::member_cert::MemberCertificate;
 
 pub mod artifact;
 pub mod hash;
 pub mod keys;
+pub mod member_cert;
 
 pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     match henv::var(CACHE_KEY_PATH_ENV_VAR) {
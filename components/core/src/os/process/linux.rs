@@ -17,11 +17,13 @@ use std::ffi::OsString;
 use std::path::PathBuf;
 use std::os::unix::process::CommandExt;
 use std::process::{self, Command};
+use std::thread;
+use std::time::Duration as StdDuration;
 use time::{Duration, SteadyTime};
 
 use error::{Error, Result};
 
-use super::{HabExitStatus, ExitStatusExt, ShutdownMethod};
+use super::{HabExitStatus, ExitStatusExt, ShutdownMethod, STATUS_POLL_INTERVAL_MS};
 
 pub fn become_command(command: PathBuf, args: Vec<OsString>) -> Result<()> {
     become_exec_command(command, args)
@@ -102,9 +104,13 @@ impl Child {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
-        try!(send_signal(self.pid, libc::SIGTERM));
+        self.terminate(libc::SIGTERM as u32, Duration::seconds(8))
+    }
+
+    pub fn terminate(&mut self, signal: u32, timeout: Duration) -> Result<ShutdownMethod> {
+        try!(send_signal(self.pid, signal as libc::c_int));
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + timeout;
         loop {
             match self.status() {
                 Ok(status) => {
@@ -119,6 +125,7 @@ impl Child {
                 try!(send_signal(self.pid, libc::SIGKILL));
                 return Ok(ShutdownMethod::Killed);
             }
+            thread::sleep(StdDuration::from_millis(STATUS_POLL_INTERVAL_MS));
         }
         Ok(ShutdownMethod::GracefulTermination)
     }
@@ -154,6 +161,7 @@ impl ExitStatusExt for HabExitStatus {
 mod tests {
     use libc;
     use std::process::Command;
+    use time::Duration;
     use super::super::*;
 
     #[test]
@@ -200,6 +208,23 @@ mod tests {
         assert_eq!(exit.signal(), Some(libc::SIGTERM as u32))
     }
 
+    #[test]
+    fn terminated_process_returns_given_signal() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("while : ; do /bin/sleep 1; done");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let _ = hab_child.terminate(libc::SIGHUP as u32, Duration::seconds(8));
+
+        let mut exit = hab_child.status().unwrap();
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+
+        assert_eq!(exit.signal(), Some(libc::SIGHUP as u32))
+    }
+
     #[test]
     fn calling_wait_multiple_times_after_exit_returns_same_status() {
         let mut cmd = Command::new("/bin/bash");
@@ -15,6 +15,8 @@
 use std::fmt;
 use std::process::Child;
 
+use time::Duration;
+
 use error::Result;
 
 #[allow(unused_variables)]
@@ -28,6 +30,9 @@ mod imp;
 
 pub use self::imp::{become_command, current_pid, is_alive};
 
+/// How long to sleep between checks on whether a terminated process has exited yet.
+const STATUS_POLL_INTERVAL_MS: u64 = 100;
+
 pub enum ShutdownMethod {
     AlreadyExited,
     GracefulTermination,
@@ -68,6 +73,12 @@ impl HabChild {
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
         self.inner.kill()
     }
+
+    /// Send `signal` to the process and wait up to `timeout` for it to exit before forcefully
+    /// killing it.
+    pub fn terminate(&mut self, signal: u32, timeout: Duration) -> Result<ShutdownMethod> {
+        self.inner.terminate(signal, timeout)
+    }
 }
 
 impl fmt::Debug for HabChild {
@@ -17,6 +17,8 @@ use std::path::PathBuf;
 use std::process::{self, Command};
 use std::ptr;
 use std::io;
+use std::thread;
+use std::time::Duration as StdDuration;
 use time::{Duration, SteadyTime};
 
 use kernel32;
@@ -24,7 +26,7 @@ use winapi;
 
 use error::{Error, Result};
 
-use super::{HabExitStatus, ExitStatusExt, ShutdownMethod};
+use super::{HabExitStatus, ExitStatusExt, ShutdownMethod, STATUS_POLL_INTERVAL_MS};
 
 const STILL_ACTIVE: u32 = 259;
 
@@ -159,6 +161,11 @@ impl Child {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
+        self.terminate(0, Duration::seconds(8))
+    }
+
+    /// Windows has no signals, so a ctrl-c event is always sent regardless of `signal`.
+    pub fn terminate(&mut self, _signal: u32, timeout: Duration) -> Result<ShutdownMethod> {
         if self.last_status.is_some() {
             return Ok(ShutdownMethod::AlreadyExited);
         }
@@ -184,7 +191,7 @@ impl Child {
             }
         }
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + timeout;
 
         let result;
         loop {
@@ -212,6 +219,7 @@ impl Child {
                 }
                 _ => {}
             }
+            thread::sleep(StdDuration::from_millis(STATUS_POLL_INTERVAL_MS));
         }
 
         // turn Ctrl-C handling back on for current process
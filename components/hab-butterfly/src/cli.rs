@@ -50,6 +50,68 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+        )
+        (@subcommand update =>
+            (about: "Commands relating to updates of Habitat services")
+            (@setting ArgRequiredElseHelp)
+            (@subcommand pause =>
+                (about: "Stop a service group from updating until updates are resumed")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+            (@subcommand resume =>
+                (about: "Let a paused or pinned service group update again")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+            (@subcommand pin =>
+                (about: "Only update a service group to packages satisfying an identifier")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier to pin updates to (ex: core/redis, core/redis/3.2.4)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+            (@subcommand now =>
+                (about: "Make a service group check for updates right away")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg PKG_IDENT: +takes_value
+                    "A package identifier to also pin updates to (ex: core/redis/3.2.4)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
             )
         )
     )
@@ -63,6 +125,8 @@ fn sub_config_apply() -> App<'static, 'static> {
             (default: 127.0.0.1:9638)")
         (@arg RING: -r --ring +takes_value
             "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value
+            "Ring member key name, which will sign communication messages")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
         (@arg VERSION_NUMBER: +required
@@ -21,6 +21,7 @@ pub mod apply {
     use std::time;
 
     use butterfly::client::Client;
+    use butterfly::identity::Identity;
     use common::ui::{Status, UI};
     use hcore::crypto::{SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
@@ -34,6 +35,7 @@ pub mod apply {
                  file_path: Option<&Path>,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
+                 identity: Option<&Identity>,
                  user_pair: Option<&BoxKeyPair>,
                  service_pair: Option<&BoxKeyPair>)
                  -> Result<()> {
@@ -78,6 +80,9 @@ pub mod apply {
             try!(ui.status(Status::Applying, format!("to peer {}", peer)));
             let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            if let Some(identity) = identity {
+                client.set_identity(identity.clone());
+            }
             try!(client.send_service_config(sg.clone(), number, body.clone(), encrypted)
                      .map_err(|e| Error::ButterflyError(format!("{}", e))));
 
@@ -20,6 +20,7 @@ pub mod upload {
     use std::time;
 
     use butterfly::client::Client;
+    use butterfly::identity::Identity;
     use common::ui::{Status, UI};
     use hcore::crypto::{SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
@@ -32,6 +33,7 @@ pub mod upload {
                  file_path: &Path,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
+                 identity: Option<&Identity>,
                  user_pair: Option<&BoxKeyPair>,
                  service_pair: Option<&BoxKeyPair>)
                  -> Result<()> {
@@ -65,6 +67,9 @@ pub mod upload {
             try!(ui.status(Status::Applying, format!("to peer {}", peer)));
             let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            if let Some(identity) = identity {
+                client.set_identity(identity.clone());
+            }
             try!(client.send_service_file(sg.clone(),
                                           filename.clone(),
                                           number,
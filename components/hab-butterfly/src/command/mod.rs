@@ -14,3 +14,25 @@
 
 pub mod config;
 pub mod file;
+pub mod update;
+
+use std::path::Path;
+
+use butterfly::identity::Identity;
+
+use error::{Error, Result};
+
+/// Loads the ring member key named by `--member-key`, which signs everything the commands send
+/// to rings that authenticate their members.
+pub fn member_identity(member_key: Option<&str>,
+                       cache_key_path: &Path)
+                       -> Result<Option<Identity>> {
+    match member_key {
+        Some(name) => {
+            Identity::load(name, cache_key_path)
+                .map(Some)
+                .map_err(|e| Error::ButterflyError(format!("{}", e)))
+        }
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::thread;
+use std::time::{self, SystemTime, UNIX_EPOCH};
+
+use butterfly::client::Client;
+use butterfly::identity::Identity;
+use common::ui::{Status, UI};
+use hcore::crypto::SymKey;
+use hcore::package::PackageIdent;
+use hcore::service::ServiceGroup;
+
+use error::{Error, Result};
+
+pub use butterfly::rumor::update_directive::UpdateDirective_Action;
+
+pub fn start(ui: &mut UI,
+             sg: &ServiceGroup,
+             action: UpdateDirective_Action,
+             ident: Option<&PackageIdent>,
+             peers: &Vec<String>,
+             ring_key: Option<&SymKey>,
+             identity: Option<&Identity>)
+             -> Result<()> {
+    let description = match (action, ident) {
+        (UpdateDirective_Action::Pause, _) => format!("Pausing updates of {}", sg),
+        (UpdateDirective_Action::Resume, _) => format!("Resuming updates of {}", sg),
+        (UpdateDirective_Action::Pin, Some(ident)) => {
+            format!("Pinning updates of {} to {}", sg, ident)
+        }
+        (UpdateDirective_Action::Pin, None) => unreachable!(),
+        (UpdateDirective_Action::UpdateNow, Some(ident)) => {
+            format!("Updating {} to {} now", sg, ident)
+        }
+        (UpdateDirective_Action::UpdateNow, None) => format!("Updating {} now", sg),
+    };
+    try!(ui.begin(description));
+
+    // The newest directive wins, so the time it was given makes for its incarnation.
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System clock is before 1970");
+    let incarnation = now.as_secs() * 1000 + (now.subsec_nanos() / 1_000_000) as u64;
+    for peer in peers.iter() {
+        try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+        let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+            .map_err(|e| Error::ButterflyError(format!("{}", e))));
+        if let Some(identity) = identity {
+            client.set_identity(identity.clone());
+        }
+        try!(client.send_update_directive(sg.clone(),
+                                          incarnation,
+                                          action,
+                                          ident.map(|i| i.to_string()))
+                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
+
+        // please take a moment to weep over the following line
+        // of code. We must sleep to allow messages to be sent
+        // before freeing the socket to prevent loss.
+        // see https://github.com/zeromq/libzmq/issues/1264
+        thread::sleep(time::Duration::from_millis(100));
+    }
+    try!(ui.end("Applied update directive"));
+    Ok(())
+}
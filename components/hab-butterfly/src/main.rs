@@ -35,9 +35,11 @@ use clap::ArgMatches;
 use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
 use hcore::env as henv;
 use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SymKey};
+use hcore::package::PackageIdent;
 use hcore::service::ServiceGroup;
 
 use hab_butterfly::{analytics, cli, command};
+use hab_butterfly::command::update::UpdateDirective_Action;
 use hab_butterfly::error::{Error, Result};
 
 /// Makes the --org CLI param optional when this env var is set
@@ -94,6 +96,15 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("update", Some(matches)) => {
+            match matches.subcommand() {
+                ("pause", Some(m)) => try!(sub_update(ui, m, UpdateDirective_Action::Pause)),
+                ("resume", Some(m)) => try!(sub_update(ui, m, UpdateDirective_Action::Resume)),
+                ("pin", Some(m)) => try!(sub_update(ui, m, UpdateDirective_Action::Pin)),
+                ("now", Some(m)) => try!(sub_update(ui, m, UpdateDirective_Action::UpdateNow)),
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -120,6 +131,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
         None => None,
     };
+    let identity = try!(command::member_identity(m.value_of("MEMBER_KEY"), &cache));
 
     let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
     if let Some(org) = org_param_or_env(&m) {
@@ -140,6 +152,7 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                   file_path,
                                   &peers,
                                   ring_key.as_ref(),
+                                  identity.as_ref(),
                                   user_pair.as_ref(),
                                   service_pair.as_ref())
 }
@@ -174,6 +187,7 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
         None => None,
     };
+    let identity = try!(command::member_identity(m.value_of("MEMBER_KEY"), &cache));
 
     let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
     if let Some(org) = org_param_or_env(&m) {
@@ -194,10 +208,46 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                  file_path,
                                  &peers,
                                  ring_key.as_ref(),
+                                 identity.as_ref(),
                                  user_pair.as_ref(),
                                  service_pair.as_ref())
 }
 
+fn sub_update(ui: &mut UI, m: &ArgMatches, action: UpdateDirective_Action) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(try!(PackageIdent::from_str(ident))),
+        None => None,
+    };
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let identity = try!(command::member_identity(m.value_of("MEMBER_KEY"), &cache));
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    command::update::start(ui,
+                           &sg,
+                           action,
+                           ident.as_ref(),
+                           &peers,
+                           ring_key.as_ref(),
+                           identity.as_ref())
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR).map(|val| val == "true").unwrap_or(false) {
         Some(false)
@@ -74,6 +74,8 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
             )
         )
         (@subcommand origin =>
@@ -277,6 +279,43 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
                 )
+                (@subcommand rotate =>
+                    (about: "Generates a new revision of an existing Habitat ring key")
+                    (aliases: &["r", "ro", "rot", "rota", "rotat"])
+                    (@arg RING: +required +takes_value "Ring key name")
+                )
+            )
+            (@subcommand authority =>
+                (about: "Commands relating to Habitat ring authority keys")
+                (aliases: &["a", "au", "aut", "auth", "autho", "author", "authori", "authorit"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand generate =>
+                    (about: "Generates a Habitat ring authority key, which certifies the keys \
+                    of ring members")
+                    (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
+                    (@arg AUTHORITY: +required +takes_value "Ring authority key name")
+                )
+            )
+            (@subcommand member =>
+                (about: "Commands relating to Habitat ring member keys")
+                (aliases: &["m", "me", "mem", "memb", "membe"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand generate =>
+                    (about: "Generates a Habitat ring member key, certified by a ring \
+                    authority key")
+                    (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
+                    (@arg MEMBER: +required +takes_value "Ring member key name")
+                    (@arg AUTHORITY: +required +takes_value "Ring authority key name")
+                )
+                (@subcommand revoke =>
+                    (about: "Adds a ring member key to a ring authority's list of revoked \
+                    members")
+                    (aliases: &["r", "re", "rev", "revo", "revok"])
+                    (@arg AUTHORITY: +required +takes_value "Ring authority key name")
+                    (@arg MEMBER: +required +takes_value
+                        "Ring member key name, with a revision to revoke only that revision \
+                        (ex: web01, web01-20160405144945)")
+                )
             )
         )
         (@subcommand service =>
@@ -319,6 +358,66 @@ pub fn get() -> App<'static, 'static> {
                 )
             )
         )
+        (@subcommand update =>
+            (about: "Commands relating to updates of Habitat services")
+            (@setting ArgRequiredElseHelp)
+            (@subcommand pause =>
+                (about: "Stop a service group from updating until updates are resumed")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+            (@subcommand resume =>
+                (about: "Let a paused or pinned service group update again")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+            (@subcommand pin =>
+                (about: "Only update a service group to packages satisfying an identifier")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier to pin updates to (ex: core/redis, core/redis/3.2.4)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+            (@subcommand now =>
+                (about: "Make a service group check for updates right away")
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg PKG_IDENT: +takes_value
+                    "A package identifier to also pin updates to (ex: core/redis/3.2.4)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value
+                    "Ring member key name, which will sign communication messages")
+            )
+        )
         (subcommand: alias_apply)
         (subcommand: alias_install)
         (subcommand: alias_setup)
@@ -374,6 +473,8 @@ fn sub_config_apply() -> App<'static, 'static> {
             (default: 127.0.0.1:9638)")
         (@arg RING: -r --ring +takes_value
             "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value
+            "Ring member key name, which will sign communication messages")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
         (@arg VERSION_NUMBER: +required
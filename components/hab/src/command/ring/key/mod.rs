@@ -15,3 +15,4 @@
 pub mod export;
 pub mod generate;
 pub mod import;
+pub mod rotate;
@@ -0,0 +1,37 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use hcore::crypto::{MemberCertificate, SigKeyPair};
+
+use error::Result;
+
+pub fn start(ui: &mut UI, member: &str, authority: &str, cache: &Path) -> Result<()> {
+    try!(ui.begin(format!("Generating ring member key for {}", &member)));
+    let authority_pair = try!(SigKeyPair::get_latest_pair_for(authority, cache));
+    let pair = try!(SigKeyPair::generate_pair_for_origin(member, cache));
+    try!(ui.status(Status::Signing,
+                   format!("member key {} with {}",
+                           &pair.name_with_rev(),
+                           &authority_pair.name_with_rev())));
+    let certificate = try!(MemberCertificate::issue(&pair, &authority_pair));
+    let path = try!(certificate.write_file(cache));
+    try!(ui.end(format!("Generated ring member key pair {}, certified in {}.",
+                        &pair.name_with_rev(),
+                        path.display())));
+    Ok(())
+}
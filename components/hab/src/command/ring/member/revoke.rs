@@ -0,0 +1,30 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::crypto::member_cert;
+
+use error::Result;
+
+pub fn start(ui: &mut UI, authority: &str, member: &str, cache: &Path) -> Result<()> {
+    try!(ui.begin(format!("Revoking ring member key {} for {}", &member, &authority)));
+    try!(member_cert::revoke_member(authority, member, cache));
+    try!(ui.end(format!("Revoked ring member key {}. Copy the revocation list to every \
+                         member of the ring for it to take effect.",
+                        &member)));
+    Ok(())
+}
@@ -143,6 +143,20 @@ fn start(ui: &mut UI) -> Result<()> {
                         ("export", Some(sc)) => try!(sub_ring_key_export(sc)),
                         ("import", Some(_)) => try!(sub_ring_key_import(ui)),
                         ("generate", Some(sc)) => try!(sub_ring_key_generate(ui, sc)),
+                        ("rotate", Some(sc)) => try!(sub_ring_key_rotate(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                ("authority", Some(m)) => {
+                    match m.subcommand() {
+                        ("generate", Some(sc)) => try!(sub_ring_authority_generate(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                ("member", Some(m)) => {
+                    match m.subcommand() {
+                        ("generate", Some(sc)) => try!(sub_ring_member_generate(ui, sc)),
+                        ("revoke", Some(sc)) => try!(sub_ring_member_revoke(ui, sc)),
                         _ => unreachable!(),
                     }
                 }
@@ -427,6 +441,37 @@ fn sub_pkg_header(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::header::start(ui, &src)
 }
 
+fn sub_ring_authority_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let authority = m.value_of("AUTHORITY").unwrap(); // Required via clap
+    init();
+
+    command::ring::authority::generate::start(ui,
+                                              authority,
+                                              &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
+fn sub_ring_member_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let member = m.value_of("MEMBER").unwrap(); // Required via clap
+    let authority = m.value_of("AUTHORITY").unwrap(); // Required via clap
+    init();
+
+    command::ring::member::generate::start(ui,
+                                           member,
+                                           authority,
+                                           &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
+fn sub_ring_member_revoke(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let authority = m.value_of("AUTHORITY").unwrap(); // Required via clap
+    let member = m.value_of("MEMBER").unwrap(); // Required via clap
+    init();
+
+    command::ring::member::revoke::start(ui,
+                                         authority,
+                                         member,
+                                         &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
     let ring = m.value_of("RING").unwrap(); // Required via clap
     init();
@@ -449,6 +494,13 @@ fn sub_ring_key_import(ui: &mut UI) -> Result<()> {
     command::ring::key::import::start(ui, &content, &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
+fn sub_ring_key_rotate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ring = m.value_of("RING").unwrap(); // Required via clap
+    init();
+
+    command::ring::key::rotate::start(ui, ring, &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
 fn sub_service_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let org = try!(org_param_or_env(&m));
     let service_group = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
@@ -490,7 +542,7 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _) | ("file", _) => {
+        ("config", _) | ("file", _) | ("update", _) => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
         ("stu", _) | ("stud", _) | ("studi", _) | ("studio", _) => {
@@ -0,0 +1,154 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders the configuration templates and hooks of an installed package without starting it.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup config render acme/redis
+//! ```
+//!
+//! Will print the `config.toml`, configuration files and hooks the Supervisor would write for the
+//! `redis` service in the `default` group.
+//!
+//! ```bash
+//! $ hab-sup config render acme/redis --user-toml user.toml --census census.json -o /tmp/redis
+//! ```
+//!
+//! Will render them with the given user configuration and census, as returned by the `/census`
+//! endpoint of a running Supervisor, into `/tmp/redis/config` and `/tmp/redis/hooks`.
+
+use std;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use butterfly::rumor::service::Service as ServiceRumor;
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::PackageInstall;
+use hcore::service::ServiceGroup;
+use serde_json;
+use toml;
+
+use config::GossipListenAddr;
+use error::{Error, Result};
+use http_gateway;
+use manager::census::{CensusEntry, CensusList};
+use manager::service::{Service, ServiceConfig, ServiceSpec};
+use manager::service::hooks::HookTable;
+use supervisor::RuntimeConfig;
+
+static LOGKEY: &'static str = "CR";
+
+/// Member ID of the rendering member when the census doesn't already contain its service group.
+const RENDER_MEMBER_ID: &'static str = "config-render";
+
+/// Render the configuration and hooks of the spec's package, writing them to `dest` if given or to
+/// standard output otherwise.
+pub fn render(spec: &ServiceSpec,
+              user_toml: Option<&Path>,
+              census: Option<&Path>,
+              dest: Option<&Path>)
+              -> Result<()> {
+    let package = try!(PackageInstall::load(&spec.ident, Some(&Path::new(&*FS_ROOT_PATH))));
+    let runtime_cfg = match Service::runtime_config_from(&package, &spec.user_config()) {
+        Ok(runtime_cfg) => runtime_cfg,
+        Err(err) => {
+            outputln!("Unable to determine the service's user and group, using the defaults: {}",
+                      err);
+            RuntimeConfig::default()
+        }
+    };
+    let service_group = try!(ServiceGroup::new(&package.ident.name, &spec.group, None));
+    let config_root = spec.config_from.clone().unwrap_or(package.installed_path.clone());
+    let mut config = try!(ServiceConfig::new(&package,
+                                             &runtime_cfg,
+                                             config_root.clone(),
+                                             spec.binds.clone(),
+                                             &GossipListenAddr::default(),
+                                             &http_gateway::ListenAddr::default()));
+    if let Some(path) = user_toml {
+        try!(config.load_user_config(path));
+    }
+    let census_list = try!(census_list(census, &package, &service_group, &config));
+    config.populate(&service_group, &census_list);
+
+    let final_toml = try!(config.to_toml());
+    let mut rendered = vec![(String::from("config.toml"), try!(toml::ser::to_string(&final_toml)))];
+    for (name, content) in try!(config.render()) {
+        rendered.push((format!("config/{}", name), content));
+    }
+    let hooks = HookTable::default().load_hooks(&service_group,
+                                                 Path::new(""),
+                                                 config_root.join("hooks"));
+    for (name, content) in try!(hooks.render(&config)) {
+        rendered.push((format!("hooks/{}", name), content));
+    }
+
+    match dest {
+        Some(dest) => write_rendered(dest, rendered),
+        None => {
+            for (name, content) in rendered {
+                println!("==> {} <==", name);
+                println!("{}", content);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Load the census from a file if one was given, making sure it contains the rendering member's
+/// service group as the live Supervisor's census always does.
+fn census_list(census: Option<&Path>,
+               package: &PackageInstall,
+               service_group: &ServiceGroup,
+               config: &ServiceConfig)
+               -> Result<CensusList> {
+    let mut census_list = match census {
+        Some(path) => {
+            let file = try!(File::open(path)
+                .map_err(|e| sup_error!(Error::BadDataFile(path.to_path_buf(), e))));
+            try!(serde_json::from_reader(file))
+        }
+        None => CensusList::new(),
+    };
+    if census_list.get(&*service_group).is_none() {
+        let exported = try!(config.to_exported());
+        let rumor = ServiceRumor::new(RENDER_MEMBER_ID,
+                                      &package.ident,
+                                      service_group,
+                                      &*config.sys,
+                                      Some(&exported));
+        let mut entry = CensusEntry::default();
+        entry.populate_from_service(&rumor);
+        entry.set_alive(true);
+        entry.set_initialized(true);
+        census_list.insert(String::from(RENDER_MEMBER_ID), entry);
+    }
+    Ok(census_list)
+}
+
+fn write_rendered(dest: &Path, rendered: Vec<(String, String)>) -> Result<()> {
+    for (name, content) in rendered {
+        let path = dest.join(&name);
+        if let Some(parent) = path.parent() {
+            try!(std::fs::create_dir_all(parent));
+        }
+        let mut file = try!(File::create(&path));
+        try!(file.write_all(content.as_bytes()));
+        outputln!("Rendered {}", path.display());
+    }
+    Ok(())
+}
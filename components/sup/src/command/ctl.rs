@@ -0,0 +1,190 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Controls the services of an already running Supervisor through its HTTP gateway.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup load acme/redis -g production
+//! ```
+//!
+//! Will load the `redis` service in the `production` group on the local Supervisor.
+//!
+//! ```bash
+//! $ hab-sup stop acme/redis -g production
+//! ```
+//!
+//! Will take the `redis` service down, leaving it loaded so it can be started again.
+
+use std::io::Read;
+
+use http_client::ApiClient;
+use hyper::header::{Authorization, Bearer, ContentType};
+use hyper::status::StatusCode;
+use hyper::Url;
+use hyper::client::{RequestBuilder, Response};
+use hcore::service::ServiceGroup;
+use serde_json::{self, Value};
+
+use {PRODUCT, VERSION};
+use error::{Error, Result, SupError};
+use http_gateway::ListenAddr;
+use manager::ServiceSpec;
+
+static LOGKEY: &'static str = "CT";
+
+/// Environment variable which overrides the control secret read from the Supervisor's state path.
+pub const CTL_SECRET_ENVVAR: &'static str = "HAB_CTL_SECRET";
+
+pub struct CtlClient {
+    inner: ApiClient,
+    secret: String,
+}
+
+impl CtlClient {
+    pub fn new(remote_sup: &ListenAddr, secret: String) -> Result<Self> {
+        let endpoint = Url::parse(&format!("http://{}", remote_sup))
+            .expect("Supervisor listen address is a valid URL");
+        Ok(CtlClient {
+               inner: ApiClient::new(&endpoint, PRODUCT, VERSION, None)?,
+               secret: secret,
+           })
+    }
+
+    /// Load the service described by the spec, starting it on the remote Supervisor.
+    pub fn load(&self, spec: &ServiceSpec) -> Result<()> {
+        let body = spec.to_toml_string()?;
+        let res = self.add_authz(self.inner.post("services"))
+            .header(ContentType::plaintext())
+            .body(&body)
+            .send()?;
+        outputln!("Loading {} on the Supervisor", &spec.ident);
+        check_accepted(res)
+    }
+
+    /// Stop and remove the service from the remote Supervisor.
+    pub fn unload(&self, service_group: &ServiceGroup) -> Result<()> {
+        let res = self.add_authz(self.inner.delete(&path_for(service_group, None)))
+            .send()?;
+        outputln!("Unloading {} from the Supervisor", service_group);
+        check_accepted(res)
+    }
+
+    /// Bring a loaded but stopped service back up.
+    pub fn start(&self, service_group: &ServiceGroup) -> Result<()> {
+        let res = self.add_authz(self.inner.post(&path_for(service_group, Some("start"))))
+            .send()?;
+        outputln!("Starting {}", service_group);
+        check_accepted(res)
+    }
+
+    /// Take a service down without unloading it from the Supervisor.
+    pub fn stop(&self, service_group: &ServiceGroup) -> Result<()> {
+        let res = self.add_authz(self.inner.post(&path_for(service_group, Some("stop"))))
+            .send()?;
+        outputln!("Stopping {}", service_group);
+        check_accepted(res)
+    }
+
+    /// Approve the package of the service group's update leader for the rest of the group to
+    /// update to, letting a canary update continue.
+    pub fn approve(&self, service_group: &ServiceGroup) -> Result<()> {
+        let res = self.add_authz(self.inner.post(&path_for(service_group, Some("approve"))))
+            .send()?;
+        outputln!("Approving the update of {}", service_group);
+        check_accepted(res)
+    }
+
+    /// Print the state of every service running on the remote Supervisor.
+    pub fn status(&self) -> Result<()> {
+        let mut res = self.inner.get("services").send()?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut body = String::new();
+        res.read_to_string(&mut body)?;
+        let services: Value = serde_json::from_str(&body)?;
+        match services.as_array() {
+            Some(services) if !services.is_empty() => {
+                for service in services {
+                    println!("{} ({}), state:{}, desired:{}, pid:{}",
+                             service["package"].as_str().unwrap_or("<unknown>"),
+                             service["service_group"].as_str().unwrap_or("<unknown>"),
+                             service["supervisor"]["state"].as_str().unwrap_or("<unknown>"),
+                             service["desired_state"].as_str().unwrap_or("<unknown>"),
+                             service["supervisor"]["pid"]
+                                 .as_u64()
+                                 .map(|pid| pid.to_string())
+                                 .unwrap_or("<none>".to_string()));
+                }
+            }
+            _ => println!("No services loaded."),
+        }
+        Ok(())
+    }
+
+    fn add_authz<'a>(&'a self, rb: RequestBuilder<'a>) -> RequestBuilder {
+        rb.header(Authorization(Bearer { token: self.secret.clone() }))
+    }
+}
+
+fn path_for(service_group: &ServiceGroup, action: Option<&str>) -> String {
+    let mut path = format!("services/{}/{}", service_group.service(), service_group.group());
+    if let Some(org) = service_group.org() {
+        path.push_str(&format!("/{}", org));
+    }
+    if let Some(action) = action {
+        path.push_str(&format!("/{}", action));
+    }
+    path
+}
+
+fn check_accepted(res: Response) -> Result<()> {
+    match res.status {
+        StatusCode::Accepted => Ok(()),
+        _ => Err(err_from_response(res)),
+    }
+}
+
+fn err_from_response(mut response: Response) -> SupError {
+    let mut buff = String::new();
+    if response.read_to_string(&mut buff).is_err() {
+        buff.truncate(0);
+    }
+    sup_error!(Error::CtlApi(response.status, buff))
+}
+
+#[cfg(test)]
+mod test {
+    use hcore::service::ServiceGroup;
+
+    use super::path_for;
+
+    #[test]
+    fn path_for_service_group() {
+        let sg = ServiceGroup::new("redis", "production", None).unwrap();
+
+        assert_eq!("services/redis/production", path_for(&sg, None));
+        assert_eq!("services/redis/production/stop", path_for(&sg, Some("stop")));
+    }
+
+    #[test]
+    fn path_for_service_group_with_org() {
+        let sg = ServiceGroup::new("redis", "production", Some("acme")).unwrap();
+
+        assert_eq!("services/redis/production/acme/start",
+                   path_for(&sg, Some("start")));
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Permanently removes a Supervisor from the ring.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup depart 3f0b5e8b6f8d4c4aa1b5f6a1e1a8c3d2 -p 10.0.0.4:9638
+//! ```
+//!
+//! Will gossip the departure of the member through the peer at `10.0.0.4:9638`; every member of
+//! the ring forgets it, and it is never allowed back in.
+
+use std::thread;
+use std::time::Duration;
+
+use butterfly::client::Client;
+use butterfly::identity::Identity;
+use hcore::crypto::{default_cache_key_path, SymKey};
+
+use error::Result;
+
+static LOGKEY: &'static str = "DP";
+
+pub fn run(member_id: &str,
+           peers: &Vec<String>,
+           ring_key: Option<SymKey>,
+           member_key: Option<&str>)
+           -> Result<()> {
+    let identity = match member_key {
+        Some(name) => Some(try!(Identity::load(name, &default_cache_key_path(None)))),
+        None => None,
+    };
+    outputln!("Departing member {} from the ring", member_id);
+    for peer in peers.iter() {
+        outputln!("Sending the departure to peer {}", peer);
+        let mut client = try!(Client::new(peer, ring_key.clone()));
+        if let Some(ref identity) = identity {
+            client.set_identity(identity.clone());
+        }
+        try!(client.send_departure(member_id));
+        // We must sleep to allow the message to be sent before freeing the socket, or it may be
+        // lost; see https://github.com/zeromq/libzmq/issues/1264
+        thread::sleep(Duration::from_millis(100));
+    }
+    Ok(())
+}
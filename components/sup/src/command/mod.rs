@@ -14,5 +14,8 @@
 
 //! The CLI commands.
 
+pub mod config;
+pub mod ctl;
+pub mod depart;
 pub mod start;
 pub mod shell;
@@ -56,7 +56,10 @@ use glob;
 use handlebars;
 use hcore::{self, package};
 use hcore::package::Identifiable;
+use http_client;
+use hyper;
 use notify;
+use serde_json;
 use toml;
 
 use output::StructuredOutput;
@@ -104,18 +107,31 @@ pub enum Error {
     BadDataPath(PathBuf, io::Error),
     BadSpecsPath(PathBuf, io::Error),
     ButterflyError(butterfly::error::Error),
+    CtlApi(hyper::status::StatusCode, String),
+    Departed(String),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
     FileNotFound(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
+    HttpClient(http_client::Error),
+    HyperError(hyper::Error),
     TemplateFileError(handlebars::TemplateFileError),
     TemplateRenderError(handlebars::RenderError),
+    InsecureSecretsPath(PathBuf),
     InvalidBinding(String),
+    InvalidCapability(String),
+    InvalidDesiredState(String),
+    InvalidHealthCheck(String),
     InvalidKeyParameter(String),
+    InvalidLogFormat(String),
     InvalidPidFile,
+    InvalidRestartPolicy(String),
+    InvalidSecretDelivery(String),
+    InvalidShutdownSignal(String),
     InvalidTopology(String),
+    InvalidUpdateBatch(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
     IPFailed,
@@ -128,6 +144,10 @@ pub enum Error {
     Permissions(String),
     ProcessLocked(u32),
     ProcessLockIO(PathBuf, io::Error),
+    ResourceLimits(String),
+    SecretNameCollision(String, String),
+    SerdeJsonError(serde_json::Error),
+    ServiceLoaded(String),
     ServiceSpecFileRead(String, String),
     ServiceSpecFileWrite(String, String),
     ServiceSpecParse(String),
@@ -166,27 +186,50 @@ impl fmt::Display for SupError {
                         err)
             }
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
+            Error::CtlApi(ref status, ref body) => {
+                format!("Supervisor control request failed ({}): {}", status, body)
+            }
+            Error::Departed(ref member_id) => {
+                format!("This Supervisor ({}) has departed the ring and can not rejoin it; remove \
+                         its data directory to start it again as a new member",
+                        member_id)
+            }
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
             Error::Permissions(ref err) => format!("{}", err),
             Error::HabitatCommon(ref err) => format!("{}", err),
             Error::HabitatCore(ref err) => format!("{}", err),
+            Error::HttpClient(ref err) => format!("{}", err),
+            Error::HyperError(ref err) => format!("{}", err),
             Error::TemplateFileError(ref err) => format!("{:?}", err),
             Error::TemplateRenderError(ref err) => format!("{}", err),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::EnvJoinPathsError(ref err) => format!("{}", err),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
+            Error::InsecureSecretsPath(ref path) => {
+                format!("Refusing to write secrets to {}, which is not a directory that only \
+                         the Supervisor's user can write to",
+                        path.display())
+            }
             Error::InvalidBinding(ref binding) => {
                 format!("Invalid binding \"{}\", must be of the form <NAME>:<SERVICE_GROUP> where \
                          <NAME> is a service name and <SERVICE_GROUP> is a valid service group",
                         binding)
             }
+            Error::InvalidCapability(ref c) => format!("Invalid Linux capability: {}", c),
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
+            Error::InvalidLogFormat(ref f) => format!("Invalid log format: {}", f),
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidDesiredState(ref s) => format!("Invalid desired state: {}", s),
+            Error::InvalidHealthCheck(ref s) => format!("Invalid health check status: {}", s),
+            Error::InvalidRestartPolicy(ref p) => format!("Invalid restart policy: {}", p),
+            Error::InvalidSecretDelivery(ref d) => format!("Invalid secret delivery: {}", d),
+            Error::InvalidShutdownSignal(ref s) => format!("Invalid shutdown signal: {}", s),
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
+            Error::InvalidUpdateBatch(ref b) => format!("Invalid update batch size: {}", b),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
@@ -217,6 +260,16 @@ impl fmt::Display for SupError {
                         path.display(),
                         err)
             }
+            Error::ResourceLimits(ref err) => format!("Unable to apply resource limits: {}", err),
+            Error::SecretNameCollision(ref name, ref other) => {
+                format!("Secret {} has the same environment variable name as secret {}",
+                        name,
+                        other)
+            }
+            Error::SerdeJsonError(ref err) => format!("{}", err),
+            Error::ServiceLoaded(ref service_group) => {
+                format!("Service already loaded as {}, unload it first", service_group)
+            }
             Error::ServiceSpecFileRead(ref path, ref details) => {
                 format!("Service spec file '{}' could not be read successfully: {}",
                         path,
@@ -269,18 +322,31 @@ impl error::Error for SupError {
             Error::BadDataPath(_, _) => "Unable to read or write to data directory",
             Error::BadSpecsPath(_, _) => "Unable to create the specs directory",
             Error::ButterflyError(ref err) => err.description(),
+            Error::CtlApi(_, _) => "Supervisor control request failed",
+            Error::Departed(_) => "Supervisor has departed the ring",
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
             Error::TemplateRenderError(ref err) => err.description(),
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HttpClient(ref err) => err.description(),
+            Error::HyperError(ref err) => err.description(),
             Error::DepotClient(ref err) => err.description(),
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
+            Error::InsecureSecretsPath(_) => "Secrets path is not a private directory",
             Error::InvalidBinding(_) => "Invalid binding parameter",
+            Error::InvalidCapability(_) => "Invalid Linux capability",
             Error::InvalidKeyParameter(_) => "Key parameter error",
+            Error::InvalidLogFormat(_) => "Invalid log format",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidDesiredState(_) => "Invalid desired state",
+            Error::InvalidHealthCheck(_) => "Invalid health check status",
+            Error::InvalidRestartPolicy(_) => "Invalid restart policy",
+            Error::InvalidSecretDelivery(_) => "Invalid secret delivery",
+            Error::InvalidShutdownSignal(_) => "Invalid shutdown signal",
             Error::InvalidTopology(_) => "Invalid topology",
+            Error::InvalidUpdateBatch(_) => "Invalid update batch size",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
@@ -293,6 +359,10 @@ impl error::Error for SupError {
             Error::Permissions(_) => "File system permissions error",
             Error::ProcessLocked(_) => "Another instance of the Habitat Supervisor is already running",
             Error::ProcessLockIO(_, _) => "Unable to write or read to a process lock",
+            Error::ResourceLimits(_) => "Unable to apply resource limits",
+            Error::SecretNameCollision(_, _) => "Secrets have the same environment variable name",
+            Error::SerdeJsonError(ref err) => err.description(),
+            Error::ServiceLoaded(_) => "Service already loaded in another group",
             Error::ServiceSpecFileRead(_, _) => "Service spec file could not be read successfully",
             Error::ServiceSpecFileWrite(_, _) => "Service spec file could not be written successfully",
             Error::ServiceSpecParse(_) => "Service spec could not be parsed successfully",
@@ -354,6 +424,18 @@ impl From<hcore::Error> for SupError {
     }
 }
 
+impl From<http_client::Error> for SupError {
+    fn from(err: http_client::Error) -> SupError {
+        sup_error!(Error::HttpClient(err))
+    }
+}
+
+impl From<hyper::Error> for SupError {
+    fn from(err: hyper::Error) -> SupError {
+        sup_error!(Error::HyperError(err))
+    }
+}
+
 impl From<depot_client::Error> for SupError {
     fn from(err: depot_client::Error) -> SupError {
         sup_error!(Error::DepotClient(err))
@@ -402,6 +484,12 @@ impl From<notify::Error> for SupError {
     }
 }
 
+impl From<serde_json::Error> for SupError {
+    fn from(err: serde_json::Error) -> SupError {
+        sup_error!(Error::SerdeJsonError(err))
+    }
+}
+
 impl From<toml::de::Error> for SupError {
     fn from(err: toml::de::Error) -> Self {
         sup_error!(Error::TomlParser(err))
@@ -21,7 +21,8 @@ use std::ops::{Deref, DerefMut};
 use std::option;
 use std::result;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Sender;
 use std::thread::{self, JoinHandle};
 
 use hcore::service::ServiceGroup;
@@ -35,8 +36,8 @@ use serde_json;
 
 use error::{Result, Error, SupError};
 use fs;
-use manager;
-use manager::service::HealthCheck;
+use manager::{self, CtlCommand};
+use manager::service::{HealthCheck, ServiceSpec};
 use manager::service::hooks::{self, HealthCheckHook};
 
 static LOGKEY: &'static str = "HG";
@@ -132,29 +133,59 @@ impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
+struct ManagerOutputs;
+
+impl typemap::Key for ManagerOutputs {
+    type Value = manager::ServiceOutputs;
+}
+
+/// Shared state for the control endpoints which mutate the running Supervisor.
+///
+/// Requests must carry the Supervisor's control secret as a bearer token before a command is
+/// forwarded to the Manager.
+pub struct CtlGateway {
+    secret: String,
+    tx: Mutex<Sender<CtlCommand>>,
+}
+
+impl CtlGateway {
+    pub fn new(secret: String, tx: Sender<CtlCommand>) -> Self {
+        CtlGateway {
+            secret: secret,
+            tx: Mutex::new(tx),
+        }
+    }
+
+    fn authorized(&self, req: &Request) -> bool {
+        match req.headers.get::<headers::Authorization<headers::Bearer>>() {
+            Some(&headers::Authorization(headers::Bearer { ref token })) => {
+                constant_time_eq(token.as_bytes(), self.secret.as_bytes())
+            }
+            None => false,
+        }
+    }
+
+    fn send(&self, cmd: CtlCommand) -> Response {
+        match self.tx.lock().expect("Control channel lock is poisoned!").send(cmd) {
+            Ok(()) => Response::with(status::Accepted),
+            Err(_) => Response::with(status::ServiceUnavailable),
+        }
+    }
+}
+
+impl typemap::Key for CtlGateway {
+    type Value = CtlGateway;
+}
+
 pub struct Server(Iron<Chain>, ListenAddr);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
-        let router = router!(
-            butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
-            census: get "/census" => with_metrics!(census, "census"),
-            metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
-            services: get "/services" => with_metrics!(services, "services"),
-            service_config: get "/services/:svc/:group/config" => {
-                with_metrics!(config, "config")
-            },
-            service_health: get "/services/:svc/:group/health" => with_metrics!(health, "health"),
-            service_config_org: get "/services/:svc/:group/:org/config" => {
-                with_metrics!(config, "config")
-            },
-            service_health_org: get "/services/:svc/:group/:org/health" => {
-                with_metrics!(health, "config")
-            }
-        );
-        let mut chain = Chain::new(router);
-        chain.link(persistent::Read::<ManagerFs>::both(manager_state));
-        Server(Iron::new(chain), listen_addr)
+    pub fn new(manager_state: Arc<manager::FsCfg>,
+               outputs: Arc<manager::ServiceOutputs>,
+               listen_addr: ListenAddr,
+               ctl: CtlGateway)
+               -> Self {
+        Server(Iron::new(chain(manager_state, outputs, ctl)), listen_addr)
     }
 
     pub fn start(self) -> Result<JoinHandle<()>> {
@@ -167,10 +198,76 @@ impl Server {
     }
 }
 
+fn chain(manager_state: Arc<manager::FsCfg>,
+         outputs: Arc<manager::ServiceOutputs>,
+         ctl: CtlGateway)
+         -> Chain {
+    let router = router!(
+        butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
+        census: get "/census" => with_metrics!(census, "census"),
+        metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
+        services: get "/services" => with_metrics!(services, "services"),
+        service_load: post "/services" => with_metrics!(service_load, "service_load"),
+        service_unload: delete "/services/:svc/:group" => {
+            with_metrics!(service_unload, "service_unload")
+        },
+        service_start: post "/services/:svc/:group/start" => {
+            with_metrics!(service_start, "service_start")
+        },
+        service_stop: post "/services/:svc/:group/stop" => {
+            with_metrics!(service_stop, "service_stop")
+        },
+        service_approve: post "/services/:svc/:group/approve" => {
+            with_metrics!(service_approve, "service_approve")
+        },
+        service_config: get "/services/:svc/:group/config" => {
+            with_metrics!(config, "config")
+        },
+        service_health: get "/services/:svc/:group/health" => with_metrics!(health, "health"),
+        service_logs: get "/services/:svc/:group/logs" => with_metrics!(logs, "logs"),
+        service_config_org: get "/services/:svc/:group/:org/config" => {
+            with_metrics!(config, "config")
+        },
+        service_health_org: get "/services/:svc/:group/:org/health" => {
+            with_metrics!(health, "config")
+        },
+        service_logs_org: get "/services/:svc/:group/:org/logs" => {
+            with_metrics!(logs, "logs")
+        },
+        service_unload_org: delete "/services/:svc/:group/:org" => {
+            with_metrics!(service_unload, "service_unload")
+        },
+        service_start_org: post "/services/:svc/:group/:org/start" => {
+            with_metrics!(service_start, "service_start")
+        },
+        service_stop_org: post "/services/:svc/:group/:org/stop" => {
+            with_metrics!(service_stop, "service_stop")
+        },
+        service_approve_org: post "/services/:svc/:group/:org/approve" => {
+            with_metrics!(service_approve, "service_approve")
+        }
+    );
+    let mut chain = Chain::new(router);
+    chain.link(persistent::Read::<ManagerFs>::both(manager_state));
+    chain.link(persistent::Read::<ManagerOutputs>::both(outputs));
+    chain.link(persistent::Read::<CtlGateway>::both(Arc::new(ctl)));
+    chain
+}
+
+/// Compare two byte strings in time which depends only on their lengths, so that the control
+/// secret can't be guessed a byte at a time from how long a rejection takes.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 #[derive(Default, Serialize)]
 struct HealthCheckBody {
     stdout: String,
     stderr: String,
+    history: Vec<serde_json::Value>,
 }
 
 fn butterfly(req: &mut Request) -> IronResult<Response> {
@@ -207,9 +304,10 @@ fn config(req: &mut Request) -> IronResult<Response> {
 
 fn health(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
-    let (health_file, stdout_path, stderr_path) = match build_service_group(req) {
+    let (health_file, history_path, stdout_path, stderr_path) = match build_service_group(req) {
         Ok(sg) => {
             (state.health_check_cache(&sg),
+             state.health_check_history(&sg),
              hooks::stdout_log_path::<HealthCheckHook>(&sg),
              hooks::stderr_log_path::<HealthCheckHook>(&sg))
         }
@@ -228,6 +326,11 @@ fn health(req: &mut Request) -> IronResult<Response> {
             if let Ok(mut file) = File::open(&stderr_path) {
                 let _ = file.read_to_string(&mut body.stderr);
             }
+            if let Ok(file) = File::open(&history_path) {
+                if let Ok(history) = serde_json::from_reader(file) {
+                    body.history = history;
+                }
+            }
             Ok(Response::with((status,
                                Header(headers::ContentType::json()),
                                serde_json::to_string(&body).unwrap())))
@@ -236,6 +339,27 @@ fn health(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn logs(req: &mut Request) -> IronResult<Response> {
+    let ctl = req.get::<persistent::Read<CtlGateway>>().unwrap();
+    if !ctl.authorized(req) {
+        return Ok(Response::with(status::Unauthorized));
+    }
+    let outputs = req.get::<persistent::Read<ManagerOutputs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let output = outputs.get(&service_group);
+    match output {
+        Some(output) => {
+            Ok(Response::with((status::Ok,
+                               Header(headers::ContentType::json()),
+                               serde_json::to_string(&*output).unwrap())))
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
 fn services(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     match File::open(&state.services_data_path) {
@@ -244,6 +368,50 @@ fn services(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn service_load(req: &mut Request) -> IronResult<Response> {
+    let ctl = req.get::<persistent::Read<CtlGateway>>().unwrap();
+    if !ctl.authorized(req) {
+        return Ok(Response::with(status::Unauthorized));
+    }
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    match ServiceSpec::from_str(&body) {
+        Ok(spec) => Ok(ctl.send(CtlCommand::Load(spec))),
+        Err(err) => Ok(Response::with((status::BadRequest, err.to_string()))),
+    }
+}
+
+fn service_unload(req: &mut Request) -> IronResult<Response> {
+    ctl_for_service_group(req, CtlCommand::Unload)
+}
+
+fn service_start(req: &mut Request) -> IronResult<Response> {
+    ctl_for_service_group(req, CtlCommand::Start)
+}
+
+fn service_stop(req: &mut Request) -> IronResult<Response> {
+    ctl_for_service_group(req, CtlCommand::Stop)
+}
+
+fn service_approve(req: &mut Request) -> IronResult<Response> {
+    ctl_for_service_group(req, CtlCommand::Approve)
+}
+
+fn ctl_for_service_group<F>(req: &mut Request, cmd: F) -> IronResult<Response>
+    where F: FnOnce(ServiceGroup) -> CtlCommand
+{
+    let ctl = req.get::<persistent::Read<CtlGateway>>().unwrap();
+    if !ctl.authorized(req) {
+        return Ok(Response::with(status::Unauthorized));
+    }
+    match build_service_group(req) {
+        Ok(sg) => Ok(ctl.send(cmd(sg))),
+        Err(_) => Ok(Response::with(status::BadRequest)),
+    }
+}
+
 fn metrics(_req: &mut Request) -> IronResult<Response> {
     let mut buffer = vec![];
     let encoder = TextEncoder::new();
@@ -287,3 +455,142 @@ fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
                                    .find("org"))?;
     Ok(sg)
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::sync::mpsc::{self, Receiver};
+
+    use hcore::service::ServiceGroup;
+    use iron::{headers, status, Chain, Headers};
+    use iron_test::request;
+
+    use super::{chain, constant_time_eq, CtlGateway};
+    use manager::{CtlCommand, FsCfg, ServiceOutputs};
+
+    const SECRET: &'static str = "sekrit";
+
+    fn ctl_chain() -> (Chain, Receiver<CtlCommand>) {
+        let (tx, rx) = mpsc::channel();
+        let chain = chain(Arc::new(FsCfg::new("/tmp/http-gateway-test")),
+                          Arc::new(ServiceOutputs::default()),
+                          CtlGateway::new(SECRET.to_string(), tx));
+        (chain, rx)
+    }
+
+    fn bearer(token: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.set(headers::Authorization(headers::Bearer { token: token.to_string() }));
+        headers
+    }
+
+    #[test]
+    fn constant_time_eq_compares_contents_and_length() {
+        assert!(constant_time_eq(b"sekrit", b"sekrit"));
+        assert!(!constant_time_eq(b"sekrit", b"sekrix"));
+        assert!(!constant_time_eq(b"sekrit", b"sekri"));
+        assert!(!constant_time_eq(b"", b"sekrit"));
+    }
+
+    #[test]
+    fn ctl_requests_without_a_token_are_rejected() {
+        let (chain, rx) = ctl_chain();
+        let response = request::post("http://localhost/services/redis/default/stop",
+                                     Headers::new(),
+                                     "",
+                                     &chain)
+            .unwrap();
+
+        assert_eq!(Some(status::Unauthorized), response.status);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn ctl_requests_with_the_wrong_token_are_rejected() {
+        let (chain, rx) = ctl_chain();
+        let response = request::delete("http://localhost/services/redis/default",
+                                       bearer("wrong"),
+                                       &chain)
+            .unwrap();
+
+        assert_eq!(Some(status::Unauthorized), response.status);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn logs_require_a_token() {
+        let (chain, _) = ctl_chain();
+        let response = request::get("http://localhost/services/redis/default/logs",
+                                    Headers::new(),
+                                    &chain)
+            .unwrap();
+        assert_eq!(Some(status::Unauthorized), response.status);
+
+        let response = request::get("http://localhost/services/redis/default/logs",
+                                    bearer(SECRET),
+                                    &chain)
+            .unwrap();
+        assert_eq!(Some(status::NotFound), response.status);
+    }
+
+    #[test]
+    fn ctl_requests_for_a_service_group_are_forwarded() {
+        let (chain, rx) = ctl_chain();
+        let expected = ServiceGroup::from_str("redis.default").unwrap();
+
+        for action in &["start", "stop", "approve"] {
+            let response =
+                request::post(&format!("http://localhost/services/redis/default/{}", action),
+                              bearer(SECRET),
+                              "",
+                              &chain)
+                    .unwrap();
+            assert_eq!(Some(status::Accepted), response.status);
+            match (*action, rx.try_recv().unwrap()) {
+                ("start", CtlCommand::Start(sg)) |
+                ("stop", CtlCommand::Stop(sg)) |
+                ("approve", CtlCommand::Approve(sg)) => assert_eq!(expected, sg),
+                (action, cmd) => panic!("Unexpected command for {}, {:?}", action, cmd),
+            }
+        }
+        let response = request::delete("http://localhost/services/redis/default",
+                                       bearer(SECRET),
+                                       &chain)
+            .unwrap();
+        assert_eq!(Some(status::Accepted), response.status);
+        match rx.try_recv().unwrap() {
+            CtlCommand::Unload(sg) => assert_eq!(expected, sg),
+            cmd => panic!("Unexpected command for unload, {:?}", cmd),
+        }
+    }
+
+    #[test]
+    fn ctl_load_forwards_a_valid_spec() {
+        let (chain, rx) = ctl_chain();
+        let response = request::post("http://localhost/services",
+                                     bearer(SECRET),
+                                     "ident = \"core/redis\"\ngroup = \"prod\"\n",
+                                     &chain)
+            .unwrap();
+
+        assert_eq!(Some(status::Accepted), response.status);
+        match rx.try_recv().unwrap() {
+            CtlCommand::Load(spec) => {
+                assert_eq!("redis", spec.ident.name);
+                assert_eq!("prod", spec.group);
+            }
+            cmd => panic!("Unexpected command for load, {:?}", cmd),
+        }
+    }
+
+    #[test]
+    fn ctl_load_rejects_an_invalid_spec() {
+        let (chain, rx) = ctl_chain();
+        let response = request::post("http://localhost/services", bearer(SECRET), "", &chain)
+            .unwrap();
+
+        assert_eq!(Some(status::BadRequest), response.status);
+        assert!(rx.try_recv().is_err());
+    }
+}
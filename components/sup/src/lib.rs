@@ -40,6 +40,7 @@
 //! * [The Habitat Supervisor Sidecar; http interface to promises](sidecar)
 
 extern crate ansi_term;
+extern crate base64;
 #[macro_use]
 extern crate bitflags;
 #[cfg(windows)]
@@ -51,8 +52,12 @@ extern crate habitat_butterfly as butterfly;
 extern crate habitat_common as common;
 extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
+extern crate habitat_http_client as http_client;
 extern crate handlebars;
+extern crate hyper;
 extern crate iron;
+#[cfg(test)]
+extern crate iron_test;
 #[macro_use]
 extern crate lazy_static;
 extern crate libc;
@@ -24,7 +24,7 @@ extern crate libc;
 extern crate clap;
 extern crate url;
 
-use std::net::{SocketAddr, ToSocketAddrs};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
@@ -35,6 +35,7 @@ use hcore::env as henv;
 use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::crypto::init as crypto_init;
 use hcore::package::{PackageArchive, PackageIdent};
+use hcore::service::ServiceGroup;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use url::Url;
 
@@ -42,9 +43,14 @@ use sup::config::{GossipListenAddr, GOSSIP_DEFAULT_PORT};
 use sup::error::{Error, Result};
 use sup::feat;
 use sup::command;
+use sup::command::ctl::{CtlClient, CTL_SECRET_ENVVAR};
 use sup::http_gateway;
-use sup::manager::ManagerConfig;
-use sup::manager::service::{ServiceBind, ServiceSpec, Topology, UpdateStrategy};
+use sup::manager::{Manager, ManagerConfig};
+use sup::manager::service::{SecretDelivery, ServiceBind, ServiceSpec, Topology, UpdateBatch,
+                            UpdateStrategy};
+use sup::output::LogFormat;
+use sup::supervisor::{LogConfig, RestartPolicy};
+use sup::util::capabilities;
 
 /// Our output key
 static LOGKEY: &'static str = "MN";
@@ -68,10 +74,16 @@ fn start() -> Result<()> {
     crypto_init();
     let app_matches = cli().get_matches();
     match app_matches.subcommand() {
+        ("approve", Some(m)) => sub_approve(m),
         ("bash", Some(m)) => sub_bash(m),
         ("config", Some(m)) => sub_config(m),
+        ("depart", Some(m)) => sub_depart(m),
+        ("load", Some(m)) => sub_load(m),
         ("sh", Some(m)) => sub_sh(m),
         ("start", Some(m)) => sub_start(m),
+        ("status", Some(m)) => sub_status(m),
+        ("stop", Some(m)) => sub_stop(m),
+        ("unload", Some(m)) => sub_unload(m),
         _ => unreachable!(),
     }
 }
@@ -85,6 +97,21 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@setting SubcommandRequiredElseHelp)
         (@arg VERBOSE: -v +global "Verbose output; shows line numbers")
         (@arg NO_COLOR: --("no-color") +global "Turn ANSI color off")
+        (@arg LOG_FORMAT: --("log-format") +global +takes_value {valid_log_format}
+            "How to format output; json prints each line as a JSON object [default: text] \
+            [values: text, json]")
+        (@subcommand approve =>
+            (about: "Approve a canary update, letting the rest of the service group update to \
+                the package of its update leader")
+            (@arg PKG_IDENT: +required +takes_value
+                "A Habitat package identifier (ex: acme/redis)")
+            (@arg GROUP: --group +takes_value "The service group [default: default].")
+            (@arg ORGANIZATION: --org +takes_value "The organization of the service group")
+            (@arg NAME: --("override-name") +takes_value
+                "The name of the running Supervisor's state directory [default: default]")
+            (@arg REMOTE_SUP: --("remote-sup") +takes_value
+                "Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+        )
         (@subcommand bash =>
             (about: "Start an interactive Bash-like shell")
             (aliases: &["b", "ba", "bas"])
@@ -92,8 +119,87 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@subcommand config =>
             (about: "Displays the default configuration options for a service")
             (aliases: &["c", "co", "con", "conf", "confi"])
+            (@setting SubcommandsNegateReqs)
             (@arg PKG_IDENT: +required +takes_value
                 "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            (@subcommand render =>
+                (about: "Renders the configuration files and hooks of an installed package \
+                    without starting it")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg GROUP: --group +takes_value
+                    "The service group; shared config and topology [default: default].")
+                (@arg BIND: --bind +takes_value +multiple
+                    "One or more service groups to bind to a configuration")
+                (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                    "Use package config from this path, rather than the package itself")
+                (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                    "Use this file as the service's user.toml")
+                (@arg CENSUS: --census +takes_value {file_exists}
+                    "Render with the census in this JSON file, as returned by the /census \
+                    endpoint of a Supervisor")
+                (@arg OUTPUT_DIR: --output -o +takes_value
+                    "Write the rendered files to this directory rather than to standard output")
+            )
+        )
+        (@subcommand depart =>
+            (about: "Permanently remove a Supervisor from the ring")
+            (@arg MEMBER_ID: +required +takes_value "The member ID of the departing Supervisor")
+            (@arg PEER: --peer -p +takes_value
+                "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                [default: 127.0.0.1:9638]")
+            (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg MEMBER_KEY: --("member-key") +takes_value
+                "Ring member key name, which will sign the departure")
+        )
+        (@subcommand load =>
+            (about: "Load a service to be started and supervised by a running Supervisor")
+            (@arg PKG_IDENT: +required +takes_value
+                "A Habitat package identifier (ex: acme/redis)")
+            (@arg NAME: --("override-name") +takes_value
+                "The name of the running Supervisor's state directory [default: default]")
+            (@arg REMOTE_SUP: --("remote-sup") +takes_value
+                "Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+            (@arg GROUP: --group +takes_value
+                "The service group; shared config and topology [default: default].")
+            (@arg DEPOT_URL: --url -u +takes_value {valid_url}
+                "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+            (@arg CHANNEL: --channel +takes_value
+                "Only install and update to packages promoted to this Depot channel")
+            (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
+                "Service topology; [default: none]")
+            (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
+                "The update strategy; [default: none] \
+                [values: none, at-once, rolling, batch, canary]")
+            (@arg UPDATE_BATCH: --("update-batch") +takes_value {valid_update_batch}
+                "How many followers update at once with the batch and canary strategies, as a \
+                number of members or a percentage (ex: 3, 25%) [default: 1]")
+            (@arg CANARY_HEALTH_CHECKS: --("canary-health-checks") +takes_value {valid_numeric}
+                "Approve a canary update once the canary passes this many consecutive health \
+                checks; [default: 0, which requires approval with `hab-sup approve`]")
+            (@arg BIND: --bind +takes_value +multiple
+                "One or more service groups to bind to a configuration")
+            (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                "Use package config from this path, rather than the package itself")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service's process; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg ROLLBACK: --rollback
+                "Roll back to the previous package if an updated package fails its smoke test \
+                or health checks")
+            (@arg SVC_USER: --("svc-user") +takes_value
+                "Run the service as this user rather than the one its package asks for")
+            (@arg SVC_GROUP: --("svc-group") +takes_value
+                "Run the service as this group rather than the one its package asks for")
+            (@arg SUPPLEMENTARY_GROUP: --("supplementary-group") +takes_value +multiple
+                "One or more supplementary groups for the service's user")
+            (@arg CAPABILITY: --capability +takes_value +multiple {valid_capability}
+                "One or more Linux capabilities the service keeps when it isn't running as \
+                root (ex: CAP_NET_BIND_SERVICE)")
+            (@arg SECRET_DELIVERY: --("secret-delivery") +takes_value {valid_secret_delivery}
+                "How the service gets the files and configuration encrypted to its key; env and \
+                tmpfs keep them out of its files directory [default: files] \
+                [values: files, env, tmpfs]")
         )
         (@subcommand sh =>
             (about: "Start an interactive Bourne-like shell")
@@ -116,6 +222,33 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this service is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg MEMBER_KEY: --("member-key") +takes_value
+                "Ring member key name, which will sign and authenticate gossip messages")
+            (@arg RING_OPERATOR: --("ring-operator") +takes_value +multiple requires[MEMBER_KEY]
+                "Ring member key name of an operator, whose departures and update directives \
+                this Supervisor accepts")
+            (@arg PARTITION_STEP_DOWN: --("partition-step-down")
+                "Step down as leader of every service group while this Supervisor can reach half \
+                of the permanent peers or fewer")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_numeric}
+                "Forget the services of members confirmed dead, and the elections of service \
+                groups without services, after this many seconds [default: 86400]")
+            (@arg CGROUP_ROOT: --("cgroup-root") +takes_value
+                "The cgroup under which each service with resource limits gets a cgroup of its \
+                own [default: hab]")
+            (@arg SERVICE_LOGS: --("service-logs")
+                "Also write the output of each service to a log file in its logs directory")
+            (@arg SERVICE_LOG_MAX_BYTES: --("service-log-max-bytes") +takes_value
+                requires[SERVICE_LOGS] {valid_numeric}
+                "Rotate a service log file before it grows past this many bytes; 0 never \
+                rotates by size [default: 10485760]")
+            (@arg SERVICE_LOG_MAX_AGE: --("service-log-max-age") +takes_value
+                requires[SERVICE_LOGS] {valid_numeric}
+                "Rotate a service log file once it is this many seconds old; 0 never rotates by \
+                age [default: 0]")
+            (@arg SERVICE_LOG_KEEP: --("service-log-keep") +takes_value requires[SERVICE_LOGS]
+                {valid_numeric}
+                "How many rotated service log files to keep [default: 5]")
             (@arg PKG_IDENT_OR_ARTIFACT:
                 "A Habitat package identifier (ex: acme/redis) or filepath to a Habitat Artifact \
                 (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -125,19 +258,81 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                     "The service group; shared config and topology [default: default].")
                 (@arg DEPOT_URL: --url -u +takes_value {valid_url}
                     "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg CHANNEL: --channel +takes_value
+                    "Only install and update to packages promoted to this Depot channel")
                 (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                     "Service topology; [default: none]")
                 (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                    "The update strategy; [default: none] [values: none, at-once, rolling]")
+                    "The update strategy; [default: none] \
+                    [values: none, at-once, rolling, batch, canary]")
+                (@arg UPDATE_BATCH: --("update-batch") +takes_value {valid_update_batch}
+                    "How many followers update at once with the batch and canary strategies, as \
+                    a number of members or a percentage (ex: 3, 25%) [default: 1]")
+                (@arg CANARY_HEALTH_CHECKS: --("canary-health-checks") +takes_value
+                    {valid_numeric}
+                    "Approve a canary update once the canary passes this many consecutive \
+                    health checks; [default: 0, which requires approval with `hab-sup approve`]")
                 (@arg BIND: --bind +takes_value +multiple
                     "One or more service groups to bind to a configuration")
                 (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                     "Use package config from this path, rather than the package itself")
+                (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                    "When to restart the service's process; [default: always] \
+                    [values: always, on-failure, never]")
+                (@arg ROLLBACK: --rollback
+                    "Roll back to the previous package if an updated package fails its smoke \
+                    test or health checks")
+                (@arg SVC_USER: --("svc-user") +takes_value
+                    "Run the service as this user rather than the one its package asks for")
+                (@arg SVC_GROUP: --("svc-group") +takes_value
+                    "Run the service as this group rather than the one its package asks for")
+                (@arg SUPPLEMENTARY_GROUP: --("supplementary-group") +takes_value +multiple
+                    "One or more supplementary groups for the service's user")
+                (@arg CAPABILITY: --capability +takes_value +multiple {valid_capability}
+                    "One or more Linux capabilities the service keeps when it isn't running as \
+                    root (ex: CAP_NET_BIND_SERVICE)")
+                (@arg SECRET_DELIVERY: --("secret-delivery") +takes_value
+                    {valid_secret_delivery}
+                    "How the service gets the files and configuration encrypted to its key; env \
+                    and tmpfs keep them out of its files directory [default: files] \
+                    [values: files, env, tmpfs]")
             )
         )
+        (@subcommand status =>
+            (about: "Query the status of the services running on a Supervisor")
+            (@arg REMOTE_SUP: --("remote-sup") +takes_value
+                "Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+        )
+        (@subcommand stop =>
+            (about: "Stop a running service without unloading it from the Supervisor")
+            (@arg PKG_IDENT: +required +takes_value
+                "A Habitat package identifier (ex: acme/redis)")
+            (@arg GROUP: --group +takes_value "The service group [default: default].")
+            (@arg ORGANIZATION: --org +takes_value "The organization of the service group")
+            (@arg NAME: --("override-name") +takes_value
+                "The name of the running Supervisor's state directory [default: default]")
+            (@arg REMOTE_SUP: --("remote-sup") +takes_value
+                "Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+        )
+        (@subcommand unload =>
+            (about: "Stop a service and remove it from a running Supervisor")
+            (@arg PKG_IDENT: +required +takes_value
+                "A Habitat package identifier (ex: acme/redis)")
+            (@arg GROUP: --group +takes_value "The service group [default: default].")
+            (@arg ORGANIZATION: --org +takes_value "The organization of the service group")
+            (@arg NAME: --("override-name") +takes_value
+                "The name of the running Supervisor's state directory [default: default]")
+            (@arg REMOTE_SUP: --("remote-sup") +takes_value
+                "Address of the Supervisor's HTTP gateway [default: 127.0.0.1:9631]")
+        )
     )
 }
 
+fn sub_approve(m: &ArgMatches) -> Result<()> {
+    let service_group = try!(service_group_from_matches(m));
+    try!(ctl_client_from_matches(m)).approve(&service_group)
+}
+
 fn sub_bash(m: &ArgMatches) -> Result<()> {
     if m.is_present("VERBOSE") {
         sup::output::set_verbose(true);
@@ -145,17 +340,89 @@ fn sub_bash(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if let Some(format) = m.value_of("LOG_FORMAT") {
+        sup::output::set_log_format(try!(LogFormat::from_str(format)));
+    }
 
     command::shell::bash()
 }
 
 fn sub_config(m: &ArgMatches) -> Result<()> {
+    if let ("render", Some(m)) = m.subcommand() {
+        return sub_config_render(m);
+    }
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
     try!(common::command::package::config::start(&ident, "/"));
     Ok(())
 }
 
+fn sub_config_render(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        sup::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        sup::output::set_no_color(true);
+    }
+    if let Some(format) = m.value_of("LOG_FORMAT") {
+        sup::output::set_log_format(try!(LogFormat::from_str(format)));
+    }
+
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let spec = try!(spec_from_matches(&ident, m));
+    command::config::render(&spec,
+                            m.value_of("USER_TOML").map(Path::new),
+                            m.value_of("CENSUS").map(Path::new),
+                            m.value_of("OUTPUT_DIR").map(Path::new))
+}
+
+fn sub_depart(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        sup::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        sup::output::set_no_color(true);
+    }
+    if let Some(format) = m.value_of("LOG_FORMAT") {
+        sup::output::set_log_format(try!(LogFormat::from_str(format)));
+    }
+
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let peers: Vec<String> = peers_str.split(",")
+        .map(|p| if p.find(':').is_some() {
+                 p.to_string()
+             } else {
+                 format!("{}:{}", p, GOSSIP_DEFAULT_PORT)
+             })
+        .collect();
+    let ring_key = try!(ring_key_from_matches(m));
+    command::depart::run(m.value_of("MEMBER_ID").unwrap(),
+                         &peers,
+                         ring_key,
+                         m.value_of("MEMBER_KEY"))
+}
+
+fn sub_load(m: &ArgMatches) -> Result<()> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let spec = try!(spec_from_matches(&ident, m));
+    try!(ctl_client_from_matches(m)).load(&spec)
+}
+
+fn sub_status(m: &ArgMatches) -> Result<()> {
+    let remote_sup = try!(remote_sup_from_matches(m));
+    try!(CtlClient::new(&remote_sup, String::new())).status()
+}
+
+fn sub_stop(m: &ArgMatches) -> Result<()> {
+    let service_group = try!(service_group_from_matches(m));
+    try!(ctl_client_from_matches(m)).stop(&service_group)
+}
+
+fn sub_unload(m: &ArgMatches) -> Result<()> {
+    let service_group = try!(service_group_from_matches(m));
+    try!(ctl_client_from_matches(m)).unload(&service_group)
+}
+
 fn sub_sh(m: &ArgMatches) -> Result<()> {
     if m.is_present("VERBOSE") {
         sup::output::set_verbose(true);
@@ -163,6 +430,9 @@ fn sub_sh(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if let Some(format) = m.value_of("LOG_FORMAT") {
+        sup::output::set_log_format(try!(LogFormat::from_str(format)));
+    }
 
     command::shell::sh()
 }
@@ -174,6 +444,9 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if let Some(format) = m.value_of("LOG_FORMAT") {
+        sup::output::set_log_format(try!(LogFormat::from_str(format)));
+    }
 
     let mut cfg = ManagerConfig::default();
 
@@ -199,6 +472,22 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
     }
     cfg.organization = m.value_of("ORGANIZATION").map(|org| org.to_string());
     cfg.gossip_permanent = m.is_present("PERMANENT_PEER");
+    cfg.partition_step_down = m.is_present("PARTITION_STEP_DOWN");
+    cfg.rumor_ttl = m.value_of("RUMOR_TTL").map(|ttl| ttl.parse().unwrap());
+    cfg.cgroup_root = m.value_of("CGROUP_ROOT").map(PathBuf::from);
+    if m.is_present("SERVICE_LOGS") {
+        let mut log_config = LogConfig::default();
+        if let Some(max_bytes) = m.value_of("SERVICE_LOG_MAX_BYTES") {
+            log_config.max_bytes = max_bytes.parse().unwrap();
+        }
+        if let Some(max_age) = m.value_of("SERVICE_LOG_MAX_AGE") {
+            log_config.max_age = max_age.parse().unwrap();
+        }
+        if let Some(keep) = m.value_of("SERVICE_LOG_KEEP") {
+            log_config.keep = keep.parse().unwrap();
+        }
+        cfg.service_logs = Some(log_config);
+    }
     // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
     // causing a lot of developer/compiler type confusion
     let mut gossip_peers: Vec<SocketAddr> = Vec::new();
@@ -221,30 +510,13 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
         }
     }
     cfg.gossip_peers = gossip_peers;
-    let ring = match m.value_of("RING") {
-        Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
-        None => {
-            match henv::var(RING_KEY_ENVVAR) {
-                Ok(val) => {
-                    let (key, _) = try!(SymKey::write_file_from_str(&val,
-                                                                    &default_cache_key_path(None)));
-                    Some(key)
-                }
-                Err(_) => {
-                    match henv::var(RING_ENVVAR) {
-                        Ok(val) => {
-                            Some(try!(SymKey::get_latest_pair_for(&val,
-                                                                  &default_cache_key_path(None))))
-                        }
-                        Err(_) => None,
-                    }
-                }
-            }
-        }
-    };
-    if let Some(ring) = ring {
+    if let Some(ring) = try!(ring_key_from_matches(m)) {
         cfg.ring = Some(ring.name_with_rev());
     }
+    cfg.member_key = m.value_of("MEMBER_KEY").map(|name| name.to_string());
+    if let Some(operators) = m.values_of("RING_OPERATOR") {
+        cfg.ring_operators = operators.map(|name| name.to_string()).collect();
+    }
 
     let mut maybe_local_artifact: Option<&str> = None;
     let maybe_spec = match m.value_of("PKG_IDENT_OR_ARTIFACT") {
@@ -259,6 +531,21 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
         }
         None => None,
     };
+    // If a Supervisor is already running then hand the service over to it rather than trying
+    // to start a second one with the same state path.
+    if let (Some(spec), None) = (maybe_spec.as_ref(), maybe_local_artifact) {
+        if Manager::is_running(&cfg) {
+            let mut http_listen = cfg.http_listen.clone();
+            if http_listen.ip().is_unspecified() {
+                http_listen.set_ip(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+            }
+            let client = try!(CtlClient::new(&http_listen, try!(ctl_secret_for(&cfg))));
+            let org = cfg.organization.as_ref().map(|org| &**org);
+            let service_group = try!(ServiceGroup::new(&spec.ident.name, &spec.group, org));
+            try!(client.load(spec));
+            return client.start(&service_group);
+        }
+    }
     if !feat::is_enabled(feat::Multi) && !m.is_present("PKG_IDENT_OR_ARTIFACT") {
         // For now, mimick the "required argument" error message to preserve original behavior of a
         // require pkg ident or artifact
@@ -275,6 +562,33 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+/// Resolves the ring key from the `RING` argument, falling back to the ring key contents or ring
+/// key name given through the environment.
+fn ring_key_from_matches(m: &ArgMatches) -> Result<Option<SymKey>> {
+    let ring = match m.value_of("RING") {
+        Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
+        None => {
+            match henv::var(RING_KEY_ENVVAR) {
+                Ok(val) => {
+                    let (key, _) = try!(SymKey::write_file_from_str(&val,
+                                                                    &default_cache_key_path(None)));
+                    Some(key)
+                }
+                Err(_) => {
+                    match henv::var(RING_ENVVAR) {
+                        Ok(val) => {
+                            Some(try!(SymKey::get_latest_pair_for(&val,
+                                                                  &default_cache_key_path(None))))
+                        }
+                        Err(_) => None,
+                    }
+                }
+            }
+        }
+    };
+    Ok(ring)
+}
+
 fn spec_from_matches(ident: &PackageIdent, m: &ArgMatches) -> Result<ServiceSpec> {
     let mut spec = ServiceSpec::default_for(ident.clone());
 
@@ -284,12 +598,27 @@ fn spec_from_matches(ident: &PackageIdent, m: &ArgMatches) -> Result<ServiceSpec
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
     spec.depot_url = String::from(url);
+    if let Some(channel) = m.value_of("CHANNEL") {
+        spec.channel = Some(channel.to_string());
+    }
     if let Some(topology) = m.value_of("TOPOLOGY") {
         spec.topology = Topology::from_str(topology)?;
     }
     if let Some(ref strategy) = m.value_of("STRATEGY") {
         spec.update_strategy = UpdateStrategy::from_str(strategy)?;
     }
+    if let Some(batch) = m.value_of("UPDATE_BATCH") {
+        spec.update_batch = UpdateBatch::from_str(batch)?;
+    }
+    if let Some(checks) = m.value_of("CANARY_HEALTH_CHECKS") {
+        spec.canary_health_checks = checks.parse().expect("Canary health checks were validated");
+    }
+    if let Some(policy) = m.value_of("RESTART_POLICY") {
+        spec.restart_policy = RestartPolicy::from_str(policy)?;
+    }
+    if m.is_present("ROLLBACK") {
+        spec.rollback = true;
+    }
     if let Some(bind_strs) = m.values_of("BIND") {
         let mut binds = Vec::new();
         for bind_str in bind_strs {
@@ -297,6 +626,25 @@ fn spec_from_matches(ident: &PackageIdent, m: &ArgMatches) -> Result<ServiceSpec
         }
         spec.binds = binds;
     }
+    if let Some(user) = m.value_of("SVC_USER") {
+        spec.svc_user = Some(user.to_string());
+    }
+    if let Some(group) = m.value_of("SVC_GROUP") {
+        spec.svc_group = Some(group.to_string());
+    }
+    if let Some(groups) = m.values_of("SUPPLEMENTARY_GROUP") {
+        spec.supplementary_groups = groups.map(|group| group.to_string()).collect();
+    }
+    if let Some(capabilities) = m.values_of("CAPABILITY") {
+        let mut names = Vec::new();
+        for capability in capabilities {
+            names.push(capabilities::parse(capability)?.0.to_string());
+        }
+        spec.capabilities = names;
+    }
+    if let Some(delivery) = m.value_of("SECRET_DELIVERY") {
+        spec.secret_delivery = SecretDelivery::from_str(delivery)?;
+    }
     if let Some(ref config_from) = m.value_of("CONFIG_DIR") {
         spec.config_from = Some(PathBuf::from(config_from));
         outputln!("");
@@ -309,6 +657,32 @@ fn spec_from_matches(ident: &PackageIdent, m: &ArgMatches) -> Result<ServiceSpec
     Ok(spec)
 }
 
+fn remote_sup_from_matches(m: &ArgMatches) -> Result<http_gateway::ListenAddr> {
+    http_gateway::ListenAddr::from_str(m.value_of("REMOTE_SUP").unwrap_or("127.0.0.1:9631"))
+}
+
+fn service_group_from_matches(m: &ArgMatches) -> Result<ServiceGroup> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    ServiceGroup::new(&ident.name,
+                      m.value_of("GROUP").unwrap_or("default"),
+                      m.value_of("ORGANIZATION"))
+        .map_err(From::from)
+}
+
+fn ctl_client_from_matches(m: &ArgMatches) -> Result<CtlClient> {
+    let mut cfg = ManagerConfig::default();
+    cfg.name = m.value_of("NAME").map(|name| name.to_string());
+    let remote_sup = try!(remote_sup_from_matches(m));
+    CtlClient::new(&remote_sup, try!(ctl_secret_for(&cfg)))
+}
+
+fn ctl_secret_for(cfg: &ManagerConfig) -> Result<String> {
+    match henv::var(CTL_SECRET_ENVVAR) {
+        Ok(secret) => Ok(secret),
+        Err(_) => Manager::ctl_secret_for(cfg),
+    }
+}
+
 fn dir_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_dir() {
         Ok(())
@@ -317,6 +691,49 @@ fn dir_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
+fn valid_capability(val: String) -> result::Result<(), String> {
+    match capabilities::parse(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Linux capability: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_log_format(val: String) -> result::Result<(), String> {
+    match LogFormat::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Log format: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_numeric(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a valid number", &val)),
+    }
+}
+
+fn valid_restart_policy(val: String) -> result::Result<(), String> {
+    match RestartPolicy::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart policy: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_secret_delivery(val: String) -> result::Result<(), String> {
+    match SecretDelivery::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Secret delivery: '{}' is not valid", &val)),
+    }
+}
+
 fn valid_topology(val: String) -> result::Result<(), String> {
     match Topology::from_str(&val) {
         Ok(_) => Ok(()),
@@ -331,6 +748,13 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_update_batch(val: String) -> result::Result<(), String> {
+    match UpdateBatch::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Update batch size: '{}' is not valid", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
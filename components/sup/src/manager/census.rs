@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 use std::str::{self, FromStr};
@@ -25,6 +26,8 @@ use butterfly::rumor::service::SysInfo;
 use butterfly::member::{Member, Health};
 use toml;
 
+use manager::service::HealthCheck;
+
 static LOGKEY: &'static str = "CE";
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -75,11 +78,14 @@ pub struct CensusEntry {
     pub update_election_is_running: Option<bool>,
     pub update_election_is_no_quorum: Option<bool>,
     pub update_election_is_finished: Option<bool>,
+    pub update_approved_pkg: Option<PackageIdent>,
     pub initialized: Option<bool>,
     pub alive: Option<bool>,
     pub suspect: Option<bool>,
     pub confirmed: Option<bool>,
     pub persistent: Option<bool>,
+    pub health_check: Option<HealthCheck>,
+    pub healthy: Option<bool>,
 }
 
 impl CensusEntry {
@@ -217,6 +223,15 @@ impl CensusEntry {
         self.update_election_is_finished.unwrap_or(false)
     }
 
+    pub fn set_update_approved_pkg(&mut self, value: Option<PackageIdent>) {
+        self.update_approved_pkg = value;
+    }
+
+    /// Returns the package approved for update followers to update to, if any.
+    pub fn get_update_approved_pkg(&self) -> Option<&PackageIdent> {
+        self.update_approved_pkg.as_ref()
+    }
+
     pub fn get_election_status(&self) -> ElectionStatus {
         if self.get_election_is_running() {
             ElectionStatus::ElectionInProgress
@@ -269,6 +284,21 @@ impl CensusEntry {
         self.persistent.unwrap_or(false)
     }
 
+    pub fn set_health_check(&mut self, value: HealthCheck) {
+        self.health_check = Some(value);
+        self.healthy = Some(value.is_healthy());
+    }
+
+    pub fn get_health_check(&self) -> HealthCheck {
+        self.health_check.unwrap_or_default()
+    }
+
+    /// Returns true if the member's service is passing its health check. Members which haven't
+    /// gossiped their health, such as older Supervisors, are assumed to be healthy.
+    pub fn get_healthy(&self) -> bool {
+        self.healthy.unwrap_or(true)
+    }
+
     pub fn populate_from_service(&mut self, rumor: &ServiceRumor) {
         self.set_member_id(String::from(rumor.get_member_id()));
         let sg = match ServiceGroup::from_str(rumor.get_service_group()) {
@@ -291,6 +321,12 @@ impl CensusEntry {
         }
         self.cfg = toml::from_slice(rumor.get_cfg()).unwrap_or(toml::value::Table::default());
         self.sys = toml::from_slice(rumor.get_sys()).unwrap_or(SysInfo::default());
+        if rumor.has_health_check() {
+            match HealthCheck::from_str(rumor.get_health_check()) {
+                Ok(health_check) => self.set_health_check(health_check),
+                Err(err) => warn!("Received a bad health check from gossip data, err={}", err),
+            }
+        }
     }
 
     pub fn populate_from_member(&mut self, member: &Member) {
@@ -359,6 +395,7 @@ impl CensusEntry {
                 self.set_update_election_is_running(true);
                 self.set_update_election_is_no_quorum(false);
                 self.set_update_election_is_finished(false);
+                self.set_update_approved_pkg(None);
             }
             Election_Status::NoQuorum => {
                 self.set_update_leader(false);
@@ -366,6 +403,7 @@ impl CensusEntry {
                 self.set_update_election_is_running(false);
                 self.set_update_election_is_no_quorum(true);
                 self.set_update_election_is_finished(false);
+                self.set_update_approved_pkg(None);
             }
             Election_Status::Finished => {
                 if self.get_member_id() == election.get_member_id() {
@@ -378,6 +416,8 @@ impl CensusEntry {
                 self.set_update_election_is_running(false);
                 self.set_update_election_is_no_quorum(false);
                 self.set_update_election_is_finished(true);
+                self.set_update_approved_pkg(PackageIdent::from_str(election.get_approved_pkg())
+                                                 .ok());
             }
         }
     }
@@ -434,6 +474,14 @@ impl Census {
         members
     }
 
+    /// Return all alive members which are passing their health check.
+    pub fn healthy_members(&self) -> Vec<&CensusEntry> {
+        self.population
+            .values()
+            .filter(|ce| ce.get_alive() && ce.get_healthy())
+            .collect()
+    }
+
     /// Return all members.
     pub fn members(&self) -> Vec<&CensusEntry> {
         self.population
@@ -483,6 +531,34 @@ impl Census {
         entry.get_service()
     }
 
+    /// Return all alive members except the update leader, ordered by member_id. This is the order
+    /// in which update followers are batched.
+    pub fn update_followers_ordered(&self) -> Vec<&CensusEntry> {
+        self.alive_members_ordered()
+            .into_iter()
+            .filter(|ce| !ce.get_update_leader())
+            .collect()
+    }
+
+    /// Return true if it's our turn to update to the update leader's package when update followers
+    /// update in batches of the given size, which is once every follower in an earlier batch is
+    /// running it.
+    pub fn is_update_batch_turn(&self, batch_size: usize) -> bool {
+        let (leader, me) = match (self.get_update_leader(), self.me()) {
+            (Some(leader), Some(me)) => (leader, me),
+            _ => return false,
+        };
+        let batch_size = cmp::max(batch_size, 1);
+        let followers = self.update_followers_ordered();
+        match followers.iter().position(|ce| ce.member_id == me.member_id) {
+            Some(idx) => {
+                let batch_start = idx / batch_size * batch_size;
+                followers[..batch_start].iter().all(|ce| ce.pkg == leader.pkg)
+            }
+            None => false,
+        }
+    }
+
     /// Return next alive peer, the peer to your right in the ordered members list, or None if you
     /// have no alive peers.
     pub fn next_peer(&self) -> Option<&CensusEntry> {
@@ -600,6 +676,7 @@ mod tests {
         use hcore::package::ident::PackageIdent;
 
         use manager::census::CensusEntry;
+        use manager::service::HealthCheck;
 
         #[test]
         fn member_id() {
@@ -625,6 +702,24 @@ mod tests {
             assert_eq!(ce.get_group(), "times");
             assert_eq!(ce.get_org(), "ofgrace");
             assert_eq!(ce.get_pkg(), &ident);
+            assert_eq!(ce.health_check, None);
+            assert!(ce.get_healthy());
+        }
+
+        #[test]
+        fn populate_from_service_rumor_with_health_check() {
+            let mut ce = CensusEntry::default();
+            let ident = PackageIdent::from_str("core/overwatch/1.2.3/20161208121212").unwrap();
+            let sg = ServiceGroup::new("overwatch", "times", None).unwrap();
+            let mut service = Service::new("neurosis".to_string(),
+                                           &ident,
+                                           &sg,
+                                           &SysInfo::default(),
+                                           None);
+            service.set_health_check(HealthCheck::Critical.to_string());
+            ce.populate_from_service(&service);
+            assert_eq!(ce.get_health_check(), HealthCheck::Critical);
+            assert!(!ce.get_healthy());
         }
 
         #[test]
@@ -639,4 +734,58 @@ mod tests {
             assert_eq!(ce.get_persistent(), member.get_persistent());
         }
     }
+
+    mod census {
+        use std::str::FromStr;
+
+        use hcore::package::ident::PackageIdent;
+
+        use manager::census::{Census, CensusEntry};
+
+        fn census_entry(member_id: &str, pkg: &str) -> CensusEntry {
+            let mut ce = CensusEntry::default();
+            ce.set_member_id(String::from(member_id));
+            ce.set_pkg(PackageIdent::from_str(pkg).unwrap());
+            ce.set_alive(true);
+            ce
+        }
+
+        fn census(me: &str, entries: Vec<CensusEntry>) -> Census {
+            let mut census = Census::new(String::from(me));
+            for ce in entries {
+                census.insert(String::from(ce.get_member_id()), ce);
+            }
+            census
+        }
+
+        #[test]
+        fn is_update_batch_turn() {
+            let old = "core/redis/3.2.4/20170514150022";
+            let new = "core/redis/3.2.9/20170602173812";
+            let mut leader = census_entry("a", new);
+            leader.set_update_leader(true);
+            let entries = vec![leader,
+                               census_entry("b", new),
+                               census_entry("c", old),
+                               census_entry("d", old),
+                               census_entry("e", old)];
+            let leader_census = census("a", entries.clone());
+            let followers: Vec<&str> = leader_census.update_followers_ordered()
+                .iter()
+                .map(|ce| ce.get_member_id())
+                .collect();
+            assert_eq!(followers, vec!["b", "c", "d", "e"]);
+
+            // The first batch of followers is b and c, which may update straight away
+            assert!(census("c", entries.clone()).is_update_batch_turn(2));
+            // d and e wait until every follower in the first batch has updated
+            assert!(!census("d", entries.clone()).is_update_batch_turn(2));
+            assert!(!census("e", entries.clone()).is_update_batch_turn(2));
+            // In batches of one, d waits for c
+            assert!(!census("d", entries.clone()).is_update_batch_turn(1));
+            assert!(census("c", entries.clone()).is_update_batch_turn(1));
+            // The leader isn't a follower so it's never its turn
+            assert!(!leader_census.is_update_batch_turn(2));
+        }
+    }
 }
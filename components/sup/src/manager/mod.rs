@@ -20,26 +20,32 @@ pub mod spec_watcher;
 
 use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
 use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::thread;
 use std::sync::{Arc, RwLock};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
 use std::time::Duration;
 
 use butterfly;
+use butterfly::identity::Identity;
 use butterfly::member::Member;
+use butterfly::ring_keys::RING_KEY_ACTIVATION_DEFAULT_MS;
 use butterfly::trace::Trace;
 use butterfly::server::timing::Timing;
 use butterfly::server::Suitability;
 use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::service::ServiceGroup;
 use hcore::os::process;
+use prometheus::Gauge;
+use rand::{self, Rng};
 use serde_json;
 use time::{SteadyTime, Duration as TimeDuration};
 use toml;
 
-pub use manager::service::{Service, ServiceConfig, ServiceSpec, UpdateStrategy, Topology};
+pub use manager::service::{DesiredState, Service, ServiceConfig, ServiceSpec, UpdateBatch,
+                           UpdateStrategy, Topology};
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use error::{Error, Result};
@@ -48,13 +54,34 @@ use config::GossipListenAddr;
 use manager::census::{CensusUpdate, CensusList, CensusEntry};
 use manager::signals::SignalEvent;
 use http_gateway;
+use supervisor::{LogConfig, ProcessOutput};
+use util::cgroup::CGROUP_ROOT_DEFAULT;
 
 const STATE_PATH_PREFIX: &'static str = "/hab/sup";
 const MEMBER_ID_FILE: &'static str = "MEMBER_ID";
+const CTL_SECRET_FILE: &'static str = "CTL_SECRET";
+const CTL_SECRET_LEN: usize = 64;
 const PROC_LOCK_FILE: &'static str = "LOCK";
 
 static LOGKEY: &'static str = "MR";
 
+lazy_static! {
+    static ref PERSISTENT_MEMBERS_GAUGE: Gauge = register_gauge!(
+        opts!(
+            "butterfly_persistent_members",
+            "Number of persistent members in the gossip ring.")).unwrap();
+
+    static ref REACHABLE_PERSISTENT_MEMBERS_GAUGE: Gauge = register_gauge!(
+        opts!(
+            "butterfly_reachable_persistent_members",
+            "Number of persistent members which are not confirmed dead.")).unwrap();
+
+    static ref MINORITY_PARTITION_GAUGE: Gauge = register_gauge!(
+        opts!(
+            "butterfly_minority_partition",
+            "1 if this Supervisor can reach half of the persistent members or fewer.")).unwrap();
+}
+
 /// FileSystem paths that the Manager uses to persist data to disk.
 ///
 /// This is shared with the `http_gateway` and `service` modules for reading and writing
@@ -88,6 +115,40 @@ impl FsCfg {
     pub fn health_check_cache(&self, service_group: &ServiceGroup) -> PathBuf {
         self.data_path.join(format!("{}.health", service_group.service()))
     }
+
+    pub fn health_check_history(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path.join(format!("{}.health.history", service_group.service()))
+    }
+}
+
+/// The output of each running service by service group, so the http gateway can serve it
+/// without waiting on the services lock, which is held while every service ticks.
+#[derive(Debug, Default)]
+pub struct ServiceOutputs(RwLock<HashMap<ServiceGroup, Arc<ProcessOutput>>>);
+
+impl ServiceOutputs {
+    pub fn get(&self, service_group: &ServiceGroup) -> Option<Arc<ProcessOutput>> {
+        self.0
+            .read()
+            .expect("Service outputs lock is poisoned!")
+            .get(service_group)
+            .cloned()
+    }
+
+    fn snapshot(&self, services: &[Service]) {
+        let outputs = services.iter().map(|s| (s.service_group.clone(), s.output())).collect();
+        *self.0.write().expect("Service outputs lock is poisoned!") = outputs;
+    }
+}
+
+/// Commands which can be sent to a running Manager through the control gateway.
+#[derive(Debug)]
+pub enum CtlCommand {
+    Load(ServiceSpec),
+    Unload(ServiceGroup),
+    Start(ServiceGroup),
+    Stop(ServiceGroup),
+    Approve(ServiceGroup),
 }
 
 #[derive(Default)]
@@ -97,6 +158,12 @@ pub struct ManagerConfig {
     pub gossip_peers: Vec<SocketAddr>,
     pub gossip_permanent: bool,
     pub ring: Option<String>,
+    pub member_key: Option<String>,
+    pub ring_operators: Vec<String>,
+    pub partition_step_down: bool,
+    pub rumor_ttl: Option<u64>,
+    pub service_logs: Option<LogConfig>,
+    pub cgroup_root: Option<PathBuf>,
     pub name: Option<String>,
     pub custom_state_path: Option<PathBuf>,
     pub organization: Option<String>,
@@ -112,6 +179,13 @@ pub struct Manager {
     gossip_listen: GossipListenAddr,
     http_listen: http_gateway::ListenAddr,
     organization: Option<String>,
+    service_logs: Option<LogConfig>,
+    cgroup_root: PathBuf,
+    timing: Timing,
+    ctl_secret: String,
+    ctl_rx: Receiver<CtlCommand>,
+    ctl_tx: Sender<CtlCommand>,
+    service_outputs: Arc<ServiceOutputs>,
 }
 
 impl Manager {
@@ -120,13 +194,18 @@ impl Manager {
         Self::create_state_path_dirs(&state_path)?;
         Self::clean_dirty_state(&state_path)?;
         let member = Self::load_member(&state_path)?;
+        let ctl_secret = Self::load_ctl_secret(&state_path)?;
         let fs_cfg = FsCfg::new(state_path);
         obtain_process_lock(&fs_cfg)?;
 
-        Self::new(cfg, member, fs_cfg)
+        Self::new(cfg, member, fs_cfg, ctl_secret)
     }
 
-    pub fn new(cfg: ManagerConfig, mut member: Member, fs_cfg: FsCfg) -> Result<Manager> {
+    pub fn new(cfg: ManagerConfig,
+               mut member: Member,
+               fs_cfg: FsCfg,
+               ctl_secret: String)
+               -> Result<Manager> {
         member.set_persistent(cfg.gossip_permanent);
         member.set_swim_port(cfg.gossip_listen.port() as i32);
         member.set_gossip_port(cfg.gossip_listen.port() as i32);
@@ -140,7 +219,7 @@ impl Manager {
         };
 
         let services = Arc::new(RwLock::new(Vec::new()));
-        let server = butterfly::Server::new(&cfg.gossip_listen,
+        let mut server = butterfly::Server::new(&cfg.gossip_listen,
                                             &cfg.gossip_listen,
                                             member,
                                             Trace::default(),
@@ -148,6 +227,17 @@ impl Manager {
                                             None,
                                             Some(&fs_cfg.data_path),
                                             Box::new(SuitabilityLookup(services.clone())))?;
+        // Accept every revision of the ring key, so it can be rotated without a restart.
+        server.watch_ring_keys(default_cache_key_path(None),
+                               Duration::from_millis(RING_KEY_ACTIVATION_DEFAULT_MS));
+        if let Some(ref member_key) = cfg.member_key {
+            let mut identity = Identity::load(member_key, &default_cache_key_path(None))?;
+            identity.set_operators(cfg.ring_operators.clone());
+            outputln!("Authenticating gossip as ring member {}",
+                      identity.name_with_rev());
+            server.set_identity(identity);
+        }
+        server.set_partition_step_down(cfg.partition_step_down);
         outputln!("Butterfly Member ID {}", server.member_id());
         for peer_addr in &cfg.gossip_peers {
             let mut peer = Member::default();
@@ -156,6 +246,11 @@ impl Manager {
             peer.set_gossip_port(peer_addr.port() as i32);
             server.member_list.add_initial_member(peer);
         }
+        let mut timing = Timing::default();
+        if let Some(rumor_ttl) = cfg.rumor_ttl {
+            timing.rumor_ttl_ms = rumor_ttl as i64 * 1000;
+        }
+        let (ctl_tx, ctl_rx) = mpsc::channel();
         Ok(Manager {
                updater: ServiceUpdater::new(server.clone()),
                butterfly: server,
@@ -166,9 +261,54 @@ impl Manager {
                gossip_listen: cfg.gossip_listen,
                http_listen: cfg.http_listen,
                organization: cfg.organization,
+               service_logs: cfg.service_logs,
+               cgroup_root: cfg.cgroup_root.unwrap_or(PathBuf::from(CGROUP_ROOT_DEFAULT)),
+               timing: timing,
+               ctl_secret: ctl_secret,
+               ctl_rx: ctl_rx,
+               ctl_tx: ctl_tx,
+               service_outputs: Arc::new(ServiceOutputs::default()),
            })
     }
 
+    /// Read the shared secret which authenticates requests to the control gateway, generating
+    /// and persisting a new one if this is the first time the Supervisor has been started.
+    pub fn load_ctl_secret<T>(state_path: T) -> Result<String>
+        where T: AsRef<Path>
+    {
+        let file_path = state_path.as_ref().join(CTL_SECRET_FILE);
+        match File::open(&file_path) {
+            Ok(mut file) => {
+                let mut secret = String::new();
+                file.read_to_string(&mut secret)
+                    .map_err(|e| sup_error!(Error::BadDataFile(file_path.clone(), e)))?;
+                Ok(secret.trim().to_string())
+            }
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => {
+                let secret: String = rand::thread_rng()
+                    .gen_ascii_chars()
+                    .take(CTL_SECRET_LEN)
+                    .collect();
+                create_private_file(&file_path)
+                    .and_then(|mut file| file.write_all(secret.as_bytes()))
+                    .map_err(|e| sup_error!(Error::BadDataFile(file_path.clone(), e)))?;
+                Ok(secret)
+            }
+            Err(err) => Err(sup_error!(Error::BadDataFile(file_path.clone(), err))),
+        }
+    }
+
+    /// Read the control gateway secret of the Supervisor described by the given configuration.
+    pub fn ctl_secret_for(cfg: &ManagerConfig) -> Result<String> {
+        let file_path = Self::state_path_from(cfg).join(CTL_SECRET_FILE);
+        let mut file = File::open(&file_path)
+            .map_err(|e| sup_error!(Error::BadDataFile(file_path.clone(), e)))?;
+        let mut secret = String::new();
+        file.read_to_string(&mut secret)
+            .map_err(|e| sup_error!(Error::BadDataFile(file_path.clone(), e)))?;
+        Ok(secret.trim().to_string())
+    }
+
     pub fn load_member<T>(state_path: T) -> Result<Member>
         where T: AsRef<Path>
     {
@@ -194,6 +334,24 @@ impl Manager {
         Ok(member)
     }
 
+    /// Returns true if a Supervisor is already running with the state path of the given
+    /// configuration.
+    pub fn is_running(cfg: &ManagerConfig) -> bool {
+        let lock_file = Self::state_path_from(cfg).join(PROC_LOCK_FILE);
+        match File::open(&lock_file) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                match reader.lines().next() {
+                    Some(Ok(line)) => {
+                        line.parse::<u32>().map(|pid| process::is_alive(pid)).unwrap_or(false)
+                    }
+                    _ => false,
+                }
+            }
+            Err(_) => false,
+        }
+    }
+
     pub fn specs_path_for(cfg: &ManagerConfig) -> PathBuf {
         Self::specs_path(&Self::state_path_from(cfg))
     }
@@ -275,7 +433,9 @@ impl Manager {
                                     &self.gossip_listen,
                                     &self.http_listen,
                                     self.fs_cfg.clone(),
-                                    self.organization.as_ref().map(|org| &**org))?;
+                                    self.organization.as_ref().map(|org| &**org),
+                                    self.service_logs.clone(),
+                                    &self.cgroup_root)?;
         service.add()?;
         self.butterfly.insert_service(service.to_rumor(self.butterfly.member_id()));
         if service.topology == Topology::Leader {
@@ -305,11 +465,16 @@ impl Manager {
         }
 
         outputln!("Starting butterfly on {}", self.butterfly.gossip_addr());
-        try!(self.butterfly.start(Timing::default()));
+        try!(self.butterfly.start(self.timing.clone()));
         debug!("butterfly server started");
         self.persist_state();
         outputln!("Starting http-gateway on {}", self.http_listen);
-        try!(http_gateway::Server::new(self.fs_cfg.clone(), self.http_listen.clone()).start());
+        let ctl = http_gateway::CtlGateway::new(self.ctl_secret.clone(), self.ctl_tx.clone());
+        try!(http_gateway::Server::new(self.fs_cfg.clone(),
+                                       self.service_outputs.clone(),
+                                       self.http_listen.clone(),
+                                       ctl)
+                     .start());
         debug!("http-gateway server started");
 
         let mut last_census_update = CensusUpdate::default();
@@ -320,11 +485,17 @@ impl Manager {
                 self.shutdown();
                 return Ok(());
             }
+            if self.butterfly.departed() {
+                self.shutdown();
+                return Err(sup_error!(Error::Departed(self.butterfly.member_id().to_string())));
+            }
+            self.check_for_ctl_commands(&mut last_census_update);
             if feat::is_enabled(feat::Multi) {
-                self.update_running_services_from_watcher()?;
+                self.update_running_services_from_watcher(&mut last_census_update)?;
             }
             self.check_for_updated_packages(&mut last_census_update);
             self.restart_elections();
+            self.update_partition_metrics();
             let (census_updated, ncu) = self.build_census(&last_census_update);
             if census_updated {
                 last_census_update = ncu;
@@ -339,6 +510,8 @@ impl Manager {
                              census_updated,
                              &mut last_census_update)
             }
+            self.service_outputs
+                .snapshot(&self.services.read().expect("Services lock is poisoned!"));
             let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
             if time_to_wait > 0 {
                 thread::sleep(Duration::from_millis(time_to_wait as u64));
@@ -432,6 +605,165 @@ impl Manager {
         }
     }
 
+    /// Drain any commands received by the control gateway and apply them to the running services.
+    fn check_for_ctl_commands(&mut self, last_update: &mut CensusUpdate) {
+        loop {
+            let cmd = match self.ctl_rx.try_recv() {
+                Ok(cmd) => cmd,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    warn!("Control gateway channel disconnected");
+                    return;
+                }
+            };
+            debug!("Received control command, {:?}", cmd);
+            let result = match cmd {
+                CtlCommand::Load(spec) => self.load_service(spec),
+                CtlCommand::Unload(service_group) => self.unload_service(&service_group),
+                CtlCommand::Start(service_group) => {
+                    self.set_desired_state(&service_group, DesiredState::Up, last_update)
+                }
+                CtlCommand::Stop(service_group) => {
+                    self.set_desired_state(&service_group, DesiredState::Down, last_update)
+                }
+                CtlCommand::Approve(service_group) => self.approve_update(&service_group),
+            };
+            if let Err(err) = result {
+                outputln!("Failed to process control command, {}", err);
+            }
+        }
+    }
+
+    /// Persist the spec to the specs directory and start the service it describes.
+    fn load_service(&mut self, spec: ServiceSpec) -> Result<()> {
+        let loaded_group = self.services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .find(|s| s.spec_ident.name == spec.ident.name)
+            .map(|s| s.service_group.group().to_string());
+        match loaded_group {
+            Some(ref group) if *group == spec.group => {
+                outputln!("Service {}.{} is already loaded, skipping", &spec.ident, group);
+                return Ok(());
+            }
+            // Specs are stored by package name, so only one group of a package can be loaded.
+            Some(group) => {
+                return Err(sup_error!(Error::ServiceLoaded(format!("{}.{}",
+                                                                   spec.ident.name,
+                                                                   group))));
+            }
+            None => (),
+        }
+        spec.to_file(self.fs_cfg.specs_path.join(spec.file_name()))?;
+        self.add_service(spec)
+    }
+
+    /// Stop and remove the running service, deleting its spec from the specs directory.
+    fn unload_service(&mut self, service_group: &ServiceGroup) -> Result<()> {
+        let spec = match self.services
+                  .read()
+                  .expect("Services lock is poisoned!")
+                  .iter()
+                  .find(|s| s.service_group == *service_group) {
+            Some(service) => service.to_spec(),
+            None => {
+                outputln!("Tried to unload {} but could not find it running, skipping",
+                          service_group);
+                return Ok(());
+            }
+        };
+        self.remove_service_for_spec(&spec)?;
+        let spec_file = self.fs_cfg.specs_path.join(spec.file_name());
+        if let Err(err) = fs::remove_file(&spec_file) {
+            debug!("Couldn't remove spec file {}, {}", spec_file.display(), err);
+        }
+        Ok(())
+    }
+
+    /// Bring the service up or down without unloading it, persisting the new desired state to
+    /// the service's spec file so it survives a Supervisor restart.
+    fn set_desired_state(&mut self,
+                         service_group: &ServiceGroup,
+                         desired_state: DesiredState,
+                         last_update: &mut CensusUpdate)
+                         -> Result<()> {
+        let mut services = self.services.write().expect("Services lock is poisoned!");
+        let service = match services.iter_mut().find(|s| s.service_group == *service_group) {
+            Some(service) => service,
+            None => {
+                outputln!("Could not find {} running, skipping", service_group);
+                return Ok(());
+            }
+        };
+        service.set_desired_state(desired_state);
+        let spec = service.to_spec();
+        spec.to_file(self.fs_cfg.specs_path.join(spec.file_name()))?;
+        self.gossip_desired_state(service, last_update);
+        Ok(())
+    }
+
+    /// Apply a desired state change which was made directly to a service's spec file.
+    fn update_desired_state_for_spec(&mut self,
+                                     spec: &ServiceSpec,
+                                     last_update: &mut CensusUpdate) {
+        let mut services = self.services.write().expect("Services lock is poisoned!");
+        match services.iter_mut().find(|s| s.spec_ident == spec.ident) {
+            Some(service) => {
+                service.set_desired_state(spec.desired_state);
+                self.gossip_desired_state(service, last_update);
+            }
+            None => {
+                outputln!("Tried to change desired state of {} but could not find it running, \
+                           skipping",
+                          &spec.ident)
+            }
+        }
+    }
+
+    /// Approve the package the update leader of the service group is running for the rest of the
+    /// group to update to, releasing the followers of a canary update.
+    fn approve_update(&mut self, service_group: &ServiceGroup) -> Result<()> {
+        let pkg = match self.census_list
+                  .get(&*service_group)
+                  .and_then(|census| census.get_update_leader())
+                  .and_then(|leader| leader.pkg.as_ref()) {
+            Some(pkg) => pkg.to_string(),
+            None => {
+                outputln!("Could not find an update leader for {}, skipping", service_group);
+                return Ok(());
+            }
+        };
+        if self.butterfly.approve_update_election(&*service_group, &pkg) {
+            outputln!("Approved {} for update followers of {}", pkg, service_group);
+        } else {
+            outputln!("The update election of {} hasn't finished, skipping", service_group);
+        }
+        Ok(())
+    }
+
+    /// Gossip the service's new desired state, telling the run loop that we updated the service
+    /// counter ourselves.
+    fn gossip_desired_state(&self, service: &Service, last_update: &mut CensusUpdate) {
+        let member_id = self.butterfly.member_id().to_string();
+        let mut updated = None;
+        self.butterfly
+            .service_store
+            .with_rumor(&*service.service_group, &member_id, |rumor| {
+                if let Some(rumor) = rumor {
+                    let mut rumor = rumor.clone();
+                    let incarnation = rumor.get_incarnation() + 1;
+                    rumor.set_desired_state(service.desired_state.to_string());
+                    rumor.set_incarnation(incarnation);
+                    updated = Some(rumor);
+                }
+            });
+        if let Some(rumor) = updated {
+            self.butterfly.insert_service(rumor);
+            last_update.service_counter += 1;
+        }
+    }
+
     /// Walk each service and check if it has an updated package installed via the Update Strategy.
     /// This updates the Service to point to the new service struct, and then marks it for
     /// restarting.
@@ -562,6 +894,13 @@ impl Manager {
         self.butterfly.restart_elections();
     }
 
+    fn update_partition_metrics(&self) {
+        let partition = &self.butterfly.partition;
+        PERSISTENT_MEMBERS_GAUGE.set(partition.persistent_members() as f64);
+        REACHABLE_PERSISTENT_MEMBERS_GAUGE.set(partition.reachable_members() as f64);
+        MINORITY_PARTITION_GAUGE.set(if partition.in_minority() { 1.0 } else { 0.0 });
+    }
+
     fn shutdown(&self) {
         let mut services = self.services.write().expect("Services lock is poisend!");
         for service in services.drain(..) {
@@ -583,7 +922,9 @@ impl Manager {
         Ok(())
     }
 
-    fn update_running_services_from_watcher(&mut self) -> Result<()> {
+    fn update_running_services_from_watcher(&mut self,
+                                            last_update: &mut CensusUpdate)
+                                            -> Result<()> {
         let mut active_specs = HashMap::new();
         for service in self.services
                 .read()
@@ -596,6 +937,9 @@ impl Manager {
             match service_event {
                 SpecWatcherEvent::AddService(spec) => self.add_service(spec)?,
                 SpecWatcherEvent::RemoveService(spec) => self.remove_service_for_spec(&spec)?,
+                SpecWatcherEvent::ChangeDesiredState(spec) => {
+                    self.update_desired_state_for_spec(&spec, last_update)
+                }
             }
         }
         Ok(())
@@ -681,11 +1025,68 @@ fn write_process_lock<T>(lock_path: T) -> Result<()>
     }
 }
 
+/// Create a new file which only the Supervisor's user can read, failing if it already exists.
+#[cfg(unix)]
+fn create_private_file(path: &Path) -> io::Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    OpenOptions::new().write(true).create_new(true).mode(0o600).open(path)
+}
+
+#[cfg(windows)]
+fn create_private_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
 #[cfg(test)]
 mod test {
+    use std::fs::File;
     use std::path::PathBuf;
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    use tempdir::TempDir;
+
+    use super::{Manager, ManagerConfig, CTL_SECRET_FILE, CTL_SECRET_LEN, STATE_PATH_PREFIX};
+
+    #[test]
+    fn manager_ctl_secret_is_generated_once() {
+        let tmpdir = TempDir::new("state").unwrap();
+        let secret = Manager::load_ctl_secret(tmpdir.path()).unwrap();
+
+        assert_eq!(CTL_SECRET_LEN, secret.len());
+        assert_eq!(secret, Manager::load_ctl_secret(tmpdir.path()).unwrap());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn manager_ctl_secret_is_only_readable_by_its_owner() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmpdir = TempDir::new("state").unwrap();
+        Manager::load_ctl_secret(tmpdir.path()).unwrap();
+        let metadata = fs::metadata(tmpdir.path().join(CTL_SECRET_FILE)).unwrap();
+
+        assert_eq!(0o600, metadata.permissions().mode() & 0o777);
+    }
+
+    #[test]
+    fn manager_ctl_secret_is_not_regenerated_on_other_errors() {
+        let tmpdir = TempDir::new("state").unwrap();
+        let not_a_dir = tmpdir.path().join("file");
+        File::create(&not_a_dir).unwrap();
+
+        assert!(Manager::load_ctl_secret(&not_a_dir).is_err());
+    }
+
+    #[test]
+    fn manager_ctl_secret_for_reads_state_path() {
+        let tmpdir = TempDir::new("state").unwrap();
+        let secret = Manager::load_ctl_secret(tmpdir.path()).unwrap();
+        let mut cfg = ManagerConfig::default();
+        cfg.custom_state_path = Some(tmpdir.path().to_path_buf());
+
+        assert_eq!(secret, Manager::ctl_secret_for(&cfg).unwrap());
+    }
 
     #[test]
     fn manager_state_path_default() {
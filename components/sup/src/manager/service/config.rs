@@ -29,6 +29,8 @@ use butterfly::rumor::service::SysInfo;
 use hcore::crypto;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
+use hcore::util::perm::{set_owner, set_permissions};
+use serde_json;
 use toml;
 
 use config::GossipListenAddr;
@@ -50,6 +52,8 @@ static ENV_VAR_PREFIX: &'static str = "HAB";
 /// is deeper than this value crosses into overly complex territory when describing configuration
 /// for a single service.
 static TOML_MAX_MERGE_DEPTH: u16 = 30;
+/// The permissions of rendered configuration files of a service which has secrets.
+const SECRET_CONFIG_PERMISSIONS: u32 = 0o600;
 
 /// The top level struct for all our configuration - this corresponds to the top level
 /// namespaces available in `config.toml`.
@@ -70,6 +74,10 @@ pub struct ServiceConfig {
     pub needs_write: bool,
     #[serde(skip_serializing, skip_deserializing)]
     supported_bindings: Vec<ServiceBind>,
+    /// The service's secrets, which templates see under `secret`. They are left out of
+    /// `config.toml` and everything else which is written to disk or served.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub secret: toml::value::Table,
 }
 
 fn default_for_pathbuf() -> PathBuf {
@@ -98,6 +106,7 @@ impl ServiceConfig {
                needs_write: true,
                supported_bindings: bindings,
                config_root: config_root,
+               secret: toml::value::Table::new(),
            })
     }
 
@@ -173,29 +182,9 @@ impl ServiceConfig {
             let mut last_toml = try!(File::create(fs::svc_config_file(&self.pkg.name)));
             try!(last_toml.write_all(&try!(toml::to_vec(&final_toml))));
         }
-        let mut template = Template::new();
-
-        // Register all the templates; this makes them available as partials!
-        // I suspect this will be useful, but I think we'll want to make this
-        // more explicit... in a minute, we render all the config files anyway.
-        let config_path = self.config_root.join("config");
-        let config_files = try!(Self::config_files(&config_path));
-        for config in config_files.iter() {
-            let path = config_path.join(config);
-            debug!("Config template {} from {:?}", config, &path);
-            if let Err(e) = template.register_template_file(config, &path) {
-                outputln!("Error parsing config template file {}: {}",
-                          path.to_string_lossy(),
-                          e);
-                return Err(sup_error!(Error::TemplateFileError(e)));
-            }
-        }
-
-        let final_data = convert::toml_to_json(final_toml);
+        let rendered = try!(self.render_config_files(self.template_data(final_toml)));
         let mut should_restart = false;
-        for config in config_files {
-            debug!("Rendering template {}", &config);
-            let template_data = try!(template.render(&config, &final_data));
+        for (config, template_data) in rendered {
             let template_hash = try!(crypto::hash::hash_string(&template_data));
             let cfg_dest = self.pkg
                 .svc_config_path
@@ -212,8 +201,7 @@ impl ServiceConfig {
             if file_hash.is_empty() {
                 debug!("Configuration {} does not exist; restarting", cfg_dest);
                 outputln!("Updated {} {}", Purple.bold().paint(config), template_hash);
-                let mut config_file = try!(File::create(&cfg_dest));
-                try!(config_file.write_all(&template_data.into_bytes()));
+                try!(self.write_config_file(&cfg_dest, template_data));
                 should_restart = true
             } else {
                 if file_hash == template_hash {
@@ -224,8 +212,7 @@ impl ServiceConfig {
                 } else {
                     debug!("Configuration {} has changed; restarting", cfg_dest);
                     outputln!("Updated {} {}", Purple.bold().paint(config), template_hash);
-                    let mut config_file = try!(File::create(&cfg_dest));
-                    try!(config_file.write_all(&template_data.into_bytes()));
+                    try!(self.write_config_file(&cfg_dest, template_data));
                     should_restart = true;
                 }
             }
@@ -234,6 +221,75 @@ impl ServiceConfig {
         Ok(should_restart)
     }
 
+    /// Render the templated configuration files without writing them, returning the name and
+    /// content of each file.
+    pub fn render(&self) -> Result<Vec<(String, String)>> {
+        let final_toml = try!(self.to_toml());
+        self.render_config_files(self.template_data(final_toml))
+    }
+
+    /// Replace the user configuration with the contents of the given TOML file, instead of the
+    /// service's `user.toml`.
+    pub fn load_user_config<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let mut file = try!(File::open(path.as_ref())
+            .map_err(|e| sup_error!(Error::BadDataFile(path.as_ref().to_path_buf(), e))));
+        let mut config = String::new();
+        try!(file.read_to_string(&mut config)
+            .map_err(|e| sup_error!(Error::BadDataFile(path.as_ref().to_path_buf(), e))));
+        let toml = try!(toml::de::from_str(&config)
+            .map_err(|e| sup_error!(Error::TomlParser(e))));
+        self.cfg.user = Some(toml::Value::Table(toml));
+        Ok(())
+    }
+
+    /// Write a rendered configuration file. When the service has secrets, the file could hold
+    /// some of them, so only the service's user may read it.
+    fn write_config_file(&self, cfg_dest: &str, template_data: String) -> Result<()> {
+        let mut config_file = try!(File::create(cfg_dest));
+        if !self.secret.is_empty() {
+            try!(set_owner(cfg_dest, &self.pkg.svc_user, &self.pkg.svc_group));
+            try!(set_permissions(cfg_dest, SECRET_CONFIG_PERMISSIONS));
+        }
+        try!(config_file.write_all(&template_data.into_bytes()));
+        Ok(())
+    }
+
+    /// The data configuration templates are rendered with: the configuration, and the secrets.
+    fn template_data(&self, mut toml: toml::Value) -> serde_json::Value {
+        if let toml::Value::Table(ref mut top) = toml {
+            top.insert(String::from("secret"), toml::Value::Table(self.secret.clone()));
+        }
+        convert::toml_to_json(toml)
+    }
+
+    fn render_config_files(&self, data: serde_json::Value) -> Result<Vec<(String, String)>> {
+        let mut template = Template::new();
+
+        // Register all the templates; this makes them available as partials!
+        // I suspect this will be useful, but I think we'll want to make this
+        // more explicit... in a minute, we render all the config files anyway.
+        let config_path = self.config_root.join("config");
+        let config_files = try!(Self::config_files(&config_path));
+        for config in config_files.iter() {
+            let path = config_path.join(config);
+            debug!("Config template {} from {:?}", config, &path);
+            if let Err(e) = template.register_template_file(config, &path) {
+                outputln!("Error parsing config template file {}: {}",
+                          path.to_string_lossy(),
+                          e);
+                return Err(sup_error!(Error::TemplateFileError(e)));
+            }
+        }
+
+        let mut rendered = Vec::with_capacity(config_files.len());
+        for config in config_files {
+            debug!("Rendering template {}", &config);
+            let template_data = try!(template.render(&config, &data));
+            rendered.push((config, template_data));
+        }
+        Ok(rendered)
+    }
+
     pub fn reload_package(&mut self,
                           package: &PackageInstall,
                           config_root: PathBuf,
@@ -682,6 +738,7 @@ mod test {
     use util::convert;
     use regex::Regex;
     use serde_json;
+    use tempdir::TempDir;
     use toml;
 
     use super::*;
@@ -833,6 +890,62 @@ mod test {
         assert_eq!(exported_toml["port"].as_integer(), Some(443));
     }
 
+    #[test]
+    fn render_config_files() {
+        let pkg = gen_pkg();
+        let sc = ServiceConfig::new(&pkg,
+                                    &runtime_config(),
+                                    fixtures().join("simple_service"),
+                                    Vec::new(),
+                                    &GossipListenAddr::default(),
+                                    &ListenAddr::default())
+                .unwrap();
+        let rendered = sc.render().unwrap();
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(rendered[0].0, "simple.conf");
+        assert!(rendered[0].1.starts_with("### Configuration ###"));
+    }
+
+    #[test]
+    fn render_config_files_with_secrets() {
+        let tmp = TempDir::new("secret-config").unwrap();
+        std::fs::create_dir_all(tmp.path().join("config")).unwrap();
+        File::create(tmp.path().join("config").join("db.conf"))
+            .unwrap()
+            .write_all(b"password = {{secret.db_password}}")
+            .unwrap();
+        let pkg = gen_pkg();
+        let mut sc = ServiceConfig::new(&pkg,
+                                        &runtime_config(),
+                                        tmp.path().to_path_buf(),
+                                        Vec::new(),
+                                        &GossipListenAddr::default(),
+                                        &ListenAddr::default())
+                .unwrap();
+        sc.secret = toml_from_str("db_password = \"hunter2\"");
+        let rendered = sc.render().unwrap();
+        assert_eq!(rendered[0].1, "password = hunter2");
+        assert!(sc.to_toml().unwrap().get("secret").is_none());
+    }
+
+    #[test]
+    fn load_user_config_overrides_default() {
+        let tmp = TempDir::new("user-config").unwrap();
+        let user_toml = tmp.path().join("user.toml");
+        File::create(&user_toml).unwrap().write_all(b"setting = \"false\"").unwrap();
+        let pkg = gen_pkg();
+        let mut sc = ServiceConfig::new(&pkg,
+                                        &runtime_config(),
+                                        fixtures().join("simple_service"),
+                                        Vec::new(),
+                                        &GossipListenAddr::default(),
+                                        &ListenAddr::default())
+                .unwrap();
+        sc.load_user_config(&user_toml).unwrap();
+        let cfg = sc.cfg.to_toml().unwrap();
+        assert_eq!(cfg["setting"].as_str(), Some("false"));
+    }
+
     #[test]
     fn merge_with_empty_me_table() {
         let mut me = toml_from_str("");
@@ -12,9 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::fmt;
+use std::result;
+use std::str::FromStr;
+use std::time::Duration;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+use time;
+
+use error::{Error, SupError};
+
+static LOGKEY: &'static str = "HC";
+
+/// Number of health check results kept in a service's health check history.
+pub const HEALTH_CHECK_HISTORY_LEN: usize = 10;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum HealthCheck {
     Ok,
     Warning,
@@ -40,6 +53,30 @@ impl From<i8> for HealthCheck {
     }
 }
 
+impl HealthCheck {
+    /// Returns true if the service is considered healthy enough to receive traffic.
+    pub fn is_healthy(&self) -> bool {
+        match *self {
+            HealthCheck::Ok | HealthCheck::Warning => true,
+            HealthCheck::Critical | HealthCheck::Unknown => false,
+        }
+    }
+}
+
+impl FromStr for HealthCheck {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "OK" => Ok(HealthCheck::Ok),
+            "WARNING" => Ok(HealthCheck::Warning),
+            "CRITICAL" => Ok(HealthCheck::Critical),
+            "UNKNOWN" => Ok(HealthCheck::Unknown),
+            _ => Err(sup_error!(Error::InvalidHealthCheck(String::from(value)))),
+        }
+    }
+}
+
 impl fmt::Display for HealthCheck {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
@@ -52,6 +89,93 @@ impl fmt::Display for HealthCheck {
     }
 }
 
+/// Governs how often a service's health is checked and how results are reported.
+///
+/// A service is only reported as unhealthy after `failure_threshold` consecutive failed checks,
+/// and only reported healthy again after `success_threshold` consecutive successful checks.
+/// Health check hooks which run for longer than `timeout` seconds are killed and count as a
+/// failed check. If `restart` is set, the service's process is restarted when it is reported
+/// as critical.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HealthCheckConfig {
+    pub interval: u64,
+    pub timeout: u64,
+    pub failure_threshold: u32,
+    pub success_threshold: u32,
+    pub restart: bool,
+}
+
+impl HealthCheckConfig {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+}
+
+impl Default for HealthCheckConfig {
+    fn default() -> HealthCheckConfig {
+        HealthCheckConfig {
+            interval: 30,
+            timeout: 10,
+            failure_threshold: 1,
+            success_threshold: 1,
+            restart: false,
+        }
+    }
+}
+
+/// The outcome of a single run of a service's health check.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheckResult {
+    pub status: HealthCheck,
+    pub timestamp: String,
+}
+
+/// Tracks consecutive health check results so that a service's reported health only changes
+/// once the configured thresholds are reached.
+#[derive(Debug, Default, Serialize)]
+pub struct HealthCheckState {
+    pub status: HealthCheck,
+    pub consecutive_failures: u32,
+    pub consecutive_successes: u32,
+    pub history: VecDeque<HealthCheckResult>,
+}
+
+impl HealthCheckState {
+    /// Record the result of a health check and return the service's reported health.
+    pub fn record(&mut self, result: HealthCheck, config: &HealthCheckConfig) -> HealthCheck {
+        if self.history.len() >= HEALTH_CHECK_HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(HealthCheckResult {
+                                   status: result,
+                                   timestamp: time::now_utc().rfc3339().to_string(),
+                               });
+        if result == HealthCheck::Ok {
+            self.consecutive_failures = 0;
+            self.consecutive_successes += 1;
+            if self.consecutive_successes >= config.success_threshold {
+                self.status = result;
+            }
+        } else {
+            self.consecutive_successes = 0;
+            self.consecutive_failures += 1;
+            if self.consecutive_failures >= config.failure_threshold {
+                self.status = result;
+            }
+        }
+        self.status
+    }
+
+    /// Forget about previous failures, for example once the service has been restarted.
+    pub fn reset_failures(&mut self) {
+        self.consecutive_failures = 0;
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum SmokeCheck {
     Ok,
@@ -75,3 +199,63 @@ impl fmt::Display for SmokeCheck {
         write!(f, "{}", msg)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn config(failure_threshold: u32, success_threshold: u32) -> HealthCheckConfig {
+        HealthCheckConfig {
+            failure_threshold: failure_threshold,
+            success_threshold: success_threshold,
+            ..HealthCheckConfig::default()
+        }
+    }
+
+    #[test]
+    fn health_check_state_reports_critical_after_failure_threshold() {
+        let cfg = config(3, 1);
+        let mut state = HealthCheckState::default();
+
+        assert_eq!(HealthCheck::Ok, state.record(HealthCheck::Ok, &cfg));
+        assert_eq!(HealthCheck::Ok, state.record(HealthCheck::Critical, &cfg));
+        assert_eq!(HealthCheck::Ok, state.record(HealthCheck::Critical, &cfg));
+        assert_eq!(HealthCheck::Critical,
+                   state.record(HealthCheck::Critical, &cfg));
+    }
+
+    #[test]
+    fn health_check_state_reports_ok_after_success_threshold() {
+        let cfg = config(1, 2);
+        let mut state = HealthCheckState::default();
+
+        assert_eq!(HealthCheck::Critical,
+                   state.record(HealthCheck::Critical, &cfg));
+        assert_eq!(HealthCheck::Critical, state.record(HealthCheck::Ok, &cfg));
+        assert_eq!(HealthCheck::Ok, state.record(HealthCheck::Ok, &cfg));
+    }
+
+    #[test]
+    fn health_check_state_failures_reset_on_success() {
+        let cfg = config(2, 1);
+        let mut state = HealthCheckState::default();
+
+        state.record(HealthCheck::Ok, &cfg);
+        state.record(HealthCheck::Critical, &cfg);
+        state.record(HealthCheck::Ok, &cfg);
+
+        assert_eq!(HealthCheck::Ok, state.record(HealthCheck::Critical, &cfg));
+    }
+
+    #[test]
+    fn health_check_state_history_is_bounded() {
+        let cfg = HealthCheckConfig::default();
+        let mut state = HealthCheckState::default();
+
+        for _ in 0..(HEALTH_CHECK_HISTORY_LEN + 5) {
+            state.record(HealthCheck::Ok, &cfg);
+        }
+
+        assert_eq!(HEALTH_CHECK_HISTORY_LEN, state.history.len());
+    }
+}
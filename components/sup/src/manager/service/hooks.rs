@@ -14,12 +14,15 @@
 
 use std;
 use std::fmt;
-use std::io::BufReader;
+use std::io::{self, BufReader};
 use std::io::prelude::*;
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::process::{Child, ExitStatus};
+use std::process::{Child, Command, ExitStatus};
 use std::result;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use ansi_term::Colour;
 use hcore;
@@ -30,6 +33,7 @@ use super::health;
 use error::Result;
 use fs;
 use manager::service::ServiceConfig;
+use manager::signals::{self, Signal};
 use supervisor::RuntimeConfig;
 use templating::Template;
 use util;
@@ -91,11 +95,16 @@ pub trait Hook: fmt::Debug + Sized {
 
     fn new(service_group: &ServiceGroup, render_pair: RenderPair) -> Self;
 
-    /// Compile a hook into it's destination service directory.
-    fn compile(&self, cfg: &ServiceConfig) -> Result<()> {
+    /// Render a hook's template with the given configuration.
+    fn render(&self, cfg: &ServiceConfig) -> Result<String> {
         let toml = try!(cfg.to_toml());
         let svc_data = util::convert::toml_to_json(toml);
-        let data = try!(self.template().render("hook", &svc_data));
+        Ok(try!(self.template().render("hook", &svc_data)))
+    }
+
+    /// Compile a hook into it's destination service directory.
+    fn compile(&self, cfg: &ServiceConfig) -> Result<()> {
+        let data = try!(self.render(cfg));
         let mut file = try!(File::create(self.path()));
         try!(file.write_all(data.as_bytes()));
         try!(hcore::util::perm::set_owner(self.path(), &cfg.pkg.svc_user, &cfg.pkg.svc_group));
@@ -108,6 +117,15 @@ pub trait Hook: fmt::Debug + Sized {
 
     /// Run a compiled hook.
     fn run(&self, service_group: &ServiceGroup, cfg: &RuntimeConfig) -> Self::ExitValue {
+        self.run_with_timeout(service_group, cfg, None)
+    }
+
+    /// Run a compiled hook, killing it if it is still running after the given timeout.
+    fn run_with_timeout(&self,
+                        service_group: &ServiceGroup,
+                        cfg: &RuntimeConfig,
+                        timeout: Option<Duration>)
+                        -> Self::ExitValue {
         let mut cmd = match util::create_command(self.path(), cfg) {
             Ok(c) => c,
             Err(err) => {
@@ -116,6 +134,9 @@ pub trait Hook: fmt::Debug + Sized {
                 return Self::ExitValue::default();
             }
         };
+        if timeout.is_some() {
+            own_process_group(&mut cmd);
+        }
         let mut child = match cmd.spawn() {
             Ok(child) => child,
             Err(err) => {
@@ -124,6 +145,8 @@ pub trait Hook: fmt::Debug + Sized {
                 return Self::ExitValue::default();
             }
         };
+        let _watchdog =
+            timeout.map(|t| HookWatchdog::start(service_group, Self::file_name(), child.id(), t));
         let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
         hook_output.stream_output::<Self>(service_group, &mut child);
         match child.wait() {
@@ -319,6 +342,216 @@ impl Hook for InitHook {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PostRunHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PostRunHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "post-run"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PostRunHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(&self,
+                       service_group: &ServiceGroup,
+                       _: &'a HookOutput,
+                       status: &ExitStatus)
+                       -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Post run failed! '{}' exited with \
+                    status code {}, will retry", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Post run failed! '{}' exited without a \
+                    status code, will retry", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn template(&self) -> &Template {
+        &self.render_pair.template
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+impl PostRunHook {
+    /// Run the hook on a thread of its own, so that a slow hook doesn't hold up the main loop.
+    /// Whether it succeeded is sent on the returned channel once it exits.
+    pub fn run_in_background(&self,
+                             service_group: &ServiceGroup,
+                             cfg: &RuntimeConfig)
+                             -> Result<mpsc::Receiver<bool>> {
+        let (tx, rx) = mpsc::channel();
+        let service_group = service_group.clone();
+        let cfg = cfg.clone();
+        let path = self.path().to_path_buf();
+        try!(thread::Builder::new()
+                 .name(format!("post-run-{}", service_group.service()))
+                 .spawn(move || {
+            // Running the hook only takes its compiled file, not its template.
+            let pair = RenderPair {
+                path: path,
+                template: Template::new(),
+            };
+            let hook = PostRunHook::new(&service_group, pair);
+            let _ = tx.send(hook.run(&service_group, &cfg));
+        }));
+        Ok(rx)
+    }
+}
+
+/// Tracks the post-run hook of a service's current process: whether it still has to succeed,
+/// when it last ran, and the run in progress, if any.
+#[derive(Debug, Default)]
+pub struct PostRunState {
+    pending: bool,
+    last_run: Option<Instant>,
+    running: Option<mpsc::Receiver<bool>>,
+}
+
+impl PostRunState {
+    /// The process has just started, so the hook is due right away. A run still going for a
+    /// previous process is forgotten, so that its result doesn't count for this one.
+    pub fn reset(&mut self) {
+        self.pending = true;
+        self.last_run = None;
+        self.running = None;
+    }
+
+    /// Whether the hook should run now: it hasn't succeeded for the current process, isn't
+    /// running, and has never run or last failed at least `retry_interval` ago.
+    pub fn is_due(&self, retry_interval: Duration) -> bool {
+        if !self.pending || self.running.is_some() {
+            return false;
+        }
+        match self.last_run {
+            Some(last) => Instant::now().duration_since(last) >= retry_interval,
+            None => true,
+        }
+    }
+
+    /// Record a run of the hook which reports whether it succeeded on `result`.
+    pub fn started(&mut self, result: mpsc::Receiver<bool>) {
+        self.last_run = Some(Instant::now());
+        self.running = Some(result);
+    }
+
+    /// Record a run of the hook which couldn't be started; it is retried later.
+    pub fn failed(&mut self) {
+        self.last_run = Some(Instant::now());
+    }
+
+    /// Nothing is left to run, for example because the service has no post-run hook.
+    pub fn done(&mut self) {
+        self.pending = false;
+    }
+
+    /// Collect the result of the run in progress, if it has finished. A run which ended without
+    /// reporting counts as a failure.
+    pub fn check(&mut self) {
+        let succeeded = match self.running {
+            Some(ref result) => {
+                match result.try_recv() {
+                    Ok(succeeded) => succeeded,
+                    Err(mpsc::TryRecvError::Empty) => return,
+                    Err(mpsc::TryRecvError::Disconnected) => false,
+                }
+            }
+            None => return,
+        };
+        self.running = None;
+        if succeeded {
+            self.pending = false;
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PostStopHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "post-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PostStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(&self,
+                       service_group: &ServiceGroup,
+                       _: &'a HookOutput,
+                       status: &ExitStatus)
+                       -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Post stop failed! '{}' exited with \
+                    status code {}", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Post stop failed! '{}' exited without a \
+                    status code", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn template(&self) -> &Template {
+        &self.render_pair.template
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct RunHook {
     render_pair: RenderPair,
@@ -636,6 +869,8 @@ pub struct HookTable {
     pub health_check: Option<HealthCheckHook>,
     pub init: Option<InitHook>,
     pub file_updated: Option<FileUpdatedHook>,
+    pub post_run: Option<PostRunHook>,
+    pub post_stop: Option<PostStopHook>,
     pub reload: Option<ReloadHook>,
     pub reconfigure: Option<ReconfigureHook>,
     pub suitability: Option<SuitabilityHook>,
@@ -663,6 +898,12 @@ impl HookTable {
         if let Some(ref hook) = self.init {
             self.compile_one(hook, service_group, config);
         }
+        if let Some(ref hook) = self.post_run {
+            self.compile_one(hook, service_group, config);
+        }
+        if let Some(ref hook) = self.post_stop {
+            self.compile_one(hook, service_group, config);
+        }
         if let Some(ref hook) = self.reload {
             self.compile_one(hook, service_group, config);
         }
@@ -692,6 +933,8 @@ impl HookTable {
                 self.health_check = HealthCheckHook::load(service_group, &hooks, &templates);
                 self.suitability = SuitabilityHook::load(service_group, &hooks, &templates);
                 self.init = InitHook::load(service_group, &hooks, &templates);
+                self.post_run = PostRunHook::load(service_group, &hooks, &templates);
+                self.post_stop = PostStopHook::load(service_group, &hooks, &templates);
                 self.reload = ReloadHook::load(service_group, &hooks, &templates);
                 self.reconfigure = ReconfigureHook::load(service_group, &hooks, &templates);
                 self.run = RunHook::load(service_group, &hooks, &templates);
@@ -705,6 +948,43 @@ impl HookTable {
         self
     }
 
+    /// Render all loaded hooks from the table without writing them, returning the file name and
+    /// content of each hook.
+    pub fn render(&self, config: &ServiceConfig) -> Result<Vec<(&'static str, String)>> {
+        let mut rendered = Vec::new();
+        if let Some(ref hook) = self.file_updated {
+            rendered.push((FileUpdatedHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.health_check {
+            rendered.push((HealthCheckHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.init {
+            rendered.push((InitHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.post_run {
+            rendered.push((PostRunHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.post_stop {
+            rendered.push((PostStopHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.reload {
+            rendered.push((ReloadHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.reconfigure {
+            rendered.push((ReconfigureHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.suitability {
+            rendered.push((SuitabilityHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.run {
+            rendered.push((RunHook::file_name(), try!(hook.render(config))));
+        }
+        if let Some(ref hook) = self.smoke_test {
+            rendered.push((SmokeTestHook::file_name(), try!(hook.render(config))));
+        }
+        Ok(rendered)
+    }
+
     fn compile_one<H>(&self, hook: &H, service_group: &ServiceGroup, config: &ServiceConfig)
         where H: Hook
     {
@@ -751,6 +1031,56 @@ impl Serialize for RenderPair {
     }
 }
 
+/// Start a hook's process in a process group of its own, so that its watchdog can kill every
+/// process the hook started along with it.
+#[cfg(unix)]
+fn own_process_group(cmd: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    use libc;
+
+    cmd.before_exec(|| if unsafe { libc::setpgid(0, 0) } == -1 {
+                        Err(io::Error::last_os_error())
+                    } else {
+                        Ok(())
+                    });
+}
+
+#[cfg(windows)]
+fn own_process_group(_: &mut Command) {}
+
+/// Kills a hook's process group if the hook hasn't exited before the timeout elapses, so that
+/// nothing it started is left holding its output open. The watchdog is cancelled when dropped.
+struct HookWatchdog {
+    _cancel: mpsc::Sender<()>,
+}
+
+impl HookWatchdog {
+    fn start(service_group: &ServiceGroup,
+             hook_name: &'static str,
+             pid: u32,
+             timeout: Duration)
+             -> Self {
+        let (tx, rx) = mpsc::channel::<()>();
+        let preamble = service_group.to_string();
+        let spawned = thread::Builder::new()
+            .name(format!("hook-watchdog-{}", pid))
+            .spawn(move || {
+                if let Err(mpsc::RecvTimeoutError::Timeout) = rx.recv_timeout(timeout) {
+                    outputln!(preamble preamble,
+                              "{} timed out after {}s, killing it", hook_name, timeout.as_secs());
+                    if let Err(err) = signals::send_group_signal(pid, Signal::SIGKILL as u32) {
+                        outputln!(preamble preamble, "Failed to kill {}: {}", hook_name, err);
+                    }
+                }
+            });
+        if let Err(err) = spawned {
+            outputln!(preamble service_group,
+                      "Failed to start watchdog for {}: {}", hook_name, err);
+        }
+        HookWatchdog { _cancel: tx }
+    }
+}
+
 pub struct HookOutput<'a> {
     stdout_log_file: &'a Path,
     stderr_log_file: &'a Path,
@@ -779,6 +1109,8 @@ impl<'a> HookOutput<'a> {
         }
     }
 
+    /// Log a hook's output as it comes. Standard error is read on a thread of its own, so that
+    /// neither stream fills up while we wait on the other.
     fn stream_output<H: Hook>(&mut self, service_group: &ServiceGroup, process: &mut Child) {
         let mut stdout_log =
             File::create(&self.stdout_log_file).expect("couldn't create log output file");
@@ -786,6 +1118,25 @@ impl<'a> HookOutput<'a> {
             File::create(&self.stderr_log_file).expect("couldn't create log output file");
 
         let preamble_str = self.stream_preamble::<H>(service_group);
+        let stderr_reader = process.stderr.take().and_then(|stderr| {
+            let preamble_str = preamble_str.clone();
+            let spawned = thread::Builder::new()
+                .name(format!("hook-stderr-{}", H::file_name()))
+                .spawn(move || for line in BufReader::new(stderr).lines() {
+                           if let Some(ref l) = line.ok() {
+                               outputln!(preamble preamble_str, l);
+                               stderr_log.write_fmt(format_args!("{}\n", l))
+                                   .expect("couldn't write line");
+                           }
+                       });
+            match spawned {
+                Ok(handle) => Some(handle),
+                Err(err) => {
+                    outputln!(preamble preamble_str, "Failed to read stderr: {}", err);
+                    None
+                }
+            }
+        });
         if let Some(ref mut stdout) = process.stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
@@ -794,13 +1145,8 @@ impl<'a> HookOutput<'a> {
                 }
             }
         }
-        if let Some(ref mut stderr) = process.stderr {
-            for line in BufReader::new(stderr).lines() {
-                if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
-                    stderr_log.write_fmt(format_args!("{}\n", l)).expect("couldn't write line");
-                }
-            }
+        if let Some(handle) = stderr_reader {
+            let _ = handle.join();
         }
     }
 
@@ -814,6 +1160,8 @@ impl<'a> HookOutput<'a> {
 mod tests {
     use super::*;
     use std::fs::{self, DirBuilder};
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::Instant;
     use tempdir::TempDir;
     use std::process::{Command, Stdio};
 
@@ -856,4 +1204,98 @@ mod tests {
 
         fs::remove_dir_all(tmp_dir).expect("remove temp dir");
     }
+
+    #[test]
+    fn hook_killed_after_timeout() {
+        let tmp_dir = TempDir::new("habitat_hooks_test").expect("create temp dir");
+        let hook = tmp_dir.path().join("sleepy");
+        // The background sleep holds the hook's stdout open after the hook itself is killed.
+        File::create(&hook)
+            .and_then(|mut f| f.write_all(b"#!/bin/sh\nsleep 60 &\nsleep 60\n"))
+            .expect("couldn't write hook");
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755))
+            .expect("couldn't make hook executable");
+        let mut cmd = Command::new(&hook);
+        cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped());
+        own_process_group(&mut cmd);
+        let mut child = cmd.spawn().expect("couldn't run hook");
+        let service_group =
+            ServiceGroup::new("dummy", "service", None).expect("couldn't create ServiceGroup");
+        let stdout_log = tmp_dir.path().join("sleepy.stdout.log");
+        let stderr_log = tmp_dir.path().join("sleepy.stderr.log");
+        let mut hook_output = HookOutput::new(&stdout_log, &stderr_log);
+        let started = Instant::now();
+
+        {
+            let _watchdog = HookWatchdog::start(&service_group,
+                                                InitHook::file_name(),
+                                                child.id(),
+                                                Duration::from_millis(500));
+            hook_output.stream_output::<InitHook>(&service_group, &mut child);
+        }
+        assert!(!child.wait().expect("couldn't wait for hook").success());
+        assert!(started.elapsed() < Duration::from_secs(30));
+    }
+    #[test]
+    fn post_run_state_is_due_right_away_after_a_start() {
+        let mut state = PostRunState::default();
+        assert!(!state.is_due(Duration::from_secs(0)));
+
+        state.reset();
+        assert!(state.is_due(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn post_run_state_retries_a_failed_hook_after_the_interval() {
+        let mut state = PostRunState::default();
+        state.reset();
+        let (tx, rx) = mpsc::channel();
+        state.started(rx);
+        assert!(!state.is_due(Duration::from_secs(0)));
+
+        state.check();
+        assert!(!state.is_due(Duration::from_secs(0)));
+
+        tx.send(false).unwrap();
+        state.check();
+        assert!(!state.is_due(Duration::from_secs(3600)));
+        assert!(state.is_due(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn post_run_state_is_done_once_the_hook_succeeds() {
+        let mut state = PostRunState::default();
+        state.reset();
+        let (tx, rx) = mpsc::channel();
+        state.started(rx);
+        tx.send(true).unwrap();
+        state.check();
+
+        assert!(!state.is_due(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn post_run_state_ignores_a_run_for_a_previous_process() {
+        let mut state = PostRunState::default();
+        state.reset();
+        let (tx, rx) = mpsc::channel();
+        state.started(rx);
+        state.reset();
+        let _ = tx.send(true);
+        state.check();
+
+        assert!(state.is_due(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn post_run_state_counts_a_run_which_never_reports_as_failed() {
+        let mut state = PostRunState::default();
+        state.reset();
+        let (tx, rx) = mpsc::channel::<bool>();
+        state.started(rx);
+        drop(tx);
+        state.check();
+
+        assert!(state.is_due(Duration::from_secs(0)));
+    }
 }
@@ -15,9 +15,12 @@
 pub mod config;
 pub mod health;
 pub mod hooks;
+pub mod rollback;
+pub mod secrets;
 pub mod spec;
 
 use std;
+use std::cmp;
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
@@ -41,9 +44,12 @@ use hcore::package::{PackageIdent, PackageInstall};
 use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
 use serde;
+use serde_json;
 use toml;
 
-use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
+use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable, PostRunState};
+use self::rollback::VerificationStatus;
+use self::secrets::Secrets;
 use config::GossipListenAddr;
 use error::{Error, Result, SupError};
 use http_gateway;
@@ -51,11 +57,14 @@ use fs;
 use manager::{self, signals};
 use manager::census::{CensusList, CensusUpdate, ElectionStatus};
 use prometheus::Opts;
-use supervisor::{Supervisor, RuntimeConfig};
+use supervisor::{LogConfig, ProcessOutput, Supervisor, RuntimeConfig};
 use util;
+use util::users::UserConfig;
 
 pub use self::config::ServiceConfig;
-pub use self::health::{HealthCheck, SmokeCheck};
+pub use self::health::{HealthCheck, HealthCheckConfig, HealthCheckState, SmokeCheck};
+pub use self::rollback::{Rollback, RollbackConfig, UpdateVerification};
+pub use self::secrets::SecretDelivery;
 pub use self::spec::{ServiceBind, ServiceSpec};
 
 static LOGKEY: &'static str = "SR";
@@ -63,34 +72,57 @@ const HABITAT_PACKAGE_INFO_NAME: &'static str = "habitat_package_info";
 const HABITAT_PACKAGE_INFO_DESC: &'static str = "package version information";
 
 lazy_static! {
-    static ref HEALTH_CHECK_INTERVAL: Duration = {
-        Duration::from_millis(30_000)
+    static ref POST_RUN_RETRY_INTERVAL: Duration = {
+        Duration::from_millis(5_000)
     };
 }
 
 #[derive(Debug, Serialize)]
 pub struct Service {
+    pub canary_health_checks: u32,
+    pub channel: Option<String>,
     pub config: ServiceConfig,
     pub current_service_files: HashMap<String, u64>,
     pub depot_url: String,
+    pub desired_state: DesiredState,
+    pub failed_updates: Vec<PackageIdent>,
     pub health_check: HealthCheck,
+    pub health_check_config: HealthCheckConfig,
+    #[serde(skip_serializing)]
+    health_check_state: HealthCheckState,
+    #[serde(skip_serializing)]
+    gossiped_health_check: HealthCheck,
+    #[serde(skip_serializing)]
+    healthy_checks_since_update: u32,
     pub initialized: bool,
     pub last_election_status: ElectionStatus,
+    pub last_rollback: Option<Rollback>,
     pub needs_reload: bool,
     pub needs_reconfiguration: bool,
     #[serde(serialize_with="serialize_lock")]
     pub package: Arc<RwLock<PackageInstall>>,
+    pub rollback_config: RollbackConfig,
+    pub secret_delivery: SecretDelivery,
+    #[serde(skip_serializing)]
+    secrets: Secrets,
     pub service_group: ServiceGroup,
     pub smoke_check: SmokeCheck,
     #[serde(skip_serializing)]
     spec_binds: Vec<ServiceBind>,
+    #[serde(skip_serializing)]
+    user_config: UserConfig,
     pub spec_ident: PackageIdent,
     pub topology: Topology,
+    pub update_batch: UpdateBatch,
     pub update_strategy: UpdateStrategy,
+    pub update_verification: Option<UpdateVerification>,
     hooks: HookTable,
     config_from: Option<PathBuf>,
+    /// When the health check last ran; `None` until the first one, which is due right away.
     #[serde(skip_serializing)]
-    last_health_check: Instant,
+    last_health_check: Option<Instant>,
+    #[serde(skip_serializing)]
+    post_run: PostRunState,
     #[serde(skip_serializing)]
     manager_fs_cfg: Arc<manager::FsCfg>,
     supervisor: Supervisor,
@@ -102,11 +134,17 @@ impl Service {
                gossip_listen: &GossipListenAddr,
                http_listen: &http_gateway::ListenAddr,
                manager_fs_cfg: Arc<manager::FsCfg>,
-               organization: Option<&str>)
+               organization: Option<&str>,
+               log_config: Option<LogConfig>,
+               cgroup_root: &Path)
                -> Result<Service> {
         spec.validate(&package)?;
         let service_group = ServiceGroup::new(&package.ident.name, spec.group, organization)?;
-        let runtime_cfg = Self::runtime_config_from(&package)?;
+        let user_config = spec.user_config();
+        let mut runtime_cfg = Self::runtime_config_from(&package, &user_config)?;
+        let secrets = Secrets::default();
+        runtime_cfg.secret_env_vars = secrets.env_vars(spec.secret_delivery,
+                                                       service_group.service());
         let config_root = spec.config_from.clone().unwrap_or(package.installed_path.clone());
         let svc_cfg = ServiceConfig::new(&package,
                                          &runtime_cfg,
@@ -117,34 +155,63 @@ impl Service {
         let hook_template_path = svc_cfg.config_root.join("hooks");
         let hooks_path = fs::svc_hooks_path(service_group.service());
         let locked_package = Arc::new(RwLock::new(package));
+        // Take what we need from the spec by reference before its fields are moved below.
+        let health_check_config = spec.health_check_config();
+        let supervisor = Supervisor::new(locked_package.clone(),
+                                         &service_group,
+                                         runtime_cfg,
+                                         spec.restart_config(),
+                                         spec.shutdown_config(),
+                                         log_config,
+                                         spec.limits.clone(),
+                                         cgroup_root);
+        let rollback_config = spec.rollback_config();
         Ok(Service {
+               canary_health_checks: spec.canary_health_checks,
                config: svc_cfg,
                current_service_files: HashMap::new(),
+               channel: spec.channel,
                depot_url: spec.depot_url,
+               desired_state: spec.desired_state,
+               failed_updates: Vec::new(),
                health_check: HealthCheck::default(),
+               health_check_config: health_check_config,
+               health_check_state: HealthCheckState::default(),
+               gossiped_health_check: HealthCheck::default(),
+               healthy_checks_since_update: 0,
                hooks: HookTable::default().load_hooks(&service_group,
                                                       &hooks_path,
                                                       &hook_template_path),
                initialized: false,
                last_election_status: ElectionStatus::None,
+               last_health_check: None,
+               last_rollback: None,
                needs_reload: false,
                needs_reconfiguration: false,
                manager_fs_cfg: manager_fs_cfg,
-               supervisor: Supervisor::new(locked_package.clone(), &service_group, runtime_cfg),
+               supervisor: supervisor,
                package: locked_package,
+               rollback_config: rollback_config,
+               secret_delivery: spec.secret_delivery,
+               secrets: secrets,
                service_group: service_group,
                smoke_check: SmokeCheck::default(),
                spec_binds: spec.binds,
                spec_ident: spec.ident,
+               user_config: user_config,
                topology: spec.topology,
+               update_batch: spec.update_batch,
                update_strategy: spec.update_strategy,
+               update_verification: None,
                config_from: spec.config_from,
-               last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
+               post_run: PostRunState::default(),
            })
     }
 
-    fn runtime_config_from(package: &PackageInstall) -> Result<RuntimeConfig> {
-        let (svc_user, svc_group) = util::users::get_user_and_group(&package)?;
+    pub fn runtime_config_from(package: &PackageInstall,
+                               user_config: &UserConfig)
+                               -> Result<RuntimeConfig> {
+        let (svc_user, svc_group) = util::users::get_user_and_group(&package, user_config)?;
         let mut env = match package.runtime_environment() {
             Ok(r) => r,
             Err(e) => return Err(sup_error!(Error::HabitatCore(e))),
@@ -153,14 +220,19 @@ impl Service {
         // FIXME: Devise a way to make OS independent so we don't have to muck with env.
         Self::run_path(&mut env)?;
 
-        Ok(RuntimeConfig::new(svc_user, svc_group, env))
+        let mut runtime_cfg = RuntimeConfig::new(svc_user, svc_group, env);
+        runtime_cfg.supplementary_groups = user_config.supplementary_groups.clone();
+        runtime_cfg.capabilities = user_config.capabilities.clone();
+        Ok(runtime_cfg)
     }
 
     pub fn load(spec: ServiceSpec,
                 gossip_listen: &GossipListenAddr,
                 http_listen: &http_gateway::ListenAddr,
                 manager_fs_cfg: Arc<manager::FsCfg>,
-                organization: Option<&str>)
+                organization: Option<&str>,
+                log_config: Option<LogConfig>,
+                cgroup_root: &Path)
                 -> Result<Service> {
         let mut ui = UI::default();
         let package = match PackageInstall::load(&spec.ident, Some(&Path::new(&*FS_ROOT_PATH))) {
@@ -169,14 +241,17 @@ impl Service {
                     UpdateStrategy::AtOnce => {
                         try!(util::pkg::maybe_install_newer(&mut ui, &spec, package))
                     }
-                    UpdateStrategy::None | UpdateStrategy::Rolling => package,
+                    UpdateStrategy::None |
+                    UpdateStrategy::Rolling |
+                    UpdateStrategy::Batch |
+                    UpdateStrategy::Canary => package,
                 }
             }
             Err(_) => {
                 outputln!("Package {} not found locally, installing from {}",
                           Yellow.bold().paint(spec.ident.to_string()),
                           &spec.depot_url);
-                try!(util::pkg::install(&mut ui, &spec.depot_url, &spec.ident))
+                try!(util::pkg::install_from_spec(&mut ui, &spec))
             }
         };
         Self::new(package,
@@ -184,7 +259,9 @@ impl Service {
                   gossip_listen,
                   http_listen,
                   manager_fs_cfg,
-                  organization)
+                  organization,
+                  log_config,
+                  cgroup_root)
     }
 
     pub fn add(&self) -> Result<()> {
@@ -196,6 +273,7 @@ impl Service {
     }
 
     pub fn remove(&self) -> Result<()> {
+        self.wipe_secrets();
         outputln!("Finished with {}",
                   Yellow.bold().paint(self.package().ident().to_string()));
         Ok(())
@@ -207,7 +285,8 @@ impl Service {
 
     /// Create the service path for this package.
     pub fn create_svc_path(&self) -> Result<()> {
-        let (user, group) = try!(util::users::get_user_and_group(&self.package()));
+        let (user, group) = try!(util::users::get_user_and_group(&self.package(),
+                                                                 &self.user_config));
 
         debug!("Creating svc paths");
 
@@ -246,19 +325,29 @@ impl Service {
     }
 
     pub fn start(&mut self) {
+        self.write_secrets();
         if let Some(err) = self.supervisor.start().err() {
             outputln!(preamble self.service_group, "Service start failed: {}", err);
+            self.supervisor.schedule_restart(None);
         } else {
             self.needs_reload = false;
             self.needs_reconfiguration = false;
+            self.post_run.reset();
         }
     }
 
     pub fn reload(&mut self) {
         self.needs_reload = false;
-        if self.is_down() || self.hooks.reload.is_none() {
-            if let Some(err) = self.supervisor.restart().err() {
-                outputln!(preamble self.service_group, "Service restart failed: {}", err);
+        if self.is_down() && !self.supervisor.is_restart_due() {
+            // The process failed, was left down by its restart policy or is backing off. It picks
+            // up the new configuration whenever it is started again.
+            self.needs_reconfiguration = false;
+        } else if self.is_down() || self.hooks.reload.is_none() {
+            match self.supervisor.restart() {
+                Ok(()) => self.post_run.reset(),
+                Err(err) => {
+                    outputln!(preamble self.service_group, "Service restart failed: {}", err)
+                }
             }
         } else {
             let hook = self.hooks
@@ -269,8 +358,77 @@ impl Service {
         }
     }
 
+    /// Take the service's process down, waiting for it to exit and running the post-stop hook.
     pub fn down(&mut self) -> Result<()> {
-        self.supervisor.down()
+        let result = self.supervisor.down();
+        self.check_stopped();
+        self.wipe_secrets();
+        result
+    }
+
+    /// Start the post-run hook, if present, once the service's process has started. It runs in
+    /// the background, and a failed hook is retried on a later tick until it succeeds or the
+    /// process is started again.
+    fn post_run(&mut self) {
+        let started = match self.hooks.post_run {
+            Some(ref hook) => hook.run_in_background(&self.service_group, self.runtime_cfg()),
+            None => {
+                self.post_run.done();
+                return;
+            }
+        };
+        match started {
+            Ok(result) => self.post_run.started(result),
+            Err(err) => {
+                outputln!(preamble self.service_group, "Failed to start post-run hook: {}", err);
+                self.post_run.failed();
+            }
+        }
+    }
+
+    /// Run the post-stop hook if the process has exited since we last looked, whether it
+    /// crashed, was restarted or was taken down. Secrets are wiped once it is down for good.
+    fn check_stopped(&mut self) {
+        if !self.supervisor.take_exited() {
+            return;
+        }
+        if let Some(ref hook) = self.hooks.post_stop {
+            hook.run(&self.service_group, self.runtime_cfg());
+        }
+        if self.supervisor.is_down() {
+            self.wipe_secrets();
+        }
+    }
+
+    /// Bring the service's process up or down to match the given desired state. A service which
+    /// is desired down stays loaded, keeping its configuration and census identity, but will not
+    /// be started or restarted until it is desired up again.
+    pub fn set_desired_state(&mut self, desired_state: DesiredState) {
+        if self.desired_state != desired_state {
+            outputln!(preamble self.service_group,
+                      "Desired state changed to {}",
+                      Yellow.bold().paint(desired_state.to_string()));
+            self.desired_state = desired_state;
+        }
+        match desired_state {
+            DesiredState::Up => {
+                // Explicitly asking for a service to be up gives a failed service another chance
+                self.supervisor.reset_restarts();
+                if self.initialized && self.is_down() {
+                    self.start();
+                } else if self.supervisor.is_down() && !self.is_down() {
+                    // Still stopping; start it again once it has exited
+                    if let Err(err) = self.supervisor.restart() {
+                        outputln!(preamble self.service_group, "Service restart failed: {}", err);
+                    }
+                }
+            }
+            DesiredState::Down => {
+                if let Some(err) = self.supervisor.begin_down().err() {
+                    outputln!(preamble self.service_group, "Service stop failed: {}", err);
+                }
+            }
+        }
     }
 
     pub fn send_signal(&self, signal: u32) -> Result<()> {
@@ -289,7 +447,8 @@ impl Service {
 
     /// Instructs the service's process supervisor to reap dead children.
     pub fn check_process(&mut self) {
-        self.supervisor.check_process()
+        self.supervisor.check_process();
+        self.check_stopped();
     }
 
     pub fn register_metrics(&self) {
@@ -379,14 +538,28 @@ impl Service {
                 }
             }
         }
+        if self.health_check != self.gossiped_health_check {
+            self.update_service_rumor_health(butterfly, last_census_update);
+        }
     }
 
     pub fn to_spec(&self) -> ServiceSpec {
         let mut spec = ServiceSpec::default_for(self.spec_ident.clone());
         spec.group = self.service_group.group().to_string();
         spec.depot_url = self.depot_url.clone();
+        spec.channel = self.channel.clone();
+        spec.desired_state = self.desired_state;
+        spec.set_restart_config(&self.supervisor.restart_config);
+        spec.set_shutdown_config(&self.supervisor.shutdown_config);
+        spec.set_health_check_config(&self.health_check_config);
+        spec.set_rollback_config(&self.rollback_config);
+        spec.set_user_config(&self.user_config);
+        spec.secret_delivery = self.secret_delivery;
+        spec.limits = self.supervisor.limits.clone();
         spec.topology = self.topology;
         spec.update_strategy = self.update_strategy;
+        spec.update_batch = self.update_batch;
+        spec.canary_health_checks = self.canary_health_checks;
         spec.binds = self.spec_binds.clone();
         spec.config_from = self.config_from.clone();
         spec
@@ -398,10 +571,10 @@ impl Service {
                             census_updated: bool,
                             last_census_update: &mut CensusUpdate) {
         self.config.populate(&self.service_group, census_list);
-        self.persist_service_files(butterfly);
+        let secrets_updated = self.persist_service_files(butterfly);
 
         let svc_cfg_updated = self.persist_service_config(butterfly);
-        if svc_cfg_updated || census_updated {
+        if svc_cfg_updated || census_updated || secrets_updated {
             if svc_cfg_updated {
                 self.update_service_rumor_cfg(butterfly, last_census_update);
                 if let Some(err) = self.config.reload_gossip().err() {
@@ -431,15 +604,51 @@ impl Service {
     }
 
     pub fn update_package(&mut self, package: PackageInstall) {
-        let runtime_cfg = match Self::runtime_config_from(&package) {
+        let previous = self.package().ident().clone();
+        let updated = package.ident().clone();
+        if !self.swap_package(package) {
+            return;
+        }
+        self.healthy_checks_since_update = 0;
+        if self.rollback_config.enabled && previous != updated {
+            outputln!(preamble self.service_group,
+                      "Verifying {} before keeping it, rolling back to {} otherwise",
+                      updated,
+                      previous);
+            self.update_verification = Some(UpdateVerification::new(previous, updated));
+            self.smoke_check = SmokeCheck::Pending;
+        }
+    }
+
+    /// Returns the number of consecutive passing health checks since the running package was
+    /// last updated.
+    pub fn healthy_checks_since_update(&self) -> u32 {
+        self.healthy_checks_since_update
+    }
+
+    /// Returns true if the given package is known to have failed verification after an update,
+    /// in which case it shouldn't be updated to again.
+    pub fn is_failed_update(&self, ident: &PackageIdent) -> bool {
+        self.failed_updates.contains(ident)
+    }
+
+    /// Replace the running package with the given one, returning false if it couldn't be used.
+    fn swap_package(&mut self, package: PackageInstall) -> bool {
+        let runtime_cfg = match Self::runtime_config_from(&package, &self.user_config) {
             Ok(c) => c,
             Err(err) => {
                 outputln!(preamble self.service_group,
                           "Unable to extract svc_user, svc_group, and env_vars \
                           from updated package, {}", err);
-                return;
+                return false;
             }
         };
+        // Stop the old process first, so the post-stop hook of the package which ran it is used
+        if let Err(err) = self.down() {
+            outputln!(preamble self.service_group,
+                      "Error stopping process while updating package: {}", err);
+        }
+
         let config_root = self.config_from.clone().unwrap_or(package.installed_path.clone());
         let hooks_path = fs::svc_hooks_path(self.service_group.service());
         self.hooks = HookTable::default().load_hooks(&self.service_group,
@@ -451,12 +660,66 @@ impl Service {
                 "Failed to reload service config with updated package: {}", err);
         }
         *self.package.write().expect("Package lock poisoned") = package;
+        self.initialized = false;
+        true
+    }
 
-        if let Err(err) = self.supervisor.down() {
-            outputln!(preamble self.service_group,
-                      "Error stopping process while updating package: {}", err);
+    /// Check on an updated package which is being verified, keeping it once it passed its
+    /// checks or rolling back to the previous package if it failed them.
+    fn verify_update(&mut self) {
+        if self.hooks.smoke_test.is_some() && self.supervisor.is_up() {
+            self.smoke_test();
+        }
+        let status = match self.update_verification {
+            Some(ref verification) => {
+                let smoke_check = if self.hooks.smoke_test.is_some() {
+                    Some(&self.smoke_check)
+                } else {
+                    None
+                };
+                verification.status(smoke_check, &self.rollback_config)
+            }
+            None => return,
+        };
+        match status {
+            VerificationStatus::Pending => (),
+            VerificationStatus::Passed => {
+                let verification = self.update_verification.take().unwrap();
+                outputln!(preamble self.service_group,
+                          "Update to {} verified",
+                          verification.updated);
+            }
+            VerificationStatus::Failed(reason) => {
+                let verification = self.update_verification.take().unwrap();
+                self.rollback(verification, reason);
+            }
         }
-        self.initialized = false;
+    }
+
+    fn rollback(&mut self, verification: UpdateVerification, reason: String) {
+        outputln!(preamble self.service_group,
+                  "Rolling back from {} to {}, {}",
+                  Red.bold().paint(verification.updated.to_string()),
+                  verification.previous,
+                  reason);
+        if !self.is_failed_update(&verification.updated) {
+            self.failed_updates.push(verification.updated.clone());
+        }
+        match PackageInstall::load(&verification.previous, Some(&Path::new(&*FS_ROOT_PATH))) {
+            Ok(package) => {
+                self.swap_package(package);
+            }
+            Err(err) => {
+                outputln!(preamble self.service_group,
+                          "Unable to load {} to roll back to, {}",
+                          verification.previous,
+                          err);
+                return;
+            }
+        }
+        self.last_rollback = Some(Rollback::new(verification.updated,
+                                                verification.previous,
+                                                reason));
     }
 
     pub fn to_rumor<T: ToString>(&self, member_id: T) -> ServiceRumor {
@@ -469,11 +732,14 @@ impl Service {
                 None
             }
         };
-        ServiceRumor::new(member_id.to_string(),
-                          &self.package().ident,
-                          &self.service_group,
-                          &*self.config.sys,
-                          exported.as_ref())
+        let mut rumor = ServiceRumor::new(member_id.to_string(),
+                                          &self.package().ident,
+                                          &self.service_group,
+                                          &*self.config.sys,
+                                          exported.as_ref());
+        rumor.set_desired_state(self.desired_state.to_string());
+        rumor.set_health_check(self.health_check.to_string());
+        rumor
     }
 
     /// Run initialization hook if present
@@ -487,6 +753,14 @@ impl Service {
             outputln!(preamble self.service_group, "Failed to copy run hook: {}", err);
         }
 
+        // Hooks run in the service's cgroup too. If its limits can't be applied the init hook
+        // runs without them, but the service's process won't be started.
+        if let Err(err) = self.supervisor.prepare_cgroup() {
+            outputln!(preamble self.service_group, "{}", err);
+        }
+
+        // The init hook gets the secrets like the service's process does
+        self.write_secrets();
         self.initialized = true;
         if let Some(ref hook) = self.hooks.init {
             self.initialized = hook.run(&self.service_group, self.runtime_cfg())
@@ -539,6 +813,11 @@ impl Service {
         &self.supervisor.runtime_config
     }
 
+    /// The most recent output of the service's process.
+    pub fn output(&self) -> Arc<ProcessOutput> {
+        self.supervisor.output.clone()
+    }
+
     pub fn smoke_test(&mut self) {
         if self.smoke_check == SmokeCheck::Pending {
             match self.hooks.smoke_test {
@@ -638,6 +917,33 @@ impl Service {
         }
     }
 
+    fn cache_health_check_history(&self) {
+        let history_file = self.manager_fs_cfg.health_check_history(&self.service_group);
+        let tmp_file = history_file.with_extension("tmp");
+        let file = match File::create(&tmp_file) {
+            Ok(file) => file,
+            Err(err) => {
+                warn!("Couldn't open temporary health check history file, {}, {}",
+                      self.service_group,
+                      err);
+                return;
+            }
+        };
+        let mut writer = BufWriter::new(file);
+        if let Some(err) = serde_json::to_writer(&mut writer, &self.health_check_state.history)
+               .err() {
+            warn!("Couldn't write to temporary health check history file, {}, {}",
+                  self.service_group,
+                  err);
+        }
+        drop(writer);
+        if let Some(err) = std::fs::rename(&tmp_file, &history_file).err() {
+            warn!("Couldn't finalize health check history file, {}, {}",
+                  self.service_group,
+                  err);
+        }
+    }
+
     // Copy the "run" file to the svc path.
     fn copy_run(&self) -> Result<()> {
         let svc_run = self.svc_path().join(hooks::RunHook::file_name());
@@ -665,16 +971,26 @@ impl Service {
     fn execute_hooks(&mut self) {
         if !self.initialized {
             self.initialize();
-            if self.initialized {
+            if self.initialized && self.desired_state == DesiredState::Up {
                 self.start();
             }
+        } else if self.desired_state == DesiredState::Down {
+            self.check_process();
         } else {
             self.check_process();
-            if Instant::now().duration_since(self.last_health_check) >= *HEALTH_CHECK_INTERVAL {
+            self.post_run.check();
+            if self.supervisor.is_up() && self.post_run.is_due(*POST_RUN_RETRY_INTERVAL) {
+                self.post_run();
+            }
+            if self.is_health_check_due() {
                 self.run_health_check_hook();
             }
+            if self.update_verification.is_some() {
+                self.verify_update();
+            }
 
-            if self.needs_reload || self.is_down() || self.needs_reconfiguration {
+            if self.needs_reload || self.supervisor.is_restart_due() ||
+               self.needs_reconfiguration {
                 self.reload();
                 if self.needs_reconfiguration {
                     self.reconfigure()
@@ -693,32 +1009,137 @@ impl Service {
         false
     }
 
-    /// Write service configuration from gossip data to disk.
+    /// Write service configuration from gossip data to disk, or keep it in memory if it is a
+    /// secret.
     ///
     /// Returns true if a change was made and false if there were no updates.
     fn persist_service_config(&mut self, butterfly: &butterfly::Server) -> bool {
-        if let Some((incarnation, config)) =
+        if let Some((incarnation, config, encrypted)) =
             butterfly.service_config_for(&*self.service_group, Some(self.config.incarnation)) {
             self.config.incarnation = incarnation;
-            self.write_butterfly_service_config(config)
+            if encrypted && self.secret_delivery != SecretDelivery::Files {
+                match self.secrets.set_config(config) {
+                    Ok(true) => {
+                        self.secrets_updated();
+                        true
+                    }
+                    Ok(false) => false,
+                    Err(e) => {
+                        outputln!(preamble self.service_group,
+                                  "Not keeping secret configuration: {}",
+                                  Red.bold().paint(format!("{}", e)));
+                        false
+                    }
+                }
+            } else {
+                self.write_butterfly_service_config(config)
+            }
         } else {
             false
         }
     }
 
-    /// Write service files from gossip data to disk.
+    /// Write service files from gossip data to disk, keeping those which are secrets in memory
+    /// instead.
     ///
-    /// Returnst rue if a file was changed, added, or removed, and false if there were no updates.
+    /// Returns true if a secret was changed or added, and false otherwise.
     fn persist_service_files(&mut self, butterfly: &butterfly::Server) -> bool {
         let mut updated = false;
-        for (incarnation, filename, body) in
+        let mut secrets_updated = false;
+        for (incarnation, filename, body, encrypted) in
             butterfly.service_files_for(&*self.service_group, &self.current_service_files)
                 .into_iter() {
-            if self.write_butterfly_service_file(filename, incarnation, body) {
+            if encrypted && self.secret_delivery != SecretDelivery::Files {
+                if self.keep_butterfly_secret_file(filename, incarnation, body) {
+                    secrets_updated = true;
+                }
+            } else if self.write_butterfly_service_file(filename, incarnation, body) {
                 updated = true;
             }
         }
-        if updated { self.file_updated() } else { false }
+        if secrets_updated {
+            self.secrets_updated();
+        }
+        if updated || secrets_updated {
+            self.file_updated();
+        }
+        secrets_updated
+    }
+
+    /// Keep a service file which is a secret in memory, removing any copy of it which was written
+    /// to disk before.
+    fn keep_butterfly_secret_file(&mut self,
+                                  filename: String,
+                                  incarnation: u64,
+                                  body: Vec<u8>)
+                                  -> bool {
+        self.current_service_files.insert(filename.clone(), incarnation);
+        let on_disk_path = self.svc_files_path().join(&filename);
+        if on_disk_path.exists() {
+            if let Err(e) = std::fs::remove_file(&on_disk_path) {
+                outputln!(preamble self.service_group,
+                          "Failed to remove {}, which is now a secret: {}",
+                          on_disk_path.to_string_lossy(),
+                          Red.bold().paint(format!("{}", e)));
+            }
+        }
+        match self.secrets.set_file(filename.clone(), body) {
+            Ok(true) => (),
+            Ok(false) => return false,
+            Err(e) => {
+                outputln!(preamble self.service_group,
+                          "Not keeping secret {}: {}",
+                          filename,
+                          Red.bold().paint(format!("{}", e)));
+                return false;
+            }
+        }
+        outputln!(preamble self.service_group,
+                  "Secret updated from butterfly: {}",
+                  filename);
+        true
+    }
+
+    /// Hand the service its secrets again after they changed.
+    fn secrets_updated(&mut self) {
+        self.config.secret = self.secrets.to_toml();
+        self.supervisor.runtime_config.secret_env_vars =
+            self.secrets.env_vars(self.secret_delivery, self.service_group.service());
+        if self.supervisor.is_up() {
+            match self.secret_delivery {
+                // The process only sees its environment when it starts.
+                SecretDelivery::Env => self.needs_reload = true,
+                SecretDelivery::Tmpfs => self.write_secrets(),
+                SecretDelivery::Files => (),
+            }
+        }
+    }
+
+    /// Write the secrets to the service's directory on tmpfs, if it gets them there.
+    fn write_secrets(&self) {
+        if self.secret_delivery != SecretDelivery::Tmpfs {
+            return;
+        }
+        if let Err(e) = self.secrets
+               .write_to(&secrets::secrets_path(self.service_group.service()),
+                         &self.supervisor.runtime_config.svc_user,
+                         &self.supervisor.runtime_config.svc_group) {
+            outputln!(preamble self.service_group,
+                      "Failed to write secrets: {}",
+                      Red.bold().paint(format!("{}", e)));
+        }
+    }
+
+    /// Remove the service's secrets from tmpfs, if it gets them there.
+    fn wipe_secrets(&self) {
+        if self.secret_delivery != SecretDelivery::Tmpfs {
+            return;
+        }
+        if let Err(e) = secrets::wipe(&secrets::secrets_path(self.service_group.service())) {
+            outputln!(preamble self.service_group,
+                      "Failed to wipe secrets: {}",
+                      Red.bold().paint(format!("{}", e)));
+        }
     }
 
     /// attempt to remove a symlink in the /svc/run/foo/ directory if
@@ -737,17 +1158,85 @@ impl Service {
         Ok(())
     }
 
+    fn is_health_check_due(&self) -> bool {
+        match self.last_health_check {
+            Some(last) => {
+                Instant::now().duration_since(last) >= self.health_check_config.interval()
+            }
+            None => true,
+        }
+    }
+
     fn run_health_check_hook(&mut self) {
         let check_result = if let Some(ref hook) = self.hooks.health_check {
-            hook.run(&self.service_group, self.runtime_cfg())
+            hook.run_with_timeout(&self.service_group,
+                                  self.runtime_cfg(),
+                                  Some(self.health_check_config.timeout()))
         } else {
             match self.supervisor.status() {
                 (true, _) => HealthCheck::Ok,
                 (false, _) => HealthCheck::Critical,
             }
         };
-        self.last_health_check = Instant::now();
-        self.cache_health_check(check_result);
+        self.last_health_check = Some(Instant::now());
+        if let Some(ref mut verification) = self.update_verification {
+            verification.record_health_check(check_result);
+        }
+        if check_result.is_healthy() {
+            self.healthy_checks_since_update += 1;
+        } else {
+            self.healthy_checks_since_update = 0;
+        }
+        let previous = self.health_check;
+        self.health_check = self.health_check_state.record(check_result, &self.health_check_config);
+        if self.health_check != previous {
+            outputln!(preamble self.service_group,
+                      "Health changed from {} to {}",
+                      previous,
+                      self.health_check);
+        }
+        self.cache_health_check(self.health_check);
+        self.cache_health_check_history();
+        if self.health_check == HealthCheck::Critical && self.health_check_config.restart &&
+           self.supervisor.is_up() {
+            outputln!(preamble self.service_group,
+                      "Restarting process, health check is {}",
+                      self.health_check);
+            self.health_check_state.reset_failures();
+            match self.supervisor.restart() {
+                Ok(()) => self.post_run.reset(),
+                Err(err) => {
+                    outputln!(preamble self.service_group, "Service restart failed: {}", err)
+                }
+            }
+        }
+    }
+
+    /// Update our own service rumor with the latest reported health check, so that other members
+    /// of the ring learn about it.
+    ///
+    /// The run loop's last updated census is a required parameter on this function to inform the
+    /// main loop that we, ourselves, updated the service counter when we updated ourselves.
+    fn update_service_rumor_health(&mut self,
+                                   butterfly: &butterfly::Server,
+                                   last_update: &mut CensusUpdate) {
+        let me = butterfly.member_id().to_string();
+        let health_check = self.health_check;
+        let mut updated = None;
+        butterfly.service_store.with_rumor(&*self.service_group, &me, |rumor| {
+            if let Some(rumor) = rumor {
+                let mut rumor = rumor.clone();
+                let incarnation = rumor.get_incarnation() + 1;
+                rumor.set_incarnation(incarnation);
+                rumor.set_health_check(health_check.to_string());
+                updated = Some(rumor);
+            }
+        });
+        if let Some(rumor) = updated {
+            butterfly.insert_service(rumor);
+            last_update.service_counter += 1;
+            self.gossiped_health_check = health_check;
+        }
     }
 
     /// Update our own service rumor with a new configuration from the packages exported
@@ -995,6 +1484,8 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Batch,
+    Canary,
 }
 
 impl UpdateStrategy {
@@ -1003,6 +1494,8 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Batch => "batch",
+            UpdateStrategy::Canary => "canary",
         }
     }
 }
@@ -1015,6 +1508,8 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "batch" => Ok(UpdateStrategy::Batch),
+            "canary" => Ok(UpdateStrategy::Canary),
             _ => Err(sup_error!(Error::InvalidUpdateStrategy(String::from(strategy)))),
         }
     }
@@ -1048,6 +1543,130 @@ impl serde::Serialize for UpdateStrategy {
     }
 }
 
+/// How many update followers update at the same time in a batched or canary update, given either
+/// as a number of members or as a percentage of the followers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UpdateBatch {
+    Members(u32),
+    Percent(u32),
+}
+
+impl UpdateBatch {
+    /// Returns the number of followers in each batch, which is never less than one.
+    pub fn size(&self, followers: usize) -> usize {
+        let size = match *self {
+            UpdateBatch::Members(members) => members as usize,
+            UpdateBatch::Percent(percent) => (followers * percent as usize + 99) / 100,
+        };
+        cmp::max(size, 1)
+    }
+}
+
+impl FromStr for UpdateBatch {
+    type Err = SupError;
+
+    fn from_str(batch: &str) -> result::Result<Self, Self::Err> {
+        let err = || sup_error!(Error::InvalidUpdateBatch(String::from(batch)));
+        if batch.ends_with('%') {
+            match batch.trim_right_matches('%').parse::<u32>() {
+                Ok(percent) if percent > 0 && percent <= 100 => Ok(UpdateBatch::Percent(percent)),
+                _ => Err(err()),
+            }
+        } else {
+            match batch.parse::<u32>() {
+                Ok(members) if members > 0 => Ok(UpdateBatch::Members(members)),
+                _ => Err(err()),
+            }
+        }
+    }
+}
+
+impl fmt::Display for UpdateBatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UpdateBatch::Members(members) => write!(f, "{}", members),
+            UpdateBatch::Percent(percent) => write!(f, "{}%", percent),
+        }
+    }
+}
+
+impl Default for UpdateBatch {
+    fn default() -> UpdateBatch {
+        UpdateBatch::Members(1)
+    }
+}
+
+impl serde::Deserialize for UpdateBatch {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for UpdateBatch {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DesiredState {
+    Up,
+    Down,
+}
+
+impl DesiredState {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DesiredState::Up => "up",
+            DesiredState::Down => "down",
+        }
+    }
+}
+
+impl FromStr for DesiredState {
+    type Err = SupError;
+
+    fn from_str(state: &str) -> result::Result<Self, Self::Err> {
+        match state {
+            "up" => Ok(DesiredState::Up),
+            "down" => Ok(DesiredState::Down),
+            _ => Err(sup_error!(Error::InvalidDesiredState(String::from(state)))),
+        }
+    }
+}
+
+impl fmt::Display for DesiredState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for DesiredState {
+    fn default() -> DesiredState {
+        DesiredState::Up
+    }
+}
+
+impl serde::Deserialize for DesiredState {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for DesiredState {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 fn serialize_lock<S>(x: &Arc<RwLock<PackageInstall>>, s: S) -> result::Result<S::Ok, S::Error>
     where S: serde::Serializer
 {
@@ -1060,7 +1679,7 @@ mod test {
 
     use toml;
 
-    use super::{Topology, UpdateStrategy};
+    use super::{DesiredState, Topology, UpdateBatch, UpdateStrategy};
     use error::Error::*;
 
     #[test]
@@ -1190,4 +1809,65 @@ mod test {
 
         assert!(toml.starts_with(r#"key = "at-once""#));
     }
+
+    #[test]
+    fn update_batch_from_str() {
+        assert_eq!(UpdateBatch::from_str("3").unwrap(), UpdateBatch::Members(3));
+        assert_eq!(UpdateBatch::from_str("25%").unwrap(),
+                   UpdateBatch::Percent(25));
+        assert!(UpdateBatch::from_str("0").is_err());
+        assert!(UpdateBatch::from_str("150%").is_err());
+        assert!(UpdateBatch::from_str("lots").is_err());
+    }
+
+    #[test]
+    fn update_batch_size() {
+        assert_eq!(UpdateBatch::Members(3).size(10), 3);
+        assert_eq!(UpdateBatch::Percent(25).size(10), 3);
+        assert_eq!(UpdateBatch::Percent(50).size(10), 5);
+        assert_eq!(UpdateBatch::Percent(10).size(2), 1);
+        assert_eq!(UpdateBatch::Percent(100).size(0), 1);
+    }
+
+    #[test]
+    fn update_batch_to_string() {
+        assert_eq!(UpdateBatch::Members(2).to_string(), "2");
+        assert_eq!(UpdateBatch::Percent(20).to_string(), "20%");
+    }
+
+    #[test]
+    fn desired_state_default() {
+        assert_eq!(DesiredState::default(), DesiredState::Up);
+    }
+
+    #[test]
+    fn desired_state_from_str() {
+        assert_eq!(DesiredState::from_str("down").unwrap(), DesiredState::Down);
+        assert_eq!(DesiredState::from_str("up").unwrap(), DesiredState::Up);
+    }
+
+    #[test]
+    fn desired_state_from_str_invalid() {
+        match DesiredState::from_str("sideways") {
+            Err(e) => {
+                match e.err {
+                    InvalidDesiredState(s) => assert_eq!("sideways", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn desired_state_toml_serialize() {
+        #[derive(Serialize)]
+        struct Data {
+            pub key: DesiredState,
+        }
+        let data = Data { key: DesiredState::Down };
+        let toml = toml::to_string(&data).unwrap();
+
+        assert!(toml.starts_with(r#"key = "down""#));
+    }
 }
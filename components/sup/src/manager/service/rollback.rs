@@ -0,0 +1,195 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, Instant};
+
+use hcore::package::PackageIdent;
+use time;
+
+use super::{HealthCheck, SmokeCheck};
+
+/// Governs whether an updated package must prove itself before being kept.
+///
+/// When `enabled`, an updated package is rolled back to the previously installed package unless
+/// its smoke test succeeds, or `health_checks` consecutive health checks pass, within `timeout`
+/// seconds of the update.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RollbackConfig {
+    pub enabled: bool,
+    pub health_checks: u32,
+    pub timeout: u64,
+}
+
+impl RollbackConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+}
+
+impl Default for RollbackConfig {
+    fn default() -> RollbackConfig {
+        RollbackConfig {
+            enabled: false,
+            health_checks: 3,
+            timeout: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationStatus {
+    Pending,
+    Passed,
+    Failed(String),
+}
+
+/// Tracks the checks of an updated package until it's either kept or rolled back.
+#[derive(Debug, Serialize)]
+pub struct UpdateVerification {
+    pub previous: PackageIdent,
+    pub updated: PackageIdent,
+    pub passed_health_checks: u32,
+    #[serde(skip_serializing)]
+    started: Instant,
+}
+
+impl UpdateVerification {
+    pub fn new(previous: PackageIdent, updated: PackageIdent) -> Self {
+        UpdateVerification {
+            previous: previous,
+            updated: updated,
+            passed_health_checks: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Record the result of a health check. Only consecutive passing checks count towards
+    /// verifying the update.
+    pub fn record_health_check(&mut self, result: HealthCheck) {
+        if result.is_healthy() {
+            self.passed_health_checks += 1;
+        } else {
+            self.passed_health_checks = 0;
+        }
+    }
+
+    /// Returns whether the update should be kept, rolled back, or checked some more. The smoke
+    /// check is only given if the package has a smoke test hook.
+    pub fn status(&self,
+                  smoke_check: Option<&SmokeCheck>,
+                  config: &RollbackConfig)
+                  -> VerificationStatus {
+        self.status_at(smoke_check, config, Instant::now())
+    }
+
+    fn status_at(&self,
+                 smoke_check: Option<&SmokeCheck>,
+                 config: &RollbackConfig,
+                 now: Instant)
+                 -> VerificationStatus {
+        match smoke_check {
+            Some(&SmokeCheck::Ok) => return VerificationStatus::Passed,
+            Some(&SmokeCheck::Failed(code)) => {
+                return VerificationStatus::Failed(format!("smoke test failed with exit code {}",
+                                                          code))
+            }
+            Some(&SmokeCheck::Pending) | None => (),
+        }
+        if self.passed_health_checks >= config.health_checks {
+            VerificationStatus::Passed
+        } else if now.duration_since(self.started) >= config.timeout() {
+            VerificationStatus::Failed(format!("{} passing health checks not reached within {}s",
+                                               config.health_checks,
+                                               config.timeout))
+        } else {
+            VerificationStatus::Pending
+        }
+    }
+}
+
+/// A record of an updated package which was rolled back.
+#[derive(Debug, Clone, Serialize)]
+pub struct Rollback {
+    pub from: PackageIdent,
+    pub to: PackageIdent,
+    pub reason: String,
+    pub timestamp: String,
+}
+
+impl Rollback {
+    pub fn new(from: PackageIdent, to: PackageIdent, reason: String) -> Self {
+        Rollback {
+            from: from,
+            to: to,
+            reason: reason,
+            timestamp: time::now_utc().rfc3339().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn verification() -> UpdateVerification {
+        UpdateVerification::new(PackageIdent::from_str("core/redis/3.2.4/20170514150022")
+                                    .unwrap(),
+                                PackageIdent::from_str("core/redis/3.2.9/20170602173812")
+                                    .unwrap())
+    }
+
+    #[test]
+    fn passes_after_consecutive_health_checks() {
+        let config = RollbackConfig::default();
+        let mut verification = verification();
+        verification.record_health_check(HealthCheck::Ok);
+        verification.record_health_check(HealthCheck::Critical);
+        verification.record_health_check(HealthCheck::Ok);
+        verification.record_health_check(HealthCheck::Warning);
+        assert_eq!(verification.status(None, &config), VerificationStatus::Pending);
+
+        verification.record_health_check(HealthCheck::Ok);
+        assert_eq!(verification.status(None, &config), VerificationStatus::Passed);
+    }
+
+    #[test]
+    fn smoke_test_decides_verification() {
+        let config = RollbackConfig::default();
+        let verification = verification();
+
+        assert_eq!(verification.status(Some(&SmokeCheck::Pending), &config),
+                   VerificationStatus::Pending);
+        assert_eq!(verification.status(Some(&SmokeCheck::Ok), &config),
+                   VerificationStatus::Passed);
+        match verification.status(Some(&SmokeCheck::Failed(2)), &config) {
+            VerificationStatus::Failed(reason) => assert!(reason.contains("exit code 2")),
+            status => panic!("Unexpected verification status: {:?}", status),
+        }
+    }
+
+    #[test]
+    fn fails_after_timeout() {
+        let config = RollbackConfig::default();
+        let mut verification = verification();
+        verification.record_health_check(HealthCheck::Ok);
+        let later = Instant::now() + Duration::from_secs(config.timeout);
+
+        match verification.status_at(None, &config, later) {
+            VerificationStatus::Failed(_) => (),
+            status => panic!("Unexpected verification status: {:?}", status),
+        }
+    }
+}
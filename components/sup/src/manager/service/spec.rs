@@ -27,8 +27,11 @@ use rand::{Rng, thread_rng};
 use serde;
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{DesiredState, HealthCheckConfig, RollbackConfig, SecretDelivery, Topology,
+            UpdateBatch, UpdateStrategy};
 use error::{Error, Result, SupError};
+use supervisor::{Limits, RestartConfig, RestartPolicy, ShutdownConfig, ShutdownSignal};
+use util::users::UserConfig;
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
@@ -44,9 +47,38 @@ pub struct ServiceSpec {
     pub ident: PackageIdent,
     pub group: String,
     pub depot_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
     pub topology: Topology,
     pub update_strategy: UpdateStrategy,
+    pub update_batch: UpdateBatch,
+    pub canary_health_checks: u32,
     pub binds: Vec<ServiceBind>,
+    pub desired_state: DesiredState,
+    pub restart_policy: RestartPolicy,
+    pub max_restarts: u32,
+    pub restart_window: u64,
+    pub restart_backoff: u64,
+    pub restart_backoff_max: u64,
+    pub shutdown_signal: ShutdownSignal,
+    pub shutdown_timeout: u64,
+    pub health_check_interval: u64,
+    pub health_check_timeout: u64,
+    pub health_check_failure_threshold: u32,
+    pub health_check_success_threshold: u32,
+    pub health_check_restart: bool,
+    pub rollback: bool,
+    pub rollback_health_checks: u32,
+    pub rollback_timeout: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub svc_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub svc_group: Option<String>,
+    pub supplementary_groups: Vec<String>,
+    pub capabilities: Vec<String>,
+    pub secret_delivery: SecretDelivery,
+    #[serde(skip_serializing_if = "Limits::is_empty")]
+    pub limits: Limits,
     #[serde(skip_deserializing, skip_serializing)]
     pub config_from: Option<PathBuf>,
 }
@@ -127,12 +159,95 @@ impl ServiceSpec {
         Ok(())
     }
 
+    pub fn restart_config(&self) -> RestartConfig {
+        RestartConfig {
+            policy: self.restart_policy,
+            max_restarts: self.max_restarts,
+            window: self.restart_window,
+            backoff: self.restart_backoff,
+            backoff_max: self.restart_backoff_max,
+        }
+    }
+
+    pub fn set_restart_config(&mut self, restart: &RestartConfig) {
+        self.restart_policy = restart.policy;
+        self.max_restarts = restart.max_restarts;
+        self.restart_window = restart.window;
+        self.restart_backoff = restart.backoff;
+        self.restart_backoff_max = restart.backoff_max;
+    }
+
+    pub fn shutdown_config(&self) -> ShutdownConfig {
+        ShutdownConfig {
+            signal: self.shutdown_signal,
+            timeout: self.shutdown_timeout,
+        }
+    }
+
+    pub fn set_shutdown_config(&mut self, shutdown: &ShutdownConfig) {
+        self.shutdown_signal = shutdown.signal;
+        self.shutdown_timeout = shutdown.timeout;
+    }
+
+    pub fn health_check_config(&self) -> HealthCheckConfig {
+        HealthCheckConfig {
+            interval: self.health_check_interval,
+            timeout: self.health_check_timeout,
+            failure_threshold: self.health_check_failure_threshold,
+            success_threshold: self.health_check_success_threshold,
+            restart: self.health_check_restart,
+        }
+    }
+
+    pub fn set_health_check_config(&mut self, health_check: &HealthCheckConfig) {
+        self.health_check_interval = health_check.interval;
+        self.health_check_timeout = health_check.timeout;
+        self.health_check_failure_threshold = health_check.failure_threshold;
+        self.health_check_success_threshold = health_check.success_threshold;
+        self.health_check_restart = health_check.restart;
+    }
+
+    pub fn rollback_config(&self) -> RollbackConfig {
+        RollbackConfig {
+            enabled: self.rollback,
+            health_checks: self.rollback_health_checks,
+            timeout: self.rollback_timeout,
+        }
+    }
+
+    pub fn set_rollback_config(&mut self, rollback: &RollbackConfig) {
+        self.rollback = rollback.enabled;
+        self.rollback_health_checks = rollback.health_checks;
+        self.rollback_timeout = rollback.timeout;
+    }
+
+    pub fn user_config(&self) -> UserConfig {
+        UserConfig {
+            user: self.svc_user.clone(),
+            group: self.svc_group.clone(),
+            supplementary_groups: self.supplementary_groups.clone(),
+            capabilities: self.capabilities.clone(),
+        }
+    }
+
+    pub fn set_user_config(&mut self, user_config: &UserConfig) {
+        self.svc_user = user_config.user.clone();
+        self.svc_group = user_config.group.clone();
+        self.supplementary_groups = user_config.supplementary_groups.clone();
+        self.capabilities = user_config.capabilities.clone();
+    }
+
     pub fn file_name(&self) -> String {
         format!("{}.{}", &self.ident.name, SPEC_FILE_EXT)
     }
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
         self.validate_binds(package)?;
+        self.user_config().validate()?;
+        if self.secret_delivery == SecretDelivery::Tmpfs && !cfg!(target_os = "linux") {
+            return Err(sup_error!(Error::InvalidSecretDelivery("tmpfs is only supported on Linux"
+                                                                   .to_string())));
+        }
         Ok(())
     }
 
@@ -156,13 +271,42 @@ impl ServiceSpec {
 
 impl Default for ServiceSpec {
     fn default() -> Self {
+        let restart = RestartConfig::default();
+        let shutdown = ShutdownConfig::default();
+        let health_check = HealthCheckConfig::default();
+        let rollback = RollbackConfig::default();
         ServiceSpec {
             ident: PackageIdent::default(),
             group: DEFAULT_GROUP.to_string(),
             depot_url: DEFAULT_DEPOT_URL.to_string(),
+            channel: None,
             topology: Topology::default(),
             update_strategy: UpdateStrategy::default(),
+            update_batch: UpdateBatch::default(),
+            canary_health_checks: 0,
             binds: vec![],
+            desired_state: DesiredState::default(),
+            restart_policy: restart.policy,
+            max_restarts: restart.max_restarts,
+            restart_window: restart.window,
+            restart_backoff: restart.backoff,
+            restart_backoff_max: restart.backoff_max,
+            shutdown_signal: shutdown.signal,
+            shutdown_timeout: shutdown.timeout,
+            health_check_interval: health_check.interval,
+            health_check_timeout: health_check.timeout,
+            health_check_failure_threshold: health_check.failure_threshold,
+            health_check_success_threshold: health_check.success_threshold,
+            health_check_restart: health_check.restart,
+            rollback: rollback.enabled,
+            rollback_health_checks: rollback.health_checks,
+            rollback_timeout: rollback.timeout,
+            svc_user: None,
+            svc_group: None,
+            supplementary_groups: vec![],
+            capabilities: vec![],
+            secret_delivery: SecretDelivery::default(),
+            limits: Limits::default(),
             config_from: None,
         }
     }
@@ -238,8 +382,10 @@ mod test {
     use tempdir::TempDir;
     use toml;
 
-    use super::{ServiceBind, ServiceSpec, Topology, UpdateStrategy};
+    use super::{DesiredState, SecretDelivery, ServiceBind, ServiceSpec, Topology, UpdateBatch,
+                UpdateStrategy};
     use error::Error::*;
+    use supervisor::{Limits, RestartPolicy, ShutdownSignal};
 
     fn file_from_str<P: AsRef<Path>>(path: P, content: &str) {
         fs::create_dir_all(path.as_ref()
@@ -265,12 +411,41 @@ mod test {
             ident = "origin/name/1.2.3/20170223130020"
             group = "jobs"
             depot_url = "http://example.com/depot"
+            channel = "stable"
             topology = "leader"
             update_strategy = "rolling"
+            update_batch = "25%"
+            canary_health_checks = 3
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
+            desired_state = "down"
+            restart_policy = "never"
+            max_restarts = 5
+            restart_window = 120
+            restart_backoff = 3
+            restart_backoff_max = 90
+            shutdown_signal = "INT"
+            shutdown_timeout = 30
+            health_check_interval = 15
+            health_check_timeout = 5
+            health_check_failure_threshold = 3
+            health_check_success_threshold = 2
+            health_check_restart = true
+            rollback = true
+            rollback_health_checks = 5
+            rollback_timeout = 600
+            svc_user = "www"
+            svc_group = "www"
+            supplementary_groups = ["ssl-cert"]
+            capabilities = ["CAP_NET_BIND_SERVICE"]
+            secret_delivery = "tmpfs"
 
             config_from = "should not be parsed"
             extra_stuff = "should be ignored"
+
+            [limits]
+            memory_max = 536870912
+            cpu_quota = 50
+            pids_max = 128
             "#;
         let spec = ServiceSpec::from_str(toml).unwrap();
 
@@ -278,11 +453,42 @@ mod test {
                    PackageIdent::from_str("origin/name/1.2.3/20170223130020").unwrap());
         assert_eq!(spec.group, String::from("jobs"));
         assert_eq!(spec.depot_url, String::from("http://example.com/depot"));
+        assert_eq!(spec.channel, Some(String::from("stable")));
         assert_eq!(spec.topology, Topology::Leader);
         assert_eq!(spec.update_strategy, UpdateStrategy::Rolling);
+        assert_eq!(spec.update_batch, UpdateBatch::Percent(25));
+        assert_eq!(spec.canary_health_checks, 3);
         assert_eq!(spec.binds,
                    vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()]);
+        assert_eq!(spec.desired_state, DesiredState::Down);
+        assert_eq!(spec.restart_policy, RestartPolicy::Never);
+        assert_eq!(spec.max_restarts, 5);
+        assert_eq!(spec.restart_window, 120);
+        assert_eq!(spec.restart_backoff, 3);
+        assert_eq!(spec.restart_backoff_max, 90);
+        assert_eq!(spec.shutdown_signal, ShutdownSignal::Int);
+        assert_eq!(spec.shutdown_timeout, 30);
+        assert_eq!(spec.health_check_interval, 15);
+        assert_eq!(spec.health_check_timeout, 5);
+        assert_eq!(spec.health_check_failure_threshold, 3);
+        assert_eq!(spec.health_check_success_threshold, 2);
+        assert_eq!(spec.health_check_restart, true);
+        assert_eq!(spec.rollback, true);
+        assert_eq!(spec.rollback_health_checks, 5);
+        assert_eq!(spec.rollback_timeout, 600);
+        assert_eq!(spec.svc_user, Some(String::from("www")));
+        assert_eq!(spec.svc_group, Some(String::from("www")));
+        assert_eq!(spec.supplementary_groups, vec![String::from("ssl-cert")]);
+        assert_eq!(spec.capabilities, vec![String::from("CAP_NET_BIND_SERVICE")]);
+        assert_eq!(spec.secret_delivery, SecretDelivery::Tmpfs);
+        assert_eq!(spec.limits,
+                   Limits {
+                       memory_max: Some(536870912),
+                       cpu_shares: None,
+                       cpu_quota: Some(50),
+                       pids_max: Some(128),
+                   });
         assert_eq!(spec.config_from, None);
     }
 
@@ -301,6 +507,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn service_spec_from_str_default_desired_state() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.desired_state, DesiredState::Up);
+    }
+
+    #[test]
+    fn service_spec_without_channel() {
+        let spec = ServiceSpec::default_for(PackageIdent::from_str("origin/name").unwrap());
+        assert_eq!(spec.channel, None);
+
+        let toml = spec.to_toml_string().unwrap();
+        assert!(!toml.contains("channel"));
+        assert!(!toml.contains("[limits]"));
+        assert_eq!(ServiceSpec::from_str(&toml).unwrap().channel, None);
+    }
+
     #[test]
     fn service_spec_from_str_invalid_topology() {
         let toml = r#"
@@ -344,10 +571,40 @@ mod test {
             ident: PackageIdent::from_str("origin/name/1.2.3/20170223130020").unwrap(),
             group: String::from("jobs"),
             depot_url: String::from("http://example.com/depot"),
+            channel: Some(String::from("stable")),
             topology: Topology::Leader,
             update_strategy: UpdateStrategy::AtOnce,
+            update_batch: UpdateBatch::Members(2),
+            canary_health_checks: 5,
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
+            desired_state: DesiredState::Down,
+            restart_policy: RestartPolicy::OnFailure,
+            max_restarts: 3,
+            restart_window: 60,
+            restart_backoff: 2,
+            restart_backoff_max: 30,
+            shutdown_signal: ShutdownSignal::Quit,
+            shutdown_timeout: 60,
+            health_check_interval: 10,
+            health_check_timeout: 2,
+            health_check_failure_threshold: 4,
+            health_check_success_threshold: 1,
+            health_check_restart: true,
+            rollback: true,
+            rollback_health_checks: 2,
+            rollback_timeout: 120,
+            svc_user: Some(String::from("www")),
+            svc_group: None,
+            supplementary_groups: vec![String::from("ssl-cert")],
+            capabilities: vec![String::from("CAP_NET_BIND_SERVICE")],
+            secret_delivery: SecretDelivery::Env,
+            limits: Limits {
+                memory_max: Some(1073741824),
+                cpu_shares: Some(512),
+                cpu_quota: None,
+                pids_max: None,
+            },
             config_from: Some(PathBuf::from("/")),
         };
         let toml = spec.to_toml_string().unwrap();
@@ -355,10 +612,31 @@ mod test {
         assert!(toml.contains(r#"ident = "origin/name/1.2.3/20170223130020""#));
         assert!(toml.contains(r#"group = "jobs""#));
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
+        assert!(toml.contains(r#"channel = "stable""#));
         assert!(toml.contains(r#"topology = "leader""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"update_batch = "2""#));
+        assert!(toml.contains(r#"canary_health_checks = 5"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
+        assert!(toml.contains(r#"desired_state = "down""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains(r#"max_restarts = 3"#));
+        assert!(toml.contains(r#"shutdown_signal = "QUIT""#));
+        assert!(toml.contains(r#"shutdown_timeout = 60"#));
+        assert!(toml.contains(r#"health_check_failure_threshold = 4"#));
+        assert!(toml.contains(r#"health_check_restart = true"#));
+        assert!(toml.contains(r#"rollback = true"#));
+        assert!(toml.contains(r#"rollback_timeout = 120"#));
+        assert!(toml.contains(r#"svc_user = "www""#));
+        assert!(!toml.contains(r#"svc_group = "#));
+        assert!(toml.contains(r#""ssl-cert""#));
+        assert!(toml.contains(r#""CAP_NET_BIND_SERVICE""#));
+        assert!(toml.contains(r#"secret_delivery = "env""#));
+        assert!(toml.contains("[limits]"));
+        assert!(toml.contains(r#"memory_max = 1073741824"#));
+        assert!(toml.contains(r#"cpu_shares = 512"#));
+        assert!(!toml.contains(r#"cpu_quota = "#));
         assert!(!toml.contains(r#"config_from = "#));
     }
 
@@ -387,9 +665,28 @@ mod test {
             ident = "origin/name/1.2.3/20170223130020"
             group = "jobs"
             depot_url = "http://example.com/depot"
+            channel = "stable"
             topology = "leader"
             update_strategy = "rolling"
+            update_batch = "25%"
+            canary_health_checks = 3
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
+            desired_state = "down"
+            restart_policy = "never"
+            max_restarts = 5
+            restart_window = 120
+            restart_backoff = 3
+            restart_backoff_max = 90
+            shutdown_signal = "INT"
+            shutdown_timeout = 30
+            health_check_interval = 15
+            health_check_timeout = 5
+            health_check_failure_threshold = 3
+            health_check_success_threshold = 2
+            health_check_restart = true
+            rollback = true
+            rollback_health_checks = 5
+            rollback_timeout = 600
 
             config_from = "should not be parsed"
             extra_stuff = "should be ignored"
@@ -401,11 +698,30 @@ mod test {
                    PackageIdent::from_str("origin/name/1.2.3/20170223130020").unwrap());
         assert_eq!(spec.group, String::from("jobs"));
         assert_eq!(spec.depot_url, String::from("http://example.com/depot"));
+        assert_eq!(spec.channel, Some(String::from("stable")));
         assert_eq!(spec.topology, Topology::Leader);
         assert_eq!(spec.update_strategy, UpdateStrategy::Rolling);
+        assert_eq!(spec.update_batch, UpdateBatch::Percent(25));
+        assert_eq!(spec.canary_health_checks, 3);
         assert_eq!(spec.binds,
                    vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()]);
+        assert_eq!(spec.desired_state, DesiredState::Down);
+        assert_eq!(spec.restart_policy, RestartPolicy::Never);
+        assert_eq!(spec.max_restarts, 5);
+        assert_eq!(spec.restart_window, 120);
+        assert_eq!(spec.restart_backoff, 3);
+        assert_eq!(spec.restart_backoff_max, 90);
+        assert_eq!(spec.shutdown_signal, ShutdownSignal::Int);
+        assert_eq!(spec.shutdown_timeout, 30);
+        assert_eq!(spec.health_check_interval, 15);
+        assert_eq!(spec.health_check_timeout, 5);
+        assert_eq!(spec.health_check_failure_threshold, 3);
+        assert_eq!(spec.health_check_success_threshold, 2);
+        assert_eq!(spec.health_check_restart, true);
+        assert_eq!(spec.rollback, true);
+        assert_eq!(spec.rollback_health_checks, 5);
+        assert_eq!(spec.rollback_timeout, 600);
         assert_eq!(spec.config_from, None);
     }
 
@@ -467,10 +783,35 @@ mod test {
             ident: PackageIdent::from_str("origin/name/1.2.3/20170223130020").unwrap(),
             group: String::from("jobs"),
             depot_url: String::from("http://example.com/depot"),
+            channel: Some(String::from("stable")),
             topology: Topology::Leader,
             update_strategy: UpdateStrategy::AtOnce,
+            update_batch: UpdateBatch::Members(2),
+            canary_health_checks: 5,
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
+            desired_state: DesiredState::Down,
+            restart_policy: RestartPolicy::OnFailure,
+            max_restarts: 3,
+            restart_window: 60,
+            restart_backoff: 2,
+            restart_backoff_max: 30,
+            shutdown_signal: ShutdownSignal::Quit,
+            shutdown_timeout: 60,
+            health_check_interval: 10,
+            health_check_timeout: 2,
+            health_check_failure_threshold: 4,
+            health_check_success_threshold: 1,
+            health_check_restart: true,
+            rollback: true,
+            rollback_health_checks: 2,
+            rollback_timeout: 120,
+            svc_user: None,
+            svc_group: None,
+            supplementary_groups: vec![],
+            capabilities: vec![],
+            secret_delivery: SecretDelivery::default(),
+            limits: Limits::default(),
             config_from: Some(PathBuf::from("/")),
         };
         spec.to_file(&path).unwrap();
@@ -479,10 +820,22 @@ mod test {
         assert!(toml.contains(r#"ident = "origin/name/1.2.3/20170223130020""#));
         assert!(toml.contains(r#"group = "jobs""#));
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
+        assert!(toml.contains(r#"channel = "stable""#));
         assert!(toml.contains(r#"topology = "leader""#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"update_batch = "2""#));
+        assert!(toml.contains(r#"canary_health_checks = 5"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
+        assert!(toml.contains(r#"desired_state = "down""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains(r#"max_restarts = 3"#));
+        assert!(toml.contains(r#"shutdown_signal = "QUIT""#));
+        assert!(toml.contains(r#"shutdown_timeout = 60"#));
+        assert!(toml.contains(r#"health_check_failure_threshold = 4"#));
+        assert!(toml.contains(r#"health_check_restart = true"#));
+        assert!(toml.contains(r#"rollback = true"#));
+        assert!(toml.contains(r#"rollback_timeout = 120"#));
         assert!(!toml.contains(r#"config_from = "#));
     }
 
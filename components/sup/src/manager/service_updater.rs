@@ -14,14 +14,19 @@
 
 use std::collections::HashMap;
 use std::path::Path;
+use std::result;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
 use butterfly;
+use butterfly::rumor::update_directive::{UpdateDirective, UpdateDirective_Action};
 use common::ui::UI;
 use depot_client;
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::crypto::default_cache_key_path;
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
@@ -29,16 +34,20 @@ use time::{SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
 use error::Result;
-use manager::census::CensusList;
+use manager::census::{Census, CensusEntry, CensusList};
 use manager::service::{Service, Topology, UpdateStrategy};
+use util;
 
 static LOGKEY: &'static str = "SU";
 const UPDATE_STRATEGY_FREQUENCY_MS: i64 = 60_000;
+/// How long members wait on each other during a coordinated update before moving on without the
+/// members which haven't updated, for example because they rolled back a failed update.
+const UPDATE_TURN_TIMEOUT_MS: i64 = 900_000;
 
 type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
 
 enum UpdaterState {
-    AtOnce(Receiver<PackageInstall>),
+    AtOnce(WorkerRx),
     Rolling(RollingState),
 }
 
@@ -50,17 +59,45 @@ enum RollingState {
 }
 
 enum LeaderState {
-    Polling(Receiver<PackageInstall>),
-    Waiting,
+    Polling(WorkerRx),
+    Waiting(TurnWait),
 }
 
 enum FollowerState {
-    Waiting,
-    Updating(Receiver<PackageInstall>),
+    Waiting(TurnWait),
+    Updating(WorkerRx),
+}
+
+/// When we started waiting on other members to update to a package, so that a member which
+/// never does can't hold up the rest of its service group forever.
+#[derive(Default)]
+struct TurnWait(Option<(PackageIdent, SteadyTime)>);
+
+impl TurnWait {
+    /// Returns true once we've been waiting on others to update to `pkg` for longer than
+    /// `UPDATE_TURN_TIMEOUT_MS`. Waiting on a different package starts the wait over.
+    fn timed_out(&mut self, pkg: &PackageIdent) -> bool {
+        let now = SteadyTime::now();
+        let waited = match self.0 {
+            Some((ref waiting_for, since)) if waiting_for == pkg => Some(now - since),
+            _ => None,
+        };
+        match waited {
+            Some(waited) => waited >= TimeDuration::milliseconds(UPDATE_TURN_TIMEOUT_MS),
+            None => {
+                self.0 = Some((pkg.clone(), now));
+                false
+            }
+        }
+    }
 }
 
 pub struct ServiceUpdater {
     states: UpdaterStateList,
+    /// Incarnation of the last update directive followed by each service group.
+    directives: HashMap<ServiceGroup, u64>,
+    /// Incarnation of the last update-now directive whose package each service group updated to.
+    applied: HashMap<ServiceGroup, u64>,
     butterfly: butterfly::Server,
 }
 
@@ -68,6 +105,8 @@ impl ServiceUpdater {
     pub fn new(butterfly: butterfly::Server) -> Self {
         ServiceUpdater {
             states: UpdaterStateList::default(),
+            directives: HashMap::new(),
+            applied: HashMap::new(),
             butterfly: butterfly,
         }
     }
@@ -76,13 +115,18 @@ impl ServiceUpdater {
         match service.update_strategy {
             UpdateStrategy::None => false,
             UpdateStrategy::AtOnce => {
+                let pin = self.butterfly
+                    .update_directive_for(&*service.service_group)
+                    .and_then(|directive| self.pin_for(service, &directive));
                 self.states.entry(service.service_group.clone()).or_insert_with(|| {
-                    let rx = Worker::new(service).start(&service.service_group, None);
+                    let rx = Worker::new(service, pin).start(&service.service_group, None);
                     UpdaterState::AtOnce(rx)
                 });
                 true
             }
-            UpdateStrategy::Rolling => {
+            UpdateStrategy::Rolling |
+            UpdateStrategy::Batch |
+            UpdateStrategy::Canary => {
                 self.states
                     .entry(service.service_group.clone())
                     .or_insert(UpdaterState::Rolling(RollingState::AwaitingElection));
@@ -95,6 +139,25 @@ impl ServiceUpdater {
                                      service: &mut Service,
                                      census_list: &CensusList)
                                      -> bool {
+        let directive = self.butterfly.update_directive_for(&*service.service_group);
+        let mut pin = None;
+        if let Some(ref directive) = directive {
+            let followed = self.follow_directive(service, directive);
+            let applied = self.apply_update_now(service, directive);
+            pin = self.pin_for(service, directive);
+            if followed || applied {
+                self.restart_polling(service, pin.clone());
+            }
+            if directive.is_paused() {
+                debug!("Updates of {} are paused", service.service_group);
+                self.stop_worker(&service.service_group);
+                return false;
+            }
+        }
+        if !self.states.contains_key(&service.service_group) {
+            // The worker was stopped while updates were paused
+            self.add(service);
+        }
         let mut updated = false;
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
@@ -108,7 +171,7 @@ impl ServiceUpdater {
                 }
                 outputln!(preamble service.service_group,
                     "Service Updater worker has died {}", "; restarting...");
-                *rx = Worker::new(service).start(&service.service_group, None);
+                *rx = Worker::new(service, pin.clone()).start(&service.service_group, None);
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
                 if let Some(census) = census_list.get(&*service.service_group) {
@@ -144,10 +207,12 @@ impl ServiceUpdater {
                                 debug!("We're the leader");
                                 // Start in waiting state to ensure all members agree with our
                                 // version before attempting a new rolling upgrade.
-                                *st = RollingState::Leader(LeaderState::Waiting);
+                                let waiting = LeaderState::Waiting(TurnWait::default());
+                                *st = RollingState::Leader(waiting);
                             } else {
                                 debug!("We're a follower");
-                                *st = RollingState::Follower(FollowerState::Waiting);
+                                let waiting = FollowerState::Waiting(TurnWait::default());
+                                *st = RollingState::Follower(waiting);
                             }
                         }
                         (Some(_), None) => return false,
@@ -156,6 +221,7 @@ impl ServiceUpdater {
                 }
             }
             Some(&mut UpdaterState::Rolling(RollingState::Leader(ref mut state))) => {
+                let mut polling = None;
                 match *state {
                     LeaderState::Polling(ref mut rx) => {
                         match rx.try_recv() {
@@ -168,26 +234,33 @@ impl ServiceUpdater {
                             Err(TryRecvError::Disconnected) => {
                                 outputln!(preamble service.service_group,
                                     "Service Updater has died {}", "; restarting...");
-                                *rx = Worker::new(service).start(&service.service_group, None);
+                                *rx = Worker::new(service, pin.clone())
+                                    .start(&service.service_group, None);
                             }
                         }
                     }
-                    LeaderState::Waiting => {
+                    LeaderState::Waiting(ref mut wait) => {
                         match census_list.get(&*service.service_group) {
                             Some(census) => {
-                                if census.members_ordered().iter().any(|ce| {
-                                    ce.pkg.as_ref().unwrap() !=
-                                    census.me()
-                                        .unwrap()
-                                        .pkg
-                                        .as_ref()
-                                        .unwrap()
-                                }) {
-                                    debug!("Update leader still waiting for followers...");
-                                    return false;
+                                if service.update_strategy == UpdateStrategy::Canary {
+                                    approve_healthy_canary(&self.butterfly, service, census);
                                 }
-                                let rx = Worker::new(service).start(&service.service_group, None);
-                                *state = LeaderState::Polling(rx);
+                                let my_pkg = census.me().unwrap().pkg.as_ref().unwrap();
+                                if census.members_ordered()
+                                       .iter()
+                                       .any(|ce| ce.pkg.as_ref().unwrap() != my_pkg) {
+                                    if !wait.timed_out(my_pkg) {
+                                        debug!("Update leader still waiting for followers...");
+                                        return false;
+                                    }
+                                    outputln!(preamble service.service_group,
+                                              "Not every follower updated to {} within {}s, \
+                                               looking for updates anyway",
+                                              my_pkg,
+                                              UPDATE_TURN_TIMEOUT_MS / 1000);
+                                }
+                                polling = Some(Worker::new(service, pin.clone())
+                                                   .start(&service.service_group, None));
                             }
                             None => {
                                 panic!("Expected census list to have service group '{}'!",
@@ -196,32 +269,61 @@ impl ServiceUpdater {
                         }
                     }
                 }
+                if let Some(rx) = polling {
+                    *state = LeaderState::Polling(rx);
+                }
                 if updated {
-                    *state = LeaderState::Waiting;
+                    *state = LeaderState::Waiting(TurnWait::default());
                 }
             }
             Some(&mut UpdaterState::Rolling(RollingState::Follower(ref mut state))) => {
+                let mut updating = None;
                 match *state {
-                    FollowerState::Waiting => {
+                    FollowerState::Waiting(ref mut wait) => {
                         match census_list.get(&*service.service_group) {
                             Some(census) => {
-                                match (census.get_update_leader(),
-                                       census.previous_peer(),
-                                       census.me()) {
-                                    (Some(leader), Some(peer), Some(me)) => {
+                                match (census.get_update_leader(), census.me()) {
+                                    (Some(leader), Some(me)) => {
                                         if leader.pkg == me.pkg {
                                             debug!("We're not in an update");
                                             return false;
                                         }
-                                        if leader.pkg != peer.pkg {
-                                            debug!("We're in an update but it's not our turn");
+                                        if service.update_strategy == UpdateStrategy::Canary &&
+                                           me.get_update_approved_pkg() != leader.pkg.as_ref() {
+                                            debug!("We're in a canary update which hasn't been \
+                                                    approved yet");
+                                            return false;
+                                        }
+                                        if !satisfies_pin(leader, pin.as_ref()) {
+                                            debug!("We're in an update to a package which isn't \
+                                                    allowed by the update pin");
+                                            return false;
+                                        }
+                                        if !is_follower_turn(service, census, leader) {
+                                            let timed_out = leader.pkg
+                                                .as_ref()
+                                                .map_or(false, |pkg| wait.timed_out(pkg));
+                                            if !timed_out {
+                                                debug!("We're in an update but it's not our \
+                                                        turn");
+                                                return false;
+                                            }
+                                            outputln!(preamble service.service_group,
+                                                      "Followers before us didn't update \
+                                                       within {}s, updating anyway",
+                                                      UPDATE_TURN_TIMEOUT_MS / 1000);
+                                        }
+                                        if leader.pkg
+                                               .as_ref()
+                                               .map_or(false, |p| service.is_failed_update(p)) {
+                                            debug!("We're in an update to a package which \
+                                                    previously failed to update");
                                             return false;
                                         }
                                         debug!("We're in an update and it's our turn");
-                                        let rx =
-                                            Worker::new(service).start(&service.service_group,
-                                                                       leader.pkg.clone());
-                                        *state = FollowerState::Updating(rx);
+                                        updating = Some(Worker::new(service, pin.clone())
+                                                            .start(&service.service_group,
+                                                                   leader.pkg.clone()));
                                     }
                                     _ => return false,
                                 }
@@ -248,8 +350,8 @@ impl ServiceUpdater {
                                             .unwrap()
                                             .pkg
                                             .clone();
-                                        *rx = Worker::new(service).start(&service.service_group,
-                                                                         package);
+                                        *rx = Worker::new(service, pin.clone())
+                                            .start(&service.service_group, package);
                                     }
                                 }
                             }
@@ -260,29 +362,210 @@ impl ServiceUpdater {
                         }
                     }
                 }
+                if let Some(rx) = updating {
+                    *state = FollowerState::Updating(rx);
+                }
                 if updated {
-                    *state = FollowerState::Waiting;
+                    *state = FollowerState::Waiting(TurnWait::default());
                 }
             }
             None => {}
         }
         updated
     }
+
+    /// Returns true if the update directive hasn't been followed by the service group yet,
+    /// recording that it now is.
+    fn follow_directive(&mut self, service: &Service, directive: &UpdateDirective) -> bool {
+        let incarnation = directive.get_incarnation();
+        if self.directives.get(&service.service_group) == Some(&incarnation) {
+            return false;
+        }
+        self.directives.insert(service.service_group.clone(), incarnation);
+        match directive.get_action() {
+            UpdateDirective_Action::Pause => {
+                outputln!(preamble service.service_group, "Updates paused")
+            }
+            UpdateDirective_Action::Resume => {
+                outputln!(preamble service.service_group, "Updates resumed")
+            }
+            UpdateDirective_Action::Pin => {
+                outputln!(preamble service.service_group,
+                          "Updates pinned to {}",
+                          directive.get_pkg())
+            }
+            UpdateDirective_Action::UpdateNow => {
+                outputln!(preamble service.service_group, "Checking for updates now")
+            }
+        }
+        true
+    }
+
+    /// Returns the package updates of the service are pinned to by the directive, if any. A pin
+    /// directive holds until it is replaced, while the package of an update-now directive only
+    /// holds until the service has updated to it.
+    fn pin_for(&self, service: &Service, directive: &UpdateDirective) -> Option<PackageIdent> {
+        if directive.get_action() == UpdateDirective_Action::UpdateNow &&
+           self.applied.get(&service.service_group) == Some(&directive.get_incarnation()) {
+            return None;
+        }
+        pinned_ident(directive)
+    }
+
+    /// Returns true if the service has just updated to the package of an update-now directive,
+    /// recording that the directive no longer pins its updates.
+    fn apply_update_now(&mut self, service: &Service, directive: &UpdateDirective) -> bool {
+        if directive.get_action() != UpdateDirective_Action::UpdateNow ||
+           self.applied.get(&service.service_group) == Some(&directive.get_incarnation()) {
+            return false;
+        }
+        let pin = match pinned_ident(directive) {
+            Some(pin) => pin,
+            None => return false,
+        };
+        if !service.package().ident().satisfies(&pin) {
+            return false;
+        }
+        outputln!(preamble service.service_group,
+                  "Updated to {}, no longer pinned to it",
+                  pin);
+        self.applied.insert(service.service_group.clone(), directive.get_incarnation());
+        true
+    }
+
+    /// Stop the update worker of the service group, if it has one, so that nothing is downloaded
+    /// while its updates are paused. A worker is started again once they resume.
+    fn stop_worker(&mut self, service_group: &ServiceGroup) {
+        let at_once = match self.states.get_mut(service_group) {
+            Some(&mut UpdaterState::AtOnce(_)) => true,
+            Some(&mut UpdaterState::Rolling(RollingState::Leader(ref mut state))) => {
+                if let LeaderState::Polling(_) = *state {
+                    *state = LeaderState::Waiting(TurnWait::default());
+                }
+                false
+            }
+            Some(&mut UpdaterState::Rolling(RollingState::Follower(ref mut state))) => {
+                if let FollowerState::Updating(_) = *state {
+                    *state = FollowerState::Waiting(TurnWait::default());
+                }
+                false
+            }
+            _ => false,
+        };
+        if at_once {
+            self.states.remove(service_group);
+        }
+    }
+
+    /// Replace the polling worker of the service group, if it has one, so a changed pin is taken
+    /// into account and the depot is checked right away.
+    fn restart_polling(&mut self, service: &Service, pin: Option<PackageIdent>) {
+        match self.states.get_mut(&service.service_group) {
+            Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
+                *rx = Worker::new(service, pin).start(&service.service_group, None);
+            }
+            Some(&mut UpdaterState::Rolling(RollingState::Leader(ref mut state))) => {
+                if let LeaderState::Polling(ref mut rx) = *state {
+                    *rx = Worker::new(service, pin).start(&service.service_group, None);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Returns the package an update directive pins its service group to, if any. Pins which aren't
+/// valid package identifiers are ignored.
+fn pinned_ident(directive: &UpdateDirective) -> Option<PackageIdent> {
+    directive.pinned_pkg().and_then(|pkg| PackageIdent::from_str(pkg).ok())
+}
+
+/// Returns true if the census entry's package is allowed by the update pin, if there is one.
+fn satisfies_pin(entry: &CensusEntry, pin: Option<&PackageIdent>) -> bool {
+    match (entry.pkg.as_ref(), pin) {
+        (Some(pkg), Some(pin)) => pkg.satisfies(pin),
+        _ => true,
+    }
+}
+
+/// Returns true if it's this update follower's turn to update to the update leader's package.
+///
+/// Rolling updates move through the followers one at a time, each waiting on its previous peer.
+/// Batched and canary updates let every follower in a batch update at the same time, once all
+/// followers in the earlier batches have updated.
+fn is_follower_turn(service: &Service, census: &Census, leader: &CensusEntry) -> bool {
+    match service.update_strategy {
+        UpdateStrategy::Batch | UpdateStrategy::Canary => {
+            let followers = census.update_followers_ordered().len();
+            census.is_update_batch_turn(service.update_batch.size(followers))
+        }
+        _ => census.previous_peer().map_or(false, |peer| peer.pkg == leader.pkg),
+    }
+}
+
+/// Approve the package of a canary update leader for the rest of its service group once it has
+/// passed the service's number of consecutive canary health checks. Canaries of services without
+/// a health gate have to be approved by an operator instead.
+fn approve_healthy_canary(butterfly: &butterfly::Server, service: &Service, census: &Census) {
+    if service.canary_health_checks == 0 ||
+       service.healthy_checks_since_update() < service.canary_health_checks {
+        return;
+    }
+    let me = match census.me() {
+        Some(me) => me,
+        None => return,
+    };
+    if me.pkg.is_none() || me.get_update_approved_pkg() == me.pkg.as_ref() {
+        return;
+    }
+    let pkg = me.get_pkg().to_string();
+    if butterfly.approve_update_election(&*service.service_group, &pkg) {
+        outputln!(preamble service.service_group,
+                  "Canary {} passed {} health checks, approving it for the rest of the group",
+                  pkg,
+                  service.canary_health_checks);
+    }
+}
+
+/// Receives the packages found by an update worker. Dropping it stops the worker.
+struct WorkerRx {
+    rx: Receiver<PackageInstall>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl WorkerRx {
+    fn try_recv(&self) -> result::Result<PackageInstall, TryRecvError> {
+        self.rx.try_recv()
+    }
+}
+
+impl Drop for WorkerRx {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
 }
 
 struct Worker {
     current: PackageIdent,
     spec_ident: PackageIdent,
+    channel: Option<String>,
+    failed_updates: Vec<PackageIdent>,
     depot: depot_client::Client,
+    stopped: Arc<AtomicBool>,
     ui: UI,
 }
 
 impl Worker {
-    pub fn new(service: &Service) -> Self {
+    /// Create a worker for the service. A pinned package ident takes the place of the service's
+    /// spec ident when polling for newer packages.
+    pub fn new(service: &Service, pin: Option<PackageIdent>) -> Self {
         Worker {
             current: service.package().ident().clone(),
-            spec_ident: service.spec_ident.clone(),
+            spec_ident: pin.unwrap_or(service.spec_ident.clone()),
+            channel: service.channel.clone(),
+            failed_updates: service.failed_updates.clone(),
             depot: depot_client::Client::new(&service.depot_url, PRODUCT, VERSION, None).unwrap(),
+            stopped: Arc::new(AtomicBool::new(false)),
             ui: UI::default(),
         }
     }
@@ -291,12 +574,11 @@ impl Worker {
     ///
     /// Passing an optional package identifier will make the worker perform a run-once update to
     /// retrieve a specific version from a remote Depot. If no package identifier is specified,
-    /// then the updater will poll until a newer more suitable package is found.
-    pub fn start(mut self,
-                 sg: &ServiceGroup,
-                 ident: Option<PackageIdent>)
-                 -> Receiver<PackageInstall> {
+    /// then the updater will poll until a newer more suitable package is found, only considering
+    /// packages promoted to the service's channel if it follows one.
+    pub fn start(mut self, sg: &ServiceGroup, ident: Option<PackageIdent>) -> WorkerRx {
         let (tx, rx) = sync_channel(0);
+        let stopped = self.stopped.clone();
         thread::Builder::new()
             .name(format!("service-updater-{}", sg))
             .spawn(move || match ident {
@@ -304,18 +586,21 @@ impl Worker {
                        None => self.run_poll(tx),
                    })
             .expect("unable to start service-updater thread");
-        rx
+        WorkerRx {
+            rx: rx,
+            stopped: stopped,
+        }
     }
 
     fn run_once(&mut self, sender: SyncSender<PackageInstall>, ident: PackageIdent) {
         outputln!("Updating from {} to {}", self.current, ident);
-        loop {
+        while !self.stopped.load(Ordering::Relaxed) {
             let next_check = SteadyTime::now() +
                              TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
             match self.install(&ident, true) {
                 Ok(package) => {
                     self.current = package.ident().clone();
-                    sender.send(package).expect("Main thread has gone away!");
+                    self.send(&sender, package);
                     break;
                 }
                 Err(e) => warn!("Failed to install updated package: {:?}", e),
@@ -328,18 +613,21 @@ impl Worker {
     }
 
     fn run_poll(&mut self, sender: SyncSender<PackageInstall>) {
-        loop {
+        while !self.stopped.load(Ordering::Relaxed) {
             let next_check = SteadyTime::now() +
                              TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
-            match self.depot.show_package(&self.spec_ident) {
-                Ok(remote) => {
-                    let latest: PackageIdent = remote.get_ident().clone().into();
-                    if latest > self.current {
+            match util::pkg::latest_ident(&self.depot,
+                                          &self.spec_ident,
+                                          self.channel.as_ref().map(String::as_str)) {
+                Ok(latest) => {
+                    if self.failed_updates.contains(&latest) {
+                        info!("Package found previously failed to update, skipping it");
+                    } else if latest > self.current {
                         outputln!("Updating from {} to {}", self.current, latest);
                         match self.install(&latest, true) {
                             Ok(package) => {
                                 self.current = latest;
-                                sender.send(package).expect("Main thread has gone away!");
+                                self.send(&sender, package);
                                 break;
                             }
                             Err(e) => warn!("Failed to install updated package: {:?}", e),
@@ -357,6 +645,14 @@ impl Worker {
         }
     }
 
+    /// Hand an installed package to the main thread, unless the worker was stopped while looking
+    /// for it.
+    fn send(&self, sender: &SyncSender<PackageInstall>, package: PackageInstall) {
+        if sender.send(package).is_err() && !self.stopped.load(Ordering::Relaxed) {
+            panic!("Main thread has gone away!");
+        }
+    }
+
     fn install(&mut self, package: &PackageIdent, recurse: bool) -> Result<PackageInstall> {
         let package = match PackageInstall::load(package, Some(&*FS_ROOT_PATH)) {
             Ok(pkg) => pkg,
@@ -383,3 +679,35 @@ impl Worker {
         Ok(pkg)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use time::{Duration, SteadyTime};
+
+    use super::{TurnWait, UPDATE_TURN_TIMEOUT_MS};
+
+    #[test]
+    fn turn_wait_times_out_only_after_the_timeout() {
+        let pkg = PackageIdent::from_str("core/redis/3.2.9/20170602173812").unwrap();
+        let mut wait = TurnWait::default();
+        assert!(!wait.timed_out(&pkg));
+        assert!(!wait.timed_out(&pkg));
+
+        wait.0 = Some((pkg.clone(),
+                       SteadyTime::now() - Duration::milliseconds(UPDATE_TURN_TIMEOUT_MS)));
+        assert!(wait.timed_out(&pkg));
+    }
+
+    #[test]
+    fn turn_wait_starts_over_for_another_package() {
+        let old = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let new = PackageIdent::from_str("core/redis/3.2.9/20170602173812").unwrap();
+        let mut wait = TurnWait::default();
+        wait.0 = Some((old, SteadyTime::now() - Duration::milliseconds(UPDATE_TURN_TIMEOUT_MS)));
+
+        assert!(!wait.timed_out(&new));
+    }
+}
@@ -26,7 +26,8 @@ mod unix;
 mod windows;
 
 #[cfg(unix)]
-pub use manager::signals::unix::{init, check_for_signal, send_signal, Signal};
+pub use manager::signals::unix::{init, check_for_signal, send_group_signal, send_signal, Signal};
 
 #[cfg(windows)]
-pub use manager::signals::windows::{init, check_for_signal, send_signal, Signal};
+pub use manager::signals::windows::{init, check_for_signal, send_group_signal, send_signal,
+                                     Signal};
@@ -126,3 +126,14 @@ pub fn send_signal(pid: u32, sig: u32) -> Result<()> {
         }
     }
 }
+
+/// send a Unix signal to every process in the process group led by a pid
+pub fn send_group_signal(pgid: u32, sig: u32) -> Result<()> {
+    debug!("sending signal {} to process group {}", sig, pgid);
+    unsafe {
+        match kill(-(pgid as i32), sig) {
+            0 => Ok(()),
+            _ => Err(sup_error!(Error::SignalFailed)),
+        }
+    }
+}
@@ -67,3 +67,9 @@ pub fn send_signal(pid: u32, sig: u32) -> Result<()> {
     debug!("sending no-op(windows) signal {} to pid {}", sig, pid);
     Ok(())
 }
+
+/// send a signal to a process group
+pub fn send_group_signal(pgid: u32, sig: u32) -> Result<()> {
+    debug!("sending no-op(windows) signal {} to process group {}", sig, pgid);
+    Ok(())
+}
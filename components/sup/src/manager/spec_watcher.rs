@@ -36,6 +36,7 @@ const SPEC_FILE_GLOB: &'static str = "*.spec.toml";
 pub enum SpecWatcherEvent {
     AddService(ServiceSpec),
     RemoveService(ServiceSpec),
+    ChangeDesiredState(ServiceSpec),
 }
 
 pub struct SpecWatcher {
@@ -152,13 +153,22 @@ impl SpecWatcher {
             events.push(event);
         }
 
-        // Ensure each running service doesn't have a different spec on disk. If a difference is
-        // found we're going to do the simple thing and remove, then add the service. In the future
-        // we should attempt to update a service in-place, if possible.
+        // Ensure each running service doesn't have a different spec on disk. If only the desired
+        // state differs the service is brought up or down in place, otherwise we're going to do
+        // the simple thing and remove, then add the service. In the future we should attempt to
+        // update a service in-place, if possible.
         for name in active_names.intersection(&desired_names) {
             let active_spec = active_specs.remove(name).expect("value should exist for key");
             let desired_spec = desired_specs.remove(name).expect("value should exist for key");
-            if active_spec != desired_spec {
+            if active_spec.desired_state != desired_spec.desired_state &&
+               active_spec ==
+               ServiceSpec { desired_state: active_spec.desired_state, ..desired_spec.clone() } {
+                let event = SpecWatcherEvent::ChangeDesiredState(desired_spec);
+                debug!("Service spec for {} has a new desired state, enqueuing {:?} event",
+                       &name,
+                       &event);
+                events.push(event);
+            } else if active_spec != desired_spec {
                 let remove_event = SpecWatcherEvent::RemoveService(active_spec);
                 let add_event = SpecWatcherEvent::AddService(desired_spec);
                 debug!("Service spec for {} is different on disk than loaded state, \
@@ -275,7 +285,7 @@ mod test {
 
     use super::{SpecWatcher, SpecWatcherEvent};
     use error::Error::*;
-    use manager::service::ServiceSpec;
+    use manager::service::{DesiredState, ServiceSpec};
 
     #[test]
     fn run_watch_dir_not_created() {
@@ -446,6 +456,25 @@ mod test {
         assert_eq!(events[1], SpecWatcherEvent::AddService(transformer_after));
     }
 
+    #[test]
+    fn new_events_changed_desired_state_with_active_specs() {
+        let tmpdir = TempDir::new("fixture").unwrap();
+        let path = tmpdir.path().join("changed_desired_state");
+        fs::create_dir(&path).unwrap();
+        new_saved_spec(&path, "acme/alpha");
+        new_saved_spec(&path, "acme/transformer");
+        let mut transformer_after = new_spec("acme/transformer");
+        transformer_after.desired_state = DesiredState::Down;
+
+        let active_specs = map_for_specs(vec!["acme/alpha", "acme/transformer"]);
+        let mut watcher = SpecWatcher::run_with::<TestWatcher, _>(&path).unwrap();
+        let events = waiting_for_new_events(&mut watcher, active_specs);
+
+        assert_eq!(1, events.len());
+        assert_eq!(events[0],
+                   SpecWatcherEvent::ChangeDesiredState(transformer_after));
+    }
+
     #[test]
     fn new_events_crazytown_with_active_specs() {
         let tmpdir = TempDir::new("fixture").unwrap();
@@ -550,6 +579,14 @@ mod test {
             spec.to_file(&toml_path).expect("couldn't write spec file");
             self.tx.send(notify::DebouncedEvent::Write(toml_path)).expect("couldn't send event");
         }
+
+        fn behavior_changed_desired_state<P: AsRef<Path>>(&mut self, path: P) {
+            let toml_path = path.as_ref().join("transformer.spec.toml");
+            let mut spec = ServiceSpec::from_file(&toml_path).expect("couldn't load spec file");
+            spec.desired_state = DesiredState::Down;
+            spec.to_file(&toml_path).expect("couldn't write spec file");
+            self.tx.send(notify::DebouncedEvent::Write(toml_path)).expect("couldn't send event");
+        }
     }
 
     impl notify::Watcher for TestWatcher {
@@ -576,6 +613,7 @@ mod test {
                     self.behavior_removed_spec(path.as_ref());
                 }
                 "changed_spec" => self.behavior_changed_spec(path.as_ref()),
+                "changed_desired_state" => self.behavior_changed_desired_state(path.as_ref()),
                 "crazytown" => {
                     self.behavior_changed_spec(path.as_ref());
                     self.behavior_new_spec(path.as_ref());
@@ -20,22 +20,73 @@
 //! where the output was generated within the Supervisor. Also supported is a `preamble`, which is
 //! used to denote when output comes from a running service rather than the Supervisor itself.
 //!
-//! The `StructuredOutput` struct supports two global options - verbosity and coloring. If verbose
-//! is turned on, then every line printed is annotated with its preamble, logkey, and precise
-//! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
-//! on the tin :)
+//! The `StructuredOutput` struct supports three global options - verbosity, coloring, and the
+//! log format. If verbose is turned on, then every line printed is annotated with its preamble,
+//! logkey, and precise location. Without verbose, it prints simply the preamble and logkey.
+//! Coloring does what it says on the tin :) The JSON log format prints every line as a JSON
+//! object instead, for log shippers which can't parse the preamble; coloring doesn't apply to it.
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
+use std::result;
+use std::str::FromStr;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use serde_json;
+use time;
 
+use error::{Error, SupError};
 use PROGRAM_NAME;
 
+static LOGKEY: &'static str = "SOT";
+
 static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// How each line of output is formatted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogFormat {
+    /// The preamble and logkey, followed by the content.
+    Text,
+    /// A JSON object with timestamp, service_group, logkey, stream, and message fields.
+    Json,
+}
+
+impl LogFormat {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = SupError;
+
+    fn from_str(format: &str) -> result::Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(sup_error!(Error::InvalidLogFormat(String::from(format)))),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for LogFormat {
+    fn default() -> LogFormat {
+        LogFormat::Text
+    }
+}
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -67,8 +118,26 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// The current log format.
+pub fn log_format() -> LogFormat {
+    unsafe {
+        if JSON.load(Ordering::Relaxed) {
+            LogFormat::Json
+        } else {
+            LogFormat::Text
+        }
+    }
+}
+
+/// Set the log format.
+pub fn set_log_format(format: LogFormat) {
+    unsafe {
+        JSON.store(format == LogFormat::Json, Ordering::Relaxed);
+    }
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
-/// to print.
+/// to print, and the stream the content came from.
 pub struct StructuredOutput<'a> {
     preamble: &'a str,
     logkey: &'static str,
@@ -76,8 +145,22 @@ pub struct StructuredOutput<'a> {
     file: &'static str,
     column: u32,
     content: &'a str,
+    pub stream: &'static str,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub format: Option<LogFormat>,
+}
+
+/// A line of output in the JSON log format.
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    timestamp: String,
+    service_group: Option<&'a str>,
+    logkey: &'static str,
+    stream: &'static str,
+    message: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -96,18 +179,46 @@ impl<'a> StructuredOutput<'a> {
             file: file,
             column: column,
             content: content,
+            stream: "stdout",
             verbose: None,
             color: None,
+            format: None,
+        }
+    }
+
+    fn fmt_json(&self, f: &mut fmt::Formatter, verbose: bool) -> fmt::Result {
+        // Output from the Supervisor itself doesn't belong to a service group.
+        let service_group = if self.preamble == PROGRAM_NAME.as_str() {
+            None
+        } else {
+            Some(self.preamble)
+        };
+        let location = if verbose {
+            Some(format!("{}:{}:{}", self.file, self.line, self.column))
+        } else {
+            None
+        };
+        let json = JsonOutput {
+            timestamp: format!("{}", time::now_utc().rfc3339()),
+            service_group: service_group,
+            logkey: self.logkey,
+            stream: self.stream,
+            message: self.content.trim_right_matches(&['\n', '\r'][..]),
+            location: location,
+        };
+        match serde_json::to_string(&json) {
+            Ok(line) => write!(f, "{}", line),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
 
-// If we ever want to create multiple output formats in the future, we would do it here -
-// essentially create a flag we check to see what output you want, then call a different formatting
-// function. Viola!
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let verbose = self.verbose.unwrap_or(is_verbose());
+        if self.format.unwrap_or(log_format()) == LogFormat::Json {
+            return self.fmt_json(f, verbose);
+        }
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
             Cyan
@@ -151,8 +262,11 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::StructuredOutput;
+    use std::str::FromStr;
+
+    use super::{LogFormat, StructuredOutput};
     use ansi_term::Colour::{White, Cyan};
+    use serde_json::{self, Value};
 
     use PROGRAM_NAME;
 
@@ -189,4 +303,37 @@ mod tests {
                            Cyan.paint(progname),
                            White.bold().paint("SOT")));
     }
+
+    #[test]
+    fn log_format_from_str() {
+        assert_eq!(LogFormat::from_str("text").unwrap(), LogFormat::Text);
+        assert_eq!(LogFormat::from_str("json").unwrap(), LogFormat::Json);
+        assert!(LogFormat::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn format_json() {
+        let mut so = so("redis.default", "opeth is amazing\n");
+        so.stream = "stderr";
+        so.verbose = Some(false);
+        so.color = Some(true);
+        so.format = Some(LogFormat::Json);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(json["service_group"].as_str(), Some("redis.default"));
+        assert_eq!(json["logkey"].as_str(), Some("SOT"));
+        assert_eq!(json["stream"].as_str(), Some("stderr"));
+        assert_eq!(json["message"].as_str(), Some("opeth is amazing"));
+        assert!(json["timestamp"].is_string());
+        assert!(!json.as_object().unwrap().contains_key("location"));
+    }
+
+    #[test]
+    fn format_json_for_the_supervisor() {
+        let mut so = so(PROGRAM_NAME.as_str(), "opeth is amazing");
+        so.verbose = Some(true);
+        so.format = Some(LogFormat::Json);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert!(json["service_group"].is_null());
+        assert_eq!(json["location"].as_str(), Some(&*format!("{}:1:2", file!())));
+    }
 }
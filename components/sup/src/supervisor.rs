@@ -19,37 +19,95 @@
 /// If the process dies, the supervisor will restart it.
 
 use std;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{self, BufReader};
 use std::io::prelude::*;
-use std::path::PathBuf;
-use std::process::Child;
+use std::path::{Path, PathBuf};
 use std::result;
-use std::sync::{Arc, RwLock};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 
 use hcore::os::process::{HabChild, ExitStatusExt};
 use hcore::util::perm::set_owner;
 use hcore::package::PackageInstall;
 use hcore::service::ServiceGroup;
-use serde::{Serialize, Serializer};
+use hcore::util::deserialize_using_from_str;
+#[cfg(unix)]
+use libc;
+use prometheus::{CounterVec, GaugeVec};
+use serde::{self, Serialize, Serializer};
 use serde::ser::SerializeStruct;
-use time::SteadyTime;
+use time::{Duration, SteadyTime};
 
-use error::{Result, Error};
+use error::{Result, Error, SupError};
 use fs;
+#[cfg(unix)]
+use manager::signals;
+use output::{self, LogFormat, StructuredOutput};
 use util;
+use util::cgroup::{Cgroup, Usage, CGROUP_MOUNT};
+use util::log_file::LogFile;
 
 static LOGKEY: &'static str = "SV";
 
+/// How many of the most recent lines of each stream of a service's process are kept in memory.
+pub const PROCESS_OUTPUT_LINES: usize = 100;
+
+lazy_static! {
+    static ref RESTART_COUNTER: CounterVec = register_counter_vec!(
+        opts!(
+            "service_restarts_total",
+            "Total number of times a service process has been restarted."),
+        &["service"]).unwrap();
+
+    static ref LAST_EXIT_STATUS_GAUGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_last_exit_status",
+            "Exit status of the last service process to exit."),
+        &["service"]).unwrap();
+
+    static ref MEMORY_GAUGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_memory_bytes",
+            "Memory used by a service with resource limits, in bytes."),
+        &["service"]).unwrap();
+
+    static ref MEMORY_LIMIT_GAUGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_memory_limit_bytes",
+            "Memory limit of a service, in bytes."),
+        &["service"]).unwrap();
+
+    static ref CPU_SECONDS_GAUGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_cpu_seconds",
+            "CPU time used by a service with resource limits, in seconds."),
+        &["service"]).unwrap();
+
+    static ref PIDS_GAUGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_pids",
+            "Processes and threads run by a service with resource limits."),
+        &["service"]).unwrap();
+
+    static ref PIDS_LIMIT_GAUGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_pids_limit",
+            "Limit on the processes and threads run by a service."),
+        &["service"]).unwrap();
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub enum ProcessState {
     Down,
     Up,
     Start,
     Restart,
+    Backoff,
+    Failed,
 }
 
 impl fmt::Display for ProcessState {
@@ -59,20 +117,368 @@ impl fmt::Display for ProcessState {
             &ProcessState::Up => "up",
             &ProcessState::Start => "start",
             &ProcessState::Restart => "restart",
+            &ProcessState::Backoff => "backoff",
+            &ProcessState::Failed => "failed",
         };
         write!(f, "{}", state)
     }
 }
 
+/// When the Supervisor should restart a service's process after it exits.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl RestartPolicy {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RestartPolicy::Always => "always",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Never => "never",
+        }
+    }
+}
+
+impl FromStr for RestartPolicy {
+    type Err = SupError;
+
+    fn from_str(policy: &str) -> result::Result<Self, Self::Err> {
+        match policy {
+            "always" => Ok(RestartPolicy::Always),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "never" => Ok(RestartPolicy::Never),
+            _ => Err(sup_error!(Error::InvalidRestartPolicy(String::from(policy)))),
+        }
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
+impl serde::Deserialize for RestartPolicy {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for RestartPolicy {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Governs how, and how often, a service's process is restarted after it exits.
+///
+/// Restarts are delayed by an exponential backoff, starting at `backoff` seconds and doubling for
+/// each restart within `window` seconds, up to `backoff_max` seconds. If more than
+/// `max_restarts` restarts happen within the window the service is considered to be crash
+/// looping and is marked as failed. A `max_restarts` of 0 never gives up.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RestartConfig {
+    pub policy: RestartPolicy,
+    pub max_restarts: u32,
+    pub window: u64,
+    pub backoff: u64,
+    pub backoff_max: u64,
+}
+
+impl Default for RestartConfig {
+    fn default() -> RestartConfig {
+        RestartConfig {
+            policy: RestartPolicy::default(),
+            max_restarts: 10,
+            window: 300,
+            backoff: 1,
+            backoff_max: 60,
+        }
+    }
+}
+
+impl RestartConfig {
+    /// Returns the backoff delay, in seconds, before the nth restart within the window.
+    pub fn backoff_for(&self, restarts: u32) -> u64 {
+        let exp = restarts.saturating_sub(1).min(32);
+        self.backoff.saturating_mul(1u64 << exp).min(self.backoff_max)
+    }
+}
+
+/// The signal sent to a service's process to ask it to shut down.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ShutdownSignal {
+    Hup,
+    Int,
+    Quit,
+    Term,
+    Usr1,
+    Usr2,
+}
+
+impl ShutdownSignal {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShutdownSignal::Hup => "HUP",
+            ShutdownSignal::Int => "INT",
+            ShutdownSignal::Quit => "QUIT",
+            ShutdownSignal::Term => "TERM",
+            ShutdownSignal::Usr1 => "USR1",
+            ShutdownSignal::Usr2 => "USR2",
+        }
+    }
+
+    #[cfg(unix)]
+    pub fn as_signal(&self) -> u32 {
+        let signal = match *self {
+            ShutdownSignal::Hup => libc::SIGHUP,
+            ShutdownSignal::Int => libc::SIGINT,
+            ShutdownSignal::Quit => libc::SIGQUIT,
+            ShutdownSignal::Term => libc::SIGTERM,
+            ShutdownSignal::Usr1 => libc::SIGUSR1,
+            ShutdownSignal::Usr2 => libc::SIGUSR2,
+        };
+        signal as u32
+    }
+
+    /// Windows processes are always asked to shut down with a ctrl-c event.
+    #[cfg(windows)]
+    pub fn as_signal(&self) -> u32 {
+        0
+    }
+}
+
+impl FromStr for ShutdownSignal {
+    type Err = SupError;
+
+    fn from_str(signal: &str) -> result::Result<Self, Self::Err> {
+        let name = if signal.starts_with("SIG") {
+            &signal[3..]
+        } else {
+            signal
+        };
+        match name {
+            "HUP" => Ok(ShutdownSignal::Hup),
+            "INT" => Ok(ShutdownSignal::Int),
+            "QUIT" => Ok(ShutdownSignal::Quit),
+            "TERM" => Ok(ShutdownSignal::Term),
+            "USR1" => Ok(ShutdownSignal::Usr1),
+            "USR2" => Ok(ShutdownSignal::Usr2),
+            _ => Err(sup_error!(Error::InvalidShutdownSignal(String::from(signal)))),
+        }
+    }
+}
+
+impl fmt::Display for ShutdownSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> ShutdownSignal {
+        ShutdownSignal::Term
+    }
+}
+
+impl serde::Deserialize for ShutdownSignal {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for ShutdownSignal {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Governs how a service's process is shut down: `signal` is sent first and, if the process
+/// hasn't exited after `timeout` seconds, it is forcefully killed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShutdownConfig {
+    pub signal: ShutdownSignal,
+    pub timeout: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> ShutdownConfig {
+        ShutdownConfig {
+            signal: ShutdownSignal::default(),
+            timeout: 8,
+        }
+    }
+}
+
+/// Governs the log files a service's process writes to its logs directory, in addition to the
+/// Supervisor's output. A log file is rotated once it would grow past `max_bytes`, or once it is
+/// older than `max_age` seconds; a limit of 0 disables it. `keep` rotated files are kept.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LogConfig {
+    pub max_bytes: u64,
+    pub max_age: u64,
+    pub keep: u32,
+}
+
+impl Default for LogConfig {
+    fn default() -> LogConfig {
+        LogConfig {
+            max_bytes: 10 * 1024 * 1024,
+            max_age: 0,
+            keep: 5,
+        }
+    }
+}
+
+impl LogConfig {
+    /// Open the log file of a service's `stream`, in the service's logs directory.
+    pub fn open(&self, service_name: &str, stream: &str) -> io::Result<LogFile> {
+        LogFile::open(fs::svc_logs_path(service_name).join(format!("{}.log", stream)),
+                      self.max_bytes,
+                      self.max_age,
+                      self.keep)
+    }
+}
+
+/// Limits on the resources a service's process and its children may use, enforced with cgroups on
+/// Linux. `memory_max` is in bytes, `cpu_shares` is the process's weight relative to other
+/// services when the CPUs are busy, `cpu_quota` caps its CPU time as a percentage of one CPU (200
+/// allows two full CPUs), and `pids_max` caps how many processes and threads it may run. Unset
+/// limits don't apply.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Limits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_max: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pids_max: Option<u64>,
+}
+
+impl Limits {
+    /// Returns true if no limit is set.
+    pub fn is_empty(&self) -> bool {
+        self.memory_max.is_none() && self.cpu_shares.is_none() && self.cpu_quota.is_none() &&
+        self.pids_max.is_none()
+    }
+}
+
+/// A stream of output from a service's process.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl OutputStream {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            OutputStream::Stdout => "stdout",
+            OutputStream::Stderr => "stderr",
+        }
+    }
+
+    /// The log key the stream's lines are printed with.
+    pub fn logkey(&self) -> &'static str {
+        match *self {
+            OutputStream::Stdout => "O",
+            OutputStream::Stderr => "E",
+        }
+    }
+}
+
+impl fmt::Display for OutputStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The most recent lines a service's process wrote to each stream. They are kept across restarts,
+/// so the output of a process which crashed can still be read.
+#[derive(Debug, Default)]
+pub struct ProcessOutput {
+    stdout: Mutex<VecDeque<String>>,
+    stderr: Mutex<VecDeque<String>>,
+}
+
+impl ProcessOutput {
+    /// Keep a line of output, forgetting the oldest line of the stream if it has
+    /// `PROCESS_OUTPUT_LINES` already.
+    pub fn push(&self, stream: OutputStream, line: &str) {
+        let mut lines = self.lines_of(stream).lock().expect("Process output lock poisoned");
+        if lines.len() >= PROCESS_OUTPUT_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line.to_string());
+    }
+
+    /// The most recent lines of a stream, oldest first.
+    pub fn lines(&self, stream: OutputStream) -> Vec<String> {
+        self.lines_of(stream)
+            .lock()
+            .expect("Process output lock poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    fn lines_of(&self, stream: OutputStream) -> &Mutex<VecDeque<String>> {
+        match stream {
+            OutputStream::Stdout => &self.stdout,
+            OutputStream::Stderr => &self.stderr,
+        }
+    }
+}
+
+impl Serialize for ProcessOutput {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("process_output", 2));
+        try!(strukt.serialize_field("stdout", &self.lines(OutputStream::Stdout)));
+        try!(strukt.serialize_field("stderr", &self.lines(OutputStream::Stderr)));
+        strukt.end()
+    }
+}
+
 /// Additional params used to start the Supervisor.
 /// These params are outside the scope of what is in
 /// Supervisor.package.ident, and aren't runtime params that are stored
 /// in the top-level Supervisor struct (such as PID etc)
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RuntimeConfig {
     pub svc_user: String,
     pub svc_group: String,
+    pub supplementary_groups: Vec<String>,
+    pub capabilities: Vec<String>,
     pub env_vars: HashMap<String, String>,
+    /// Kept apart from `env_vars` so that they are never serialized.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub secret_env_vars: HashMap<String, String>,
+    /// The cgroup enforcing the service's resource limits, which its process and hooks join
+    /// before they exec.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub cgroup: Option<Cgroup>,
 }
 
 impl RuntimeConfig {
@@ -83,7 +489,11 @@ impl RuntimeConfig {
         RuntimeConfig {
             svc_user: svc_user,
             svc_group: svc_group,
+            supplementary_groups: Vec::new(),
+            capabilities: Vec::new(),
             env_vars: env_vars,
+            secret_env_vars: HashMap::new(),
+            cgroup: None,
         }
     }
 }
@@ -93,7 +503,11 @@ impl Default for RuntimeConfig {
         RuntimeConfig {
             svc_user: util::users::DEFAULT_USER.to_string(),
             svc_group: util::users::DEFAULT_GROUP.to_string(),
+            supplementary_groups: Vec::new(),
+            capabilities: Vec::new(),
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
+            cgroup: None,
         }
     }
 }
@@ -107,12 +521,32 @@ pub struct Supervisor {
     pub state_entered: SteadyTime,
     pub has_started: bool,
     pub runtime_config: RuntimeConfig,
+    pub restart_config: RestartConfig,
+    pub shutdown_config: ShutdownConfig,
+    pub log_config: Option<LogConfig>,
+    /// The open log file of each output stream, shared by every process the service starts so
+    /// that only one handle ever writes to, and rotates, each file.
+    log_files: HashMap<String, Arc<Mutex<LogFile>>>,
+    pub limits: Limits,
+    pub output: Arc<ProcessOutput>,
+    pub restart_count: u64,
+    pub last_exit_status: Option<u32>,
+    restart_at: Option<SteadyTime>,
+    restart_history: VecDeque<SteadyTime>,
+    stop_deadline: Option<SteadyTime>,
+    exited: bool,
+    cgroup: Cgroup,
 }
 
 impl Supervisor {
     pub fn new(package: Arc<RwLock<PackageInstall>>,
                service_group: &ServiceGroup,
-               runtime_config: RuntimeConfig)
+               runtime_config: RuntimeConfig,
+               restart_config: RestartConfig,
+               shutdown_config: ShutdownConfig,
+               log_config: Option<LogConfig>,
+               limits: Limits,
+               cgroup_root: &Path)
                -> Supervisor {
         Supervisor {
             child: None,
@@ -122,6 +556,19 @@ impl Supervisor {
             state_entered: SteadyTime::now(),
             has_started: false,
             runtime_config: runtime_config,
+            restart_config: restart_config,
+            shutdown_config: shutdown_config,
+            log_config: log_config,
+            log_files: HashMap::new(),
+            limits: limits,
+            output: Arc::new(ProcessOutput::default()),
+            restart_count: 0,
+            last_exit_status: None,
+            restart_at: None,
+            restart_history: VecDeque::new(),
+            stop_deadline: None,
+            exited: false,
+            cgroup: Cgroup::new(CGROUP_MOUNT, cgroup_root.join(service_group.service())),
         }
     }
 
@@ -137,7 +584,7 @@ impl Supervisor {
                              SteadyTime::now() - self.state_entered);
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::Backoff | ProcessState::Failed => false,
         };
         (healthy, status)
     }
@@ -155,6 +602,7 @@ impl Supervisor {
                       "Starting process as user={}, group={}",
                       &self.runtime_config.svc_user,
                       &self.runtime_config.svc_group);
+            try!(self.prepare_cgroup());
             self.enter_state(ProcessState::Start);
             let mut child = try!(try!(util::create_command(self.run_cmd(), &self.runtime_config))
                                      .spawn());
@@ -162,10 +610,12 @@ impl Supervisor {
             let hab_child = try!(HabChild::from(&mut child));
             self.child = Some(hab_child);
             try!(self.create_pidfile());
-            let package_name = self.preamble.clone();
-            try!(thread::Builder::new()
-                     .name(String::from("sup-service-read"))
-                     .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
+            if let Some(stdout) = child.stdout.take() {
+                try!(self.spawn_reader(stdout, OutputStream::Stdout));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                try!(self.spawn_reader(stderr, OutputStream::Stderr));
+            }
             self.enter_state(ProcessState::Up);
             self.has_started = true;
         } else {
@@ -174,12 +624,18 @@ impl Supervisor {
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Send the shutdown signal to a process, wait for the shutdown timeout, then send SIGKILL
     pub fn stop(&mut self) -> Result<()> {
+        self.stop_deadline = None;
+        let signal = self.shutdown_config.signal;
+        let timeout = Duration::seconds(self.shutdown_config.timeout as i64);
         match self.child {
             Some(ref mut child) => {
-                outputln!(preamble & self.preamble, "Stopping...");
-                let shutdown = try!(child.kill());
+                outputln!(preamble & self.preamble,
+                          "Stopping with SIG{}, timeout {}s...",
+                          signal,
+                          self.shutdown_config.timeout);
+                let shutdown = try!(child.terminate(signal.as_signal(), timeout));
                 outputln!("{} - Shutdown method: {}", self.preamble, shutdown);
             }
             None => {}
@@ -204,59 +660,269 @@ impl Supervisor {
         }
     }
 
+    /// Send the shutdown signal to a process without waiting for it to exit. `check_process`
+    /// reaps the process once it has exited, and sends SIGKILL if it is still running after the
+    /// shutdown timeout.
+    #[cfg(unix)]
+    fn begin_stop(&mut self) -> Result<()> {
+        if self.stop_deadline.is_some() {
+            return Ok(());
+        }
+        if let Some(ref child) = self.child {
+            outputln!(preamble self.preamble,
+                      "Stopping with SIG{}, timeout {}s...",
+                      self.shutdown_config.signal,
+                      self.shutdown_config.timeout);
+            try!(signals::send_signal(child.id(), self.shutdown_config.signal.as_signal()));
+            self.stop_deadline = Some(SteadyTime::now() +
+                                      Duration::seconds(self.shutdown_config.timeout as i64));
+        }
+        Ok(())
+    }
+
+    /// A ctrl-c event can't be aimed at a single process, so Windows processes are stopped by
+    /// waiting for them.
+    #[cfg(windows)]
+    fn begin_stop(&mut self) -> Result<()> {
+        self.stop()
+    }
+
+    /// Take the process down, waiting for it to exit.
     pub fn down(&mut self) -> Result<()> {
         self.enter_state(ProcessState::Down);
-        try!(self.stop());
-        self.cleanup_pidfile();
-        Ok(())
+        self.stop()
     }
 
+    /// Start taking the process down without waiting for it to exit.
+    pub fn begin_down(&mut self) -> Result<()> {
+        self.enter_state(ProcessState::Down);
+        self.begin_stop()
+    }
+
+    /// Restart the process. A running process is first asked to stop, and is started again once
+    /// it has exited, when the restart becomes due.
     pub fn restart(&mut self) -> Result<()> {
         self.enter_state(ProcessState::Restart);
-        try!(self.stop());
-        try!(self.start());
-        Ok(())
+        if self.child.is_some() {
+            self.begin_stop()
+        } else {
+            match self.start() {
+                Ok(()) => Ok(()),
+                Err(err) => {
+                    self.schedule_restart(None);
+                    Err(err)
+                }
+            }
+        }
+    }
+
+    /// Returns true, once, after the process has exited for any reason: it crashed, or it was
+    /// taken down or restarted.
+    pub fn take_exited(&mut self) -> bool {
+        let exited = self.exited;
+        self.exited = false;
+        exited
+    }
+
+    /// Returns true if the process isn't running but should be (re)started now. A process which
+    /// is backing off is only due once its backoff delay has elapsed, and a failed process is
+    /// never due.
+    pub fn is_restart_due(&self) -> bool {
+        match self.state {
+            ProcessState::Backoff => {
+                self.restart_at.map(|at| SteadyTime::now() >= at).unwrap_or(true)
+            }
+            ProcessState::Up | ProcessState::Start | ProcessState::Restart => self.child.is_none(),
+            ProcessState::Down | ProcessState::Failed => false,
+        }
+    }
+
+    /// Forget about previous restarts, for example when the service is explicitly started again
+    /// after having failed.
+    pub fn reset_restarts(&mut self) {
+        self.restart_at = None;
+        self.restart_history.clear();
+        if let ProcessState::Failed = self.state {
+            self.enter_state(ProcessState::Down);
+        }
+    }
+
+    /// Decide what happens after the process exited on its own, given its exit status, by
+    /// consulting the restart policy. The process is either scheduled to restart after a
+    /// backoff delay, or marked as down or failed and left alone.
+    pub fn schedule_restart(&mut self, exit_status: Option<u32>) {
+        let failed = exit_status.map(|code| code != 0).unwrap_or(true);
+        let restart = match self.restart_config.policy {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => failed,
+            RestartPolicy::Never => false,
+        };
+        if !restart {
+            outputln!(preamble self.preamble,
+                      "Not restarting process; restart policy is {}",
+                      self.restart_config.policy);
+            if failed {
+                self.enter_state(ProcessState::Failed);
+            } else {
+                self.enter_state(ProcessState::Down);
+            }
+            return;
+        }
+
+        let now = SteadyTime::now();
+        let window = Duration::seconds(self.restart_config.window as i64);
+        while self.restart_history.front().map(|t| now - *t > window).unwrap_or(false) {
+            self.restart_history.pop_front();
+        }
+        let max_restarts = self.restart_config.max_restarts;
+        if max_restarts > 0 && self.restart_history.len() as u32 >= max_restarts {
+            outputln!(preamble self.preamble,
+                      "Process restarted {} times within {}s; crash loop detected, giving up",
+                      self.restart_history.len(),
+                      self.restart_config.window);
+            self.enter_state(ProcessState::Failed);
+            return;
+        }
+        self.restart_history.push_back(now);
+        self.restart_count += 1;
+        RESTART_COUNTER.with_label_values(&[self.preamble.as_str()]).inc();
+        let backoff = self.restart_config.backoff_for(self.restart_history.len() as u32);
+        outputln!(preamble self.preamble, "Restarting process in {}s", backoff);
+        self.restart_at = Some(now + Duration::seconds(backoff as i64));
+        self.enter_state(ProcessState::Backoff);
     }
 
     /// if the child process exists, check it's status via waitpid().
     pub fn check_process(&mut self) {
         let changed = match self.child {
-            None => false,
+            None => None,
             Some(ref mut child) => {
                 match child.status() {
-                    Ok(ref status) if status.no_status() => false,
+                    Ok(ref status) if status.no_status() => None,
                     Ok(ref status) => {
                         if status.code().is_some() {
                             outputln!("{} - process {} died with exit code {}",
                                       self.preamble,
                                       child.id(),
                                       status.code().unwrap());
+                            Some(status.code())
                         } else if status.signal().is_some() {
                             outputln!("{} - process {} died with signal {}",
                                       self.preamble,
                                       child.id(),
                                       status.signal().unwrap());
+                            // Follow the shell convention of reporting death by signal as 128+n
+                            Some(status.signal().map(|signal| 128 + signal))
+                        } else {
+                            Some(None)
                         }
-                        true
                     }
                     Err(e) => {
                         debug!("Error checking process status: {}, continuing", e);
-                        false
+                        None
                     }
                 }
             }
         };
-        if changed {
+        if let Some(exit_status) = changed {
+            self.stop_deadline = None;
+            self.exited = true;
+            self.cleanup_pidfile();
+            self.last_exit_status = exit_status;
+            if let Some(code) = exit_status {
+                LAST_EXIT_STATUS_GAUGE.with_label_values(&[self.preamble.as_str()])
+                    .set(code as f64);
+            }
             match self.state {
-                ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
+                ProcessState::Up | ProcessState::Start => {
+                    outputln!("{} - Service exited", self.preamble);
+                    self.child = None;
+                    self.schedule_restart(exit_status);
+                }
+                ProcessState::Restart => {
                     outputln!("{} - Service exited", self.preamble);
                     self.child = None;
                 }
-                ProcessState::Down => {
+                ProcessState::Down | ProcessState::Backoff | ProcessState::Failed => {
                     self.enter_state(ProcessState::Down);
                     self.child = None;
                 }
             }
+        } else {
+            self.kill_if_overdue();
+        }
+        self.update_usage_metrics();
+    }
+
+    /// Forcefully kill a process which is still running once its shutdown timeout has passed.
+    #[cfg(unix)]
+    fn kill_if_overdue(&mut self) {
+        if !self.stop_deadline.map(|at| SteadyTime::now() > at).unwrap_or(false) {
+            return;
+        }
+        if let Some(ref child) = self.child {
+            outputln!(preamble self.preamble,
+                      "Still running after {}s, killing process {}",
+                      self.shutdown_config.timeout,
+                      child.id());
+            if let Err(err) = signals::send_signal(child.id(), libc::SIGKILL as u32) {
+                outputln!(preamble self.preamble, "Failed to kill process: {}", err);
+            }
+        }
+        self.stop_deadline = None;
+    }
+
+    #[cfg(windows)]
+    fn kill_if_overdue(&mut self) {}
+
+    /// Set up the service's cgroup with its resource limits, for the process and hooks to join
+    /// when they start. Services without limits run without one, while a service whose limits
+    /// can't be enforced doesn't run at all.
+    pub fn prepare_cgroup(&mut self) -> Result<()> {
+        self.runtime_config.cgroup = None;
+        if self.limits.is_empty() {
+            return Ok(());
+        }
+        if !self.cgroup.is_supported() {
+            let err = format!("cgroups are not mounted at {}", CGROUP_MOUNT);
+            return Err(sup_error!(Error::ResourceLimits(err)));
+        }
+        if let Err(e) = self.cgroup.apply(&self.limits) {
+            return Err(sup_error!(Error::ResourceLimits(e.to_string())));
+        }
+        self.runtime_config.cgroup = Some(self.cgroup.clone());
+        Ok(())
+    }
+
+    /// How much of each resource the service uses, if it has resource limits.
+    pub fn usage(&self) -> Option<Usage> {
+        if self.limits.is_empty() || !self.cgroup.is_supported() {
+            None
+        } else {
+            Some(self.cgroup.usage())
+        }
+    }
+
+    fn update_usage_metrics(&self) {
+        let usage = match self.usage() {
+            Some(usage) => usage,
+            None => return,
+        };
+        let labels = &[self.preamble.as_str()];
+        if let Some(memory) = usage.memory_bytes {
+            MEMORY_GAUGE.with_label_values(labels).set(memory as f64);
+        }
+        if let Some(cpu) = usage.cpu_seconds {
+            CPU_SECONDS_GAUGE.with_label_values(labels).set(cpu);
+        }
+        if let Some(pids) = usage.pids {
+            PIDS_GAUGE.with_label_values(labels).set(pids as f64);
+        }
+        if let Some(memory_max) = self.limits.memory_max {
+            MEMORY_LIMIT_GAUGE.with_label_values(labels).set(memory_max as f64);
+        }
+        if let Some(pids_max) = self.limits.pids_max {
+            PIDS_LIMIT_GAUGE.with_label_values(labels).set(pids_max as f64);
         }
     }
 
@@ -273,11 +939,56 @@ impl Supervisor {
     }
 
     pub fn pid_file(&self) -> PathBuf {
-        fs::svc_pid_file(&self.package
-                              .read()
-                              .expect("Package lock poisoned")
-                              .ident()
-                              .name)
+        fs::svc_pid_file(self.service_name())
+    }
+
+    /// Read a stream of the process's output in its own thread, so that neither stream can fill
+    /// up and block the process while we wait on the other.
+    fn spawn_reader<R>(&mut self, reader: R, stream: OutputStream) -> Result<()>
+        where R: Read + Send + 'static
+    {
+        let package_name = self.preamble.clone();
+        let log_file = self.log_file(stream.as_str());
+        let output = self.output.clone();
+        try!(thread::Builder::new()
+                 .name(format!("sup-service-{}-{}", self.service_name(), stream))
+                 .spawn(move || -> Result<()> {
+                            child_reader(reader, stream, package_name, log_file, output)
+                        }));
+        Ok(())
+    }
+
+    /// The log file of the process's `stream`, if it has one, opening it the first time. A log
+    /// file which can't be opened is not fatal; the output is still printed, and opening it is
+    /// tried again when the process next starts.
+    fn log_file(&mut self, stream: &str) -> Option<Arc<Mutex<LogFile>>> {
+        if let Some(log_file) = self.log_files.get(stream) {
+            return Some(log_file.clone());
+        }
+        let opened = match self.log_config {
+            Some(ref log_config) => log_config.open(&self.service_name(), stream),
+            None => return None,
+        };
+        match opened {
+            Ok(log_file) => {
+                let log_file = Arc::new(Mutex::new(log_file));
+                self.log_files.insert(stream.to_string(), log_file.clone());
+                Some(log_file)
+            }
+            Err(e) => {
+                outputln!(preamble self.preamble, "Unable to open the {} log file: {}", stream, e);
+                None
+            }
+        }
+    }
+
+    fn service_name(&self) -> String {
+        self.package
+            .read()
+            .expect("Package lock poisoned")
+            .ident()
+            .name
+            .clone()
     }
 
     /// Create a pid file for a package
@@ -347,7 +1058,7 @@ impl Serialize for Supervisor {
             Some(ref child) => Some(child.id()),
             None => None,
         };
-        let mut strukt = try!(serializer.serialize_struct("supervisor", 7));
+        let mut strukt = try!(serializer.serialize_struct("supervisor", 14));
         try!(strukt.serialize_field("pid", &pid));
         try!(strukt.serialize_field("package",
                                     &self.package
@@ -359,6 +1070,13 @@ impl Serialize for Supervisor {
         try!(strukt.serialize_field("state_entered", &self.state_entered.to_string()));
         try!(strukt.serialize_field("started", &self.has_started));
         try!(strukt.serialize_field("runtime_config", &self.runtime_config));
+        try!(strukt.serialize_field("restart_config", &self.restart_config));
+        try!(strukt.serialize_field("shutdown_config", &self.shutdown_config));
+        try!(strukt.serialize_field("log_config", &self.log_config));
+        try!(strukt.serialize_field("limits", &self.limits));
+        try!(strukt.serialize_field("usage", &self.usage()));
+        try!(strukt.serialize_field("restart_count", &self.restart_count));
+        try!(strukt.serialize_field("last_exit_status", &self.last_exit_status));
         strukt.end()
     }
 }
@@ -366,25 +1084,279 @@ impl Serialize for Supervisor {
 impl Drop for Supervisor {
     fn drop(&mut self) {
         let _ = self.cleanup_pidfile();
+        if !self.limits.is_empty() && self.cgroup.is_supported() {
+            self.cgroup.remove();
+        }
     }
 }
 
-/// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
-        None => return Err(sup_error!(Error::UnpackFailed)),
-    };
+/// Consume a stream of output from a child process until EOF, then finish. Each line is printed
+/// with our structured output formatter, kept in the process's recent output and, if the service
+/// has a log file for the stream, written to it.
+fn child_reader<R: Read>(reader: R,
+                         stream: OutputStream,
+                         package_name: String,
+                         log_file: Option<Arc<Mutex<LogFile>>>,
+                         output: Arc<ProcessOutput>)
+                         -> Result<()> {
+    let mut reader = BufReader::new(reader);
+    let mut buffer = Vec::new();
 
-    let mut reader = BufReader::new(c_stdout);
-    let mut buffer = String::new();
-
-    while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
-        line.push_str(&buffer);
-        print!("{}", line);
+    loop {
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => break,
+            Ok(_) => (),
+            Err(e) => {
+                debug!("Error reading the {} of {}: {}", stream, package_name, e);
+                break;
+            }
+        }
+        {
+            // Services may write anything, so output which isn't UTF-8 is logged lossily rather
+            // than ending the reader.
+            let line = String::from_utf8_lossy(&buffer);
+            let content = line.trim_right_matches(&['\n', '\r'][..]);
+            let mut so = StructuredOutput::new(&package_name,
+                                               stream.logkey(),
+                                               line!(),
+                                               file!(),
+                                               column!(),
+                                               content);
+            so.stream = stream.as_str();
+            println!("{}", so);
+            output.push(stream, content);
+            if let Some(ref log_file) = log_file {
+                let mut log_file = log_file.lock().expect("Log file lock poisoned");
+                let written = match output::log_format() {
+                    LogFormat::Text => log_file.write_line(content),
+                    LogFormat::Json => log_file.write_line(&so.to_string()),
+                };
+                if let Err(e) = written {
+                    debug!("Error writing to the {} log file of {}: {}",
+                           stream,
+                           package_name,
+                           e);
+                }
+            }
+        }
         buffer.clear();
     }
-    debug!("child_reader exiting");
+    debug!("child_reader for {} exiting", stream);
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+    use std::sync::{Arc, RwLock};
+
+    use hcore::package::{PackageIdent, PackageInstall};
+    use hcore::service::ServiceGroup;
+    use time::{Duration, SteadyTime};
+
+    use error::Error::{InvalidRestartPolicy, InvalidShutdownSignal};
+    use super::{child_reader, Limits, OutputStream, ProcessOutput, ProcessState,
+                PROCESS_OUTPUT_LINES, RestartConfig, RestartPolicy, RuntimeConfig,
+                ShutdownConfig, ShutdownSignal, Supervisor};
+
+    fn supervisor(restart_config: RestartConfig) -> Supervisor {
+        let package =
+            PackageInstall::new_from_parts(PackageIdent::from_str("core/redis/3.2.4/20170101000000")
+                                               .unwrap(),
+                                           PathBuf::from("/"),
+                                           PathBuf::from("/fakeo"),
+                                           PathBuf::from("/fakeo/here"));
+        Supervisor::new(Arc::new(RwLock::new(package)),
+                        &ServiceGroup::new("redis", "default", None).unwrap(),
+                        RuntimeConfig::default(),
+                        restart_config,
+                        ShutdownConfig::default(),
+                        None,
+                        Limits::default(),
+                        Path::new("/hab/sup/test"))
+    }
+
+    /// The delay, in seconds, before the restart scheduled last.
+    fn scheduled_backoff(sup: &Supervisor) -> i64 {
+        (sup.restart_at.unwrap() - *sup.restart_history.back().unwrap()).num_seconds()
+    }
+
+    fn assert_state(expected: ProcessState, sup: &Supervisor) {
+        assert_eq!(expected.to_string(), sup.state.to_string());
+    }
+
+    #[test]
+    fn restart_policy_from_str() {
+        assert_eq!(RestartPolicy::Always, RestartPolicy::from_str("always").unwrap());
+        assert_eq!(RestartPolicy::OnFailure,
+                   RestartPolicy::from_str("on-failure").unwrap());
+        assert_eq!(RestartPolicy::Never, RestartPolicy::from_str("never").unwrap());
+    }
+
+    #[test]
+    fn restart_policy_from_str_invalid() {
+        match RestartPolicy::from_str("sometimes") {
+            Err(e) => {
+                match e.err {
+                    InvalidRestartPolicy(s) => assert_eq!("sometimes", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn restart_policy_default() {
+        assert_eq!(RestartPolicy::Always, RestartPolicy::default());
+    }
+
+    #[test]
+    fn restart_config_backoff_doubles_until_max() {
+        let cfg = RestartConfig {
+            backoff: 2,
+            backoff_max: 20,
+            ..RestartConfig::default()
+        };
+
+        assert_eq!(2, cfg.backoff_for(1));
+        assert_eq!(4, cfg.backoff_for(2));
+        assert_eq!(8, cfg.backoff_for(3));
+        assert_eq!(16, cfg.backoff_for(4));
+        assert_eq!(20, cfg.backoff_for(5));
+        assert_eq!(20, cfg.backoff_for(100));
+    }
+
+    #[test]
+    fn shutdown_signal_from_str() {
+        assert_eq!(ShutdownSignal::Term, ShutdownSignal::from_str("TERM").unwrap());
+        assert_eq!(ShutdownSignal::Term, ShutdownSignal::from_str("SIGTERM").unwrap());
+        assert_eq!(ShutdownSignal::Usr1, ShutdownSignal::from_str("USR1").unwrap());
+    }
+
+    #[test]
+    fn shutdown_signal_from_str_invalid() {
+        match ShutdownSignal::from_str("KILL") {
+            Err(e) => {
+                match e.err {
+                    InvalidShutdownSignal(s) => assert_eq!("KILL", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn shutdown_signal_default() {
+        assert_eq!(ShutdownSignal::Term, ShutdownSignal::default());
+    }
+
+    #[test]
+    fn process_output_keeps_streams_apart() {
+        let output = ProcessOutput::default();
+        output.push(OutputStream::Stdout, "listening");
+        output.push(OutputStream::Stderr, "oh no");
+        assert_eq!(output.lines(OutputStream::Stdout), vec!["listening".to_string()]);
+        assert_eq!(output.lines(OutputStream::Stderr), vec!["oh no".to_string()]);
+    }
+
+    #[test]
+    fn process_output_keeps_the_most_recent_lines() {
+        let output = ProcessOutput::default();
+        for n in 0..(PROCESS_OUTPUT_LINES + 2) {
+            output.push(OutputStream::Stderr, &n.to_string());
+        }
+        let lines = output.lines(OutputStream::Stderr);
+        assert_eq!(lines.len(), PROCESS_OUTPUT_LINES);
+        assert_eq!(lines[0], "2");
+        assert_eq!(lines[PROCESS_OUTPUT_LINES - 1],
+                   (PROCESS_OUTPUT_LINES + 1).to_string());
+    }
+
+    #[test]
+    fn schedule_restart_backs_off_exponentially_up_to_the_max() {
+        let mut sup = supervisor(RestartConfig {
+                                     backoff: 2,
+                                     backoff_max: 20,
+                                     max_restarts: 0,
+                                     ..RestartConfig::default()
+                                 });
+        for expected in &[2, 4, 8, 16, 20, 20] {
+            sup.schedule_restart(Some(1));
+            assert_state(ProcessState::Backoff, &sup);
+            assert_eq!(*expected, scheduled_backoff(&sup));
+        }
+        assert_eq!(6, sup.restart_count);
+    }
+
+    #[test]
+    fn schedule_restart_resets_the_backoff_after_a_stable_run() {
+        let mut sup = supervisor(RestartConfig {
+                                     backoff: 2,
+                                     backoff_max: 20,
+                                     window: 300,
+                                     ..RestartConfig::default()
+                                 });
+        // Restarts from before the process last ran for a whole window are forgotten.
+        let long_ago = SteadyTime::now() - Duration::seconds(301);
+        for _ in 0..3 {
+            sup.restart_history.push_back(long_ago);
+        }
+        sup.schedule_restart(Some(1));
+
+        assert_eq!(1, sup.restart_history.len());
+        assert_eq!(2, scheduled_backoff(&sup));
+    }
+
+    #[test]
+    fn schedule_restart_gives_up_on_a_crash_loop() {
+        let mut sup = supervisor(RestartConfig {
+                                     max_restarts: 3,
+                                     window: 300,
+                                     ..RestartConfig::default()
+                                 });
+        for _ in 0..3 {
+            sup.schedule_restart(Some(1));
+            assert_state(ProcessState::Backoff, &sup);
+        }
+        sup.schedule_restart(Some(1));
+
+        assert_state(ProcessState::Failed, &sup);
+        assert!(!sup.is_restart_due());
+        assert_eq!(3, sup.restart_count);
+    }
+
+    #[test]
+    fn schedule_restart_follows_the_restart_policy() {
+        let mut sup = supervisor(RestartConfig {
+                                     policy: RestartPolicy::OnFailure,
+                                     ..RestartConfig::default()
+                                 });
+        sup.schedule_restart(Some(0));
+        assert_state(ProcessState::Down, &sup);
+        sup.schedule_restart(Some(1));
+        assert_state(ProcessState::Backoff, &sup);
+
+        let mut sup = supervisor(RestartConfig {
+                                     policy: RestartPolicy::Never,
+                                     ..RestartConfig::default()
+                                 });
+        sup.schedule_restart(Some(1));
+        assert_state(ProcessState::Failed, &sup);
+    }
+
+    #[test]
+    fn child_reader_keeps_reading_past_invalid_utf8() {
+        let output = Arc::new(ProcessOutput::default());
+        child_reader(&b"before\n\xff after\r\nlast"[..],
+                     OutputStream::Stdout,
+                     "test".to_string(),
+                     None,
+                     output.clone())
+            .unwrap();
+        assert_eq!(output.lines(OutputStream::Stdout),
+                   vec!["before".to_string(), "\u{fffd} after".to_string(), "last".to_string()]);
+    }
+}
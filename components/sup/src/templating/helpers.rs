@@ -12,20 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
 use std::str::FromStr;
 use std::string::ToString;
-use std::collections::HashMap;
 
+use base64;
 use hcore::package::{PackageIdent, Identifiable};
 use hcore::fs;
 use manager::service::config::ServiceConfig;
-use handlebars::{Handlebars, Helper, Renderable, RenderContext, RenderError, Context};
-use serde_json;
+use handlebars::{Handlebars, Helper, Renderable, RenderContext, RenderError, Context, Template};
+use serde_json::{self, Value};
 use serde_json::map::Map;
 use toml;
 
 
 type RenderResult = Result<(), RenderError>;
+type CensusMembers<'a> = Vec<(&'a Value, &'a Map<String, Value>)>;
+
+/// The prefix of the environment variables templates may read.
+const ENV_VAR_PREFIX: &'static str = "HAB_";
 
 pub fn each_alive(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
     let value =
@@ -48,22 +57,7 @@ pub fn each_alive(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderR
             }));
         if member.contains_key("alive") && member["alive"].as_bool().unwrap_or(false) {
             debug!("Alive! {:?}", value_array[i]);
-            let mut map = HashMap::default();
-
-            // Check for those tricky people who use as |blah| inside their helpers.
-            // I'm looking at you Smith!
-            let local_context_data = match h.block_param() {
-                Some(name) => {
-                    let mut json_map = Map::new();
-                    json_map.insert(name.to_string(), value_array[i].clone());
-                    serde_json::Value::Object(json_map)
-                }
-                None => value_array[i].clone(),
-            };
-            let mut local_context = Context::wraps(&local_context_data);
-            let mut writer = rc.writer();
-            let mut local_rc = RenderContext::new(&mut local_context, &mut map, &mut writer);
-            try!(template.render(r, &mut local_rc));
+            try!(render_member(h, r, rc, template, &value_array[i]));
         } else {
             debug!("Dead! {:?}", value_array[i]);
         }
@@ -71,6 +65,170 @@ pub fn each_alive(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderR
     Ok(())
 }
 
+pub fn each_healthy(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    each_member_where("eachHealthy", h, r, rc, |m| is_alive(m) && is_healthy(m))
+}
+
+pub fn each_leader(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    each_member_where("eachLeader", h, r, rc, |m| is_alive(m) && flag(m, "leader"))
+}
+
+pub fn each_follower(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    each_member_where("eachFollower", h, r, rc, |m| is_alive(m) && flag(m, "follower"))
+}
+
+pub fn each_update_leader(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    each_member_where("eachUpdateLeader",
+                      h,
+                      r,
+                      rc,
+                      |m| is_alive(m) && flag(m, "update_leader"))
+}
+
+pub fn each_update_follower(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    each_member_where("eachUpdateFollower",
+                      h,
+                      r,
+                      rc,
+                      |m| is_alive(m) && flag(m, "update_follower"))
+}
+
+pub fn member_count(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let members = try!(census_members("memberCount", h));
+    let count = members.iter().filter(|&&(_, m)| is_alive(m)).count();
+    try!(rc.writer.write(count.to_string().into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn with_leader(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    first_member_where("withLeader", h, r, rc, |m| is_alive(m) && flag(m, "leader"))
+}
+
+pub fn with_update_leader(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    first_member_where("withUpdateLeader",
+                       h,
+                       r,
+                       rc,
+                       |m| is_alive(m) && flag(m, "update_leader"))
+}
+
+/// Render the helper's content once for every census member, in member ID order, matching the
+/// given filter. The helper's `else` content is rendered if no member matched.
+fn each_member_where<F>(name: &str,
+                        h: &Helper,
+                        r: &Handlebars,
+                        rc: &mut RenderContext,
+                        filter: F)
+                        -> RenderResult
+    where F: Fn(&Map<String, Value>) -> bool
+{
+    let template = try!(h.template().ok_or_else(|| {
+        RenderError::new(format!("No content to render inside the helper \"{}\"", name))
+    }));
+    let members = try!(census_members(name, h));
+    let mut rendered = false;
+    for &(value, _) in members.iter().filter(|&&(_, m)| filter(m)) {
+        try!(render_member(h, r, rc, template, value));
+        rendered = true;
+    }
+    if !rendered {
+        if let Some(inverse) = h.inverse() {
+            try!(inverse.render(r, rc));
+        }
+    }
+    Ok(())
+}
+
+/// Render the helper's content with the first census member, in member ID order, matching the
+/// given filter as its context. The helper's `else` content is rendered if no member matched.
+fn first_member_where<F>(name: &str,
+                         h: &Helper,
+                         r: &Handlebars,
+                         rc: &mut RenderContext,
+                         filter: F)
+                         -> RenderResult
+    where F: Fn(&Map<String, Value>) -> bool
+{
+    let template = try!(h.template().ok_or_else(|| {
+        RenderError::new(format!("No content to render inside the helper \"{}\"", name))
+    }));
+    let members = try!(census_members(name, h));
+    match members.iter().find(|&&(_, m)| filter(m)) {
+        Some(&(value, _)) => render_member(h, r, rc, template, value),
+        None => {
+            match h.inverse() {
+                Some(inverse) => inverse.render(r, rc),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+/// Returns the census members given as the helper's first parameter, ordered by member ID so
+/// that rendered configuration doesn't change when the census is gossiped in a different order.
+fn census_members<'a>(name: &str, h: &'a Helper) -> Result<CensusMembers<'a>, RenderError> {
+    let value = try!(h.param(0).ok_or_else(|| {
+        RenderError::new(format!("Param not found for helper \"{}\"", name))
+    }))
+        .value();
+    let mut members = Vec::new();
+    if let Some(values) = value.as_array() {
+        for value in values {
+            let member = try!(value.as_object().ok_or_else(|| {
+                RenderError::new(format!("Param value is not a valid census member.  Parameter \
+                                          content is: {:?}",
+                                         value))
+            }));
+            members.push((value, member));
+        }
+    }
+    members.sort_by(|&(_, a), &(_, b)| {
+        let a = a.get("member_id").and_then(|id| id.as_str()).unwrap_or("");
+        let b = b.get("member_id").and_then(|id| id.as_str()).unwrap_or("");
+        a.cmp(b)
+    });
+    Ok(members)
+}
+
+fn flag(member: &Map<String, Value>, key: &str) -> bool {
+    member.get(key).and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
+fn is_alive(member: &Map<String, Value>) -> bool {
+    flag(member, "alive")
+}
+
+/// Members which haven't gossiped their health are assumed to be healthy.
+fn is_healthy(member: &Map<String, Value>) -> bool {
+    member.get("healthy").and_then(|v| v.as_bool()).unwrap_or(true)
+}
+
+/// Render a block helper's content with a census member as its context, binding the member to
+/// the block parameter if one was given (ex: `{{#eachAlive members as |member|}}`).
+fn render_member(h: &Helper,
+                 r: &Handlebars,
+                 rc: &mut RenderContext,
+                 template: &Template,
+                 member: &Value)
+                 -> RenderResult {
+    let mut map = HashMap::default();
+
+    // Check for those tricky people who use as |blah| inside their helpers.
+    // I'm looking at you Smith!
+    let local_context_data = match h.block_param() {
+        Some(name) => {
+            let mut json_map = Map::new();
+            json_map.insert(name.to_string(), member.clone());
+            Value::Object(json_map)
+        }
+        None => member.clone(),
+    };
+    let mut local_context = Context::wraps(&local_context_data);
+    let mut writer = rc.writer();
+    let mut local_rc = RenderContext::new(&mut local_context, &mut map, &mut writer);
+    template.render(r, &mut local_rc)
+}
+
 pub fn pkg_path_for(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
     let param =
         try!(h.param(0)
@@ -150,3 +308,323 @@ pub fn to_toml(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResu
     try!(rc.writer.write_all(bytes.as_ref()));
     Ok(())
 }
+
+pub fn to_yaml(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param = try!(h.param(0)
+            .ok_or_else(|| RenderError::new("Expected 1 parameter for \"toYaml\"")))
+            .value();
+    let mut yaml = String::new();
+    write_yaml(&mut yaml, param, 0);
+    try!(rc.writer.write(yaml.into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn to_base64(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param = try!(str_param(h, 0, "toBase64", "Expected a string parameter"));
+    try!(rc.writer.write(base64::encode(param.as_bytes()).into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn to_hex(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param = try!(str_param(h, 0, "toHex", "Expected a string parameter"));
+    let hex: String = param.as_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+    try!(rc.writer.write(hex.into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn trim(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param = try!(str_param(h, 0, "trim", "Expected a string parameter"));
+    try!(rc.writer.write(param.trim().as_bytes()));
+    Ok(())
+}
+
+/// Renders the first parameter, or the second parameter if the first is missing, null or an
+/// empty string.
+pub fn default(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    if h.params().len() != 2 {
+        return Err(RenderError::new("Expected 2 parameters for \"default\""));
+    }
+    let value = h.param(0).unwrap().value();
+    let value = match *value {
+        Value::Null => h.param(1).unwrap().value(),
+        Value::String(ref s) if s.is_empty() => h.param(1).unwrap().value(),
+        _ => value,
+    };
+    try!(rc.writer.write(scalar_to_string(value).into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Renders its content if the first parameter, a string or an array, contains the second
+/// parameter and its `else` content otherwise.
+pub fn contains(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    if h.params().len() != 2 {
+        return Err(RenderError::new("Expected 2 parameters for \"contains\""));
+    }
+    let haystack = h.param(0).unwrap().value();
+    let needle = h.param(1).unwrap().value();
+    let found = match *haystack {
+        Value::String(ref s) => {
+            let needle = try!(needle.as_str().ok_or_else(|| {
+                RenderError::new("Expected a string to look for in a string for \"contains\"")
+            }));
+            s.contains(needle)
+        }
+        Value::Array(ref values) => values.contains(needle),
+        Value::Null => false,
+        _ => {
+            return Err(RenderError::new("Expected a string or an array as the first parameter \
+                                         for \"contains\""))
+        }
+    };
+    let template = if found { h.template() } else { h.inverse() };
+    match template {
+        Some(t) => t.render(r, rc),
+        None => Ok(()),
+    }
+}
+
+/// Renders its content once for each part of the first parameter split on the second. Each part
+/// is the context of the content, or bound to the block parameter if one was given.
+pub fn split(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let value = try!(str_param(h, 0, "split", "Expected 2 string parameters"));
+    let separator = try!(str_param(h, 1, "split", "Expected 2 string parameters"));
+    let template = try!(h.template()
+        .ok_or_else(|| RenderError::new("No content to render inside the helper \"split\"")));
+    for part in value.split(separator) {
+        try!(render_member(h, r, rc, template, &Value::String(part.to_string())));
+    }
+    Ok(())
+}
+
+/// Renders its parameters after the first, separated by the first.
+pub fn join(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let separator = try!(str_param(h,
+                                   0,
+                                   "join",
+                                   "Expected a separator and at least 1 more parameter"));
+    if h.params().len() < 2 {
+        return Err(RenderError::new("Expected a separator and at least 1 more parameter for \
+                                     \"join\""));
+    }
+    let parts: Vec<String> = h.params()[1..].iter().map(|p| scalar_to_string(p.value())).collect();
+    try!(rc.writer.write(parts.join(separator).into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Renders the elements of the array given as the first parameter, separated by the second.
+pub fn str_join(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let values = try!(h.param(0)
+        .and_then(|v| v.value().as_array())
+        .ok_or_else(|| {
+            RenderError::new("Expected an array and a string parameter for \"strJoin\"")
+        }));
+    let separator = try!(str_param(h, 1, "strJoin", "Expected an array and a string parameter"));
+    let parts: Vec<String> = values.iter().map(scalar_to_string).collect();
+    try!(rc.writer.write(parts.join(separator).into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn add(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h, rc, "add", |a, b| a.checked_add(b), |a, b| a + b)
+}
+
+pub fn sub(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h, rc, "sub", |a, b| a.checked_sub(b), |a, b| a - b)
+}
+
+pub fn mul(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h, rc, "mul", |a, b| a.checked_mul(b), |a, b| a * b)
+}
+
+/// Divides the first parameter by the second. When both are integers the result is an integer
+/// too, truncated towards zero; pass a float, ex: `2.0`, for a fractional result.
+pub fn div(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let divisor = try!(number_param(h, 1, "div"));
+    if divisor == 0.0 {
+        return Err(RenderError::new("Division by zero in \"div\""));
+    }
+    arithmetic(h, rc, "div", |a, b| a.checked_div(b), |a, b| a / b)
+}
+
+/// Renders the value of the environment variable named by the first parameter, or nothing if it
+/// isn't set. Only variables starting with `HAB_` can be read, so that templates can't pull
+/// arbitrary parts of the Supervisor's environment into configuration.
+pub fn env_var(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let name = try!(str_param(h, 0, "env", "Expected a string parameter"));
+    if !name.starts_with(ENV_VAR_PREFIX) {
+        return Err(RenderError::new(format!("\"env\" can only read variables starting with \
+                                             {}, not {}",
+                                            ENV_VAR_PREFIX,
+                                            name)));
+    }
+    if let Ok(value) = env::var(name) {
+        try!(rc.writer.write(value.into_bytes().as_ref()));
+    }
+    Ok(())
+}
+
+/// Renders the contents of the file at the path given as the first parameter. Only files in the
+/// service's package or service directory can be read, so that templates can't pull arbitrary
+/// files of the Supervisor's host into configuration.
+pub fn file_contents(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let path = try!(str_param(h, 0, "fileContents", "Expected a string parameter"));
+    let allowed = ["/pkg/path", "/pkg/svc_path"]
+        .iter()
+        .filter_map(|pointer| rc.context().data().pointer(pointer).and_then(|v| v.as_str()))
+        .filter_map(|dir| Path::new(dir).canonicalize().ok())
+        .collect::<Vec<_>>();
+    let canonical = try!(Path::new(path).canonicalize().map_err(|e| {
+            RenderError::new(format!("Can't read file {} for \"fileContents\": {}", path, e))
+        }));
+    if !allowed.iter().any(|dir| canonical.starts_with(dir)) {
+        return Err(RenderError::new(format!("\"fileContents\" can only read files in the \
+                                             package or service directory, not {}",
+                                            path)));
+    }
+    let mut contents = String::new();
+    try!(File::open(&canonical)
+        .and_then(|mut f| f.read_to_string(&mut contents))
+        .map_err(|e| {
+            RenderError::new(format!("Can't read file {} for \"fileContents\": {}", path, e))
+        }));
+    try!(rc.writer.write(contents.into_bytes().as_ref()));
+    Ok(())
+}
+
+fn str_param<'a>(h: &'a Helper,
+                 index: usize,
+                 helper: &str,
+                 message: &str)
+                 -> Result<&'a str, RenderError> {
+    h.param(index)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new(format!("{} for \"{}\"", message, helper)))
+}
+
+fn number_param(h: &Helper, index: usize, helper: &str) -> Result<f64, RenderError> {
+    h.param(index)
+        .and_then(|v| number(v.value()))
+        .ok_or_else(|| RenderError::new(format!("Expected 2 number parameters for \"{}\"", helper)))
+}
+
+/// Numbers are often strings in configuration, ex: the `sys` CPU info, so those are accepted too.
+fn number(value: &Value) -> Option<f64> {
+    match *value {
+        Value::Number(ref n) => n.as_f64(),
+        Value::String(ref s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn integer(value: &Value) -> Option<i64> {
+    match *value {
+        Value::Number(ref n) => n.as_i64(),
+        Value::String(ref s) => s.trim().parse::<i64>().ok(),
+        _ => None,
+    }
+}
+
+/// Applies an operation to the helper's two parameters, using integer arithmetic when both are
+/// integers and floating point arithmetic otherwise.
+fn arithmetic<I, F>(h: &Helper,
+                    rc: &mut RenderContext,
+                    helper: &str,
+                    int_op: I,
+                    float_op: F)
+                    -> RenderResult
+    where I: Fn(i64, i64) -> Option<i64>,
+          F: Fn(f64, f64) -> f64
+{
+    if h.params().len() != 2 {
+        return Err(RenderError::new(format!("Expected 2 number parameters for \"{}\"", helper)));
+    }
+    let a = h.param(0).unwrap().value();
+    let b = h.param(1).unwrap().value();
+    let result = match (integer(a), integer(b)) {
+        (Some(a), Some(b)) => {
+            try!(int_op(a, b).ok_or_else(|| {
+                    RenderError::new(format!("Integer overflow in \"{}\"", helper))
+                }))
+                .to_string()
+        }
+        _ => {
+            let a = try!(number_param(h, 0, helper));
+            let b = try!(number_param(h, 1, helper));
+            float_op(a, b).to_string()
+        }
+    };
+    try!(rc.writer.write(result.into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Renders a scalar value the way Handlebars would, ex: strings without quotes.
+fn scalar_to_string(value: &Value) -> String {
+    match *value {
+        Value::String(ref s) => s.clone(),
+        Value::Null => String::new(),
+        ref v => v.to_string(),
+    }
+}
+
+fn write_yaml(out: &mut String, value: &Value, indent: usize) {
+    match *value {
+        Value::Object(ref map) if !map.is_empty() => {
+            for (key, value) in map {
+                out.push_str(&" ".repeat(indent));
+                out.push_str(&yaml_key(key));
+                out.push(':');
+                write_yaml_child(out, value, indent);
+            }
+        }
+        Value::Array(ref values) if !values.is_empty() => {
+            for value in values {
+                out.push_str(&" ".repeat(indent));
+                out.push('-');
+                write_yaml_child(out, value, indent);
+            }
+        }
+        ref scalar => {
+            out.push_str(&" ".repeat(indent));
+            out.push_str(&yaml_scalar(scalar));
+            out.push('\n');
+        }
+    }
+}
+
+fn write_yaml_child(out: &mut String, value: &Value, indent: usize) {
+    match *value {
+        Value::Object(ref map) if !map.is_empty() => {
+            out.push('\n');
+            write_yaml(out, value, indent + 2);
+        }
+        Value::Array(ref values) if !values.is_empty() => {
+            out.push('\n');
+            write_yaml(out, value, indent + 2);
+        }
+        ref scalar => {
+            out.push(' ');
+            out.push_str(&yaml_scalar(scalar));
+            out.push('\n');
+        }
+    }
+}
+
+fn yaml_key(key: &str) -> String {
+    if !key.is_empty() &&
+       key.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.') {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Strings are always double quoted, using JSON escaping which is valid YAML, so that values
+/// like `yes` or `1.0` keep their type.
+fn yaml_scalar(value: &Value) -> String {
+    match *value {
+        Value::Null => "null".to_string(),
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        ref v => v.to_string(),
+    }
+}
@@ -24,11 +24,34 @@ impl Template {
         let mut handlebars = Handlebars::new();
         handlebars.register_helper("pkgPathFor", Box::new(helpers::pkg_path_for));
         handlebars.register_helper("eachAlive", Box::new(helpers::each_alive));
+        handlebars.register_helper("eachHealthy", Box::new(helpers::each_healthy));
+        handlebars.register_helper("eachLeader", Box::new(helpers::each_leader));
+        handlebars.register_helper("eachFollower", Box::new(helpers::each_follower));
+        handlebars.register_helper("eachUpdateLeader", Box::new(helpers::each_update_leader));
+        handlebars.register_helper("eachUpdateFollower", Box::new(helpers::each_update_follower));
+        handlebars.register_helper("memberCount", Box::new(helpers::member_count));
+        handlebars.register_helper("withLeader", Box::new(helpers::with_leader));
+        handlebars.register_helper("withUpdateLeader", Box::new(helpers::with_update_leader));
         handlebars.register_helper("toUppercase", Box::new(helpers::to_uppercase));
         handlebars.register_helper("toLowercase", Box::new(helpers::to_lowercase));
         handlebars.register_helper("strReplace", Box::new(helpers::str_replace));
         handlebars.register_helper("toJson", Box::new(helpers::to_json));
         handlebars.register_helper("toToml", Box::new(helpers::to_toml));
+        handlebars.register_helper("toYaml", Box::new(helpers::to_yaml));
+        handlebars.register_helper("toBase64", Box::new(helpers::to_base64));
+        handlebars.register_helper("toHex", Box::new(helpers::to_hex));
+        handlebars.register_helper("trim", Box::new(helpers::trim));
+        handlebars.register_helper("default", Box::new(helpers::default));
+        handlebars.register_helper("contains", Box::new(helpers::contains));
+        handlebars.register_helper("split", Box::new(helpers::split));
+        handlebars.register_helper("join", Box::new(helpers::join));
+        handlebars.register_helper("strJoin", Box::new(helpers::str_join));
+        handlebars.register_helper("add", Box::new(helpers::add));
+        handlebars.register_helper("sub", Box::new(helpers::sub));
+        handlebars.register_helper("mul", Box::new(helpers::mul));
+        handlebars.register_helper("div", Box::new(helpers::div));
+        handlebars.register_helper("env", Box::new(helpers::env_var));
+        handlebars.register_helper("fileContents", Box::new(helpers::file_contents));
 
         // JW TODO: remove these at a later date, these are an alias for toJson/toToml
         handlebars.register_helper("json", Box::new(helpers::to_json));
@@ -63,15 +86,20 @@ mod test {
     use toml;
     use serde_json;
 
-    use std::fs::File;
-    use std::io::Read;
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
     use std::path::PathBuf;
     use std::collections::BTreeMap;
 
+    use tempdir::TempDir;
+
     use super::*;
 
     use util::convert;
     use manager::ServiceConfig;
+    use manager::census::{CensusEntry, CensusList};
+    use manager::service::HealthCheck;
 
     #[test]
     fn test_handlebars_json_helper() {
@@ -152,6 +180,120 @@ mod test {
         assert_eq!(rendered, "this is new".to_string());
     }
 
+    #[test]
+    fn string_helpers() {
+        let data = json_data(r#"{"var": "  a,b,c  ", "list": ["x", 1, true], "empty": ""}"#);
+
+        assert_eq!(render("[{{trim var}}]", &data), "[a,b,c]");
+        assert_eq!(render("{{#split (trim var) \",\"}}<{{this}}>{{/split}}", &data),
+                   "<a><b><c>");
+        assert_eq!(render("{{strJoin list \"-\"}}", &data), "x-1-true");
+        assert_eq!(render("{{join \":\" \"host\" 8080}}", &data), "host:8080");
+        assert_eq!(render("{{default empty \"fallback\"}}", &data), "fallback");
+        assert_eq!(render("{{default missing 42}}", &data), "42");
+        assert_eq!(render("{{default var \"fallback\"}}", &data), "  a,b,c  ");
+    }
+
+    #[test]
+    fn contains_helper() {
+        let data = json_data(r#"{"var": "hello world", "list": ["a", "b"]}"#);
+
+        assert_eq!(render("{{#contains var \"world\"}}yes{{else}}no{{/contains}}", &data),
+                   "yes");
+        assert_eq!(render("{{#contains list \"c\"}}yes{{else}}no{{/contains}}", &data),
+                   "no");
+        assert_eq!(render("{{#contains list \"a\"}}yes{{/contains}}", &data), "yes");
+    }
+
+    #[test]
+    fn math_helpers() {
+        let data = json_data(r#"{"port": 8080, "cpus": "4", "ratio": 0.5}"#);
+
+        assert_eq!(render("{{add port 1}}", &data), "8081");
+        assert_eq!(render("{{sub port 80}}", &data), "8000");
+        assert_eq!(render("{{mul cpus 2}}", &data), "8");
+        assert_eq!(render("{{mul cpus ratio}}", &data), "2");
+        assert_eq!(render("{{div port 3}}", &data), "2693");
+        assert_eq!(render("{{div 5 2.0}}", &data), "2.5");
+    }
+
+    #[test]
+    fn math_helpers_reject_bad_parameters() {
+        let data = json_data(r#"{"var": "nope"}"#);
+
+        for content in &["{{add var 1}}", "{{mul 2}}", "{{div 4 0}}"] {
+            let mut template = Template::new();
+            template.register_template_string("t", content.to_string()).unwrap();
+            assert!(template.render("t", &data).is_err(), "{} should fail", content);
+        }
+    }
+
+    #[test]
+    fn encoding_helpers() {
+        let data = json_data(r#"{"var": "hab"}"#);
+
+        assert_eq!(render("{{toBase64 var}}", &data), "aGFi");
+        assert_eq!(render("{{toHex var}}", &data), "686162");
+    }
+
+    #[test]
+    fn to_yaml_helper() {
+        let data = json_data(r#"{"cfg": {"name": "redis", "port": 6379,
+                                         "tags": ["a", "b"], "tls": {"enabled": false},
+                                         "empty": []}}"#);
+
+        assert_eq!(render("{{toYaml cfg}}", &data),
+                   "empty: []\n\
+                    name: \"redis\"\n\
+                    port: 6379\n\
+                    tags:\n  \
+                    - \"a\"\n  \
+                    - \"b\"\n\
+                    tls:\n  \
+                    enabled: false\n");
+    }
+
+    #[test]
+    fn env_helper() {
+        env::set_var("HAB_TEMPLATE_HELPER_TEST", "value");
+        env::set_var("TEMPLATE_HELPER_TEST", "value");
+        let data = json_data("{}");
+
+        assert_eq!(render("{{env \"HAB_TEMPLATE_HELPER_TEST\"}}", &data), "value");
+        assert_eq!(render("[{{env \"HAB_TEMPLATE_HELPER_UNSET\"}}]", &data), "[]");
+        let mut template = Template::new();
+        template.register_template_string("t", "{{env \"TEMPLATE_HELPER_TEST\"}}".to_string())
+            .unwrap();
+        assert!(template.render("t", &data).is_err());
+    }
+
+    #[test]
+    fn file_contents_helper() {
+        let tmp = TempDir::new("file-contents").unwrap();
+        let svc_path = tmp.path().join("svc");
+        fs::create_dir(&svc_path).unwrap();
+        let inside = svc_path.join("contents.txt");
+        File::create(&inside).unwrap().write_all(b"secret").unwrap();
+        let outside = tmp.path().join("outside.txt");
+        File::create(&outside).unwrap().write_all(b"host").unwrap();
+        let quote = |p: &PathBuf| serde_json::to_string(&p.to_string_lossy()).unwrap();
+        let data = json_data(&format!("{{\"pkg\": {{\"svc_path\": {}}}, \"inside\": {}, \
+                                       \"outside\": {}}}",
+                                      quote(&svc_path),
+                                      quote(&inside),
+                                      quote(&outside)));
+
+        assert_eq!(render("{{fileContents inside}}", &data), "secret");
+        let mut template = Template::new();
+        template.register_template_string("t", "{{fileContents outside}}".to_string())
+            .unwrap();
+        assert!(template.render("t", &data).is_err());
+    }
+
+    fn json_data(json: &str) -> serde_json::Value {
+        serde_json::from_str(json).unwrap()
+    }
+
     pub fn root() -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests")
     }
@@ -259,4 +401,134 @@ mod test {
 
         assert_eq!(each_alive_render, each_if_render);
     }
+
+    fn census_entry(member_id: &str, alive: bool) -> CensusEntry {
+        let mut ce = CensusEntry::default();
+        ce.set_member_id(member_id.to_string());
+        ce.set_service("redis".to_string());
+        ce.set_group("default".to_string());
+        ce.set_alive(alive);
+        ce
+    }
+
+    /// Build template data out of a synthetic census, the same way census members are exposed
+    /// to service configuration templates.
+    fn census_data(entries: Vec<CensusEntry>) -> serde_json::Value {
+        let mut census_list = CensusList::new();
+        for entry in entries {
+            census_list.insert(String::from("me"), entry);
+        }
+        let census = census_list.get("redis.default").unwrap();
+        let members: Vec<serde_json::Value> = census.members()
+            .into_iter()
+            .map(|ce| serde_json::to_value(ce).unwrap())
+            .collect();
+        let mut data = serde_json::Map::new();
+        data.insert("members".to_string(), serde_json::Value::Array(members));
+        serde_json::Value::Object(data)
+    }
+
+    fn render(content: &str, data: &serde_json::Value) -> String {
+        let mut template = Template::new();
+        template.register_template_string("t", content.to_string()).unwrap();
+        template.render("t", data).unwrap()
+    }
+
+    #[test]
+    fn each_healthy_helper() {
+        let mut critical = census_entry("b", true);
+        critical.set_health_check(HealthCheck::Critical);
+        let mut warning = census_entry("c", true);
+        warning.set_health_check(HealthCheck::Warning);
+        let data = census_data(vec![census_entry("d", true),
+                                    warning,
+                                    critical,
+                                    census_entry("a", false)]);
+
+        let rendered = render("{{#eachHealthy members}}{{member_id}},{{/eachHealthy}}", &data);
+        assert_eq!(rendered, "c,d,");
+    }
+
+    #[test]
+    fn each_healthy_helper_renders_else_without_members() {
+        let data = census_data(vec![census_entry("a", false)]);
+
+        let rendered = render("{{#eachHealthy members}}{{member_id}}{{else}}none{{/eachHealthy}}",
+                              &data);
+        assert_eq!(rendered, "none");
+    }
+
+    #[test]
+    fn each_follower_helper() {
+        let mut leader = census_entry("a", true);
+        leader.set_leader(true);
+        leader.set_follower(false);
+        let mut follower = census_entry("c", true);
+        follower.set_follower(true);
+        let mut other_follower = census_entry("b", true);
+        other_follower.set_follower(true);
+        let mut dead_follower = census_entry("d", false);
+        dead_follower.set_follower(true);
+        let data = census_data(vec![leader, follower, other_follower, dead_follower]);
+
+        let rendered = render("{{#eachFollower members as |m|}}{{m.member_id}},{{/eachFollower}}",
+                              &data);
+        assert_eq!(rendered, "b,c,");
+        let rendered = render("{{#eachLeader members}}{{member_id}}{{/eachLeader}}", &data);
+        assert_eq!(rendered, "a");
+    }
+
+    #[test]
+    fn each_update_follower_helper() {
+        let mut update_leader = census_entry("b", true);
+        update_leader.set_update_leader(true);
+        let mut update_follower = census_entry("a", true);
+        update_follower.set_update_follower(true);
+        let data = census_data(vec![update_leader, update_follower]);
+
+        let rendered = render("{{#eachUpdateFollower members}}{{member_id}},\
+                               {{/eachUpdateFollower}}",
+                              &data);
+        assert_eq!(rendered, "a,");
+        let rendered = render("{{#eachUpdateLeader members}}{{member_id}}{{/eachUpdateLeader}}",
+                              &data);
+        assert_eq!(rendered, "b");
+    }
+
+    #[test]
+    fn member_count_helper() {
+        let data = census_data(vec![census_entry("a", true),
+                                    census_entry("b", false),
+                                    census_entry("c", true)]);
+
+        assert_eq!(render("{{memberCount members}}", &data), "2");
+    }
+
+    #[test]
+    fn with_leader_helper() {
+        let mut leader = census_entry("b", true);
+        leader.set_leader(true);
+        let data = census_data(vec![census_entry("a", true), leader]);
+
+        let rendered = render("{{#withLeader members}}{{member_id}}{{else}}none{{/withLeader}}",
+                              &data);
+        assert_eq!(rendered, "b");
+
+        let data = census_data(vec![census_entry("a", true)]);
+        let rendered = render("{{#withLeader members}}{{member_id}}{{else}}none{{/withLeader}}",
+                              &data);
+        assert_eq!(rendered, "none");
+    }
+
+    #[test]
+    fn with_update_leader_helper() {
+        let mut update_leader = census_entry("b", true);
+        update_leader.set_update_leader(true);
+        let data = census_data(vec![census_entry("a", true), update_leader]);
+
+        let rendered = render("{{#withUpdateLeader members as |l|}}{{l.member_id}}\
+                               {{/withUpdateLeader}}",
+                              &data);
+        assert_eq!(rendered, "b");
+    }
 }
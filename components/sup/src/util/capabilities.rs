@@ -0,0 +1,175 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Linux capabilities which a service keeps when it runs as a user other than root.
+//!
+//! The Supervisor, running as root, switches to the service's user and raises the capabilities in
+//! the ambient set of the process before it runs the service, so that they survive `execve`. This
+//! needs Linux 4.3 or later.
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "CP";
+
+/// The capabilities we know about, and their numbers.
+const CAPABILITIES: &'static [(&'static str, u32)] = &[("CAP_CHOWN", 0),
+                                                       ("CAP_DAC_OVERRIDE", 1),
+                                                       ("CAP_DAC_READ_SEARCH", 2),
+                                                       ("CAP_FOWNER", 3),
+                                                       ("CAP_FSETID", 4),
+                                                       ("CAP_KILL", 5),
+                                                       ("CAP_SETGID", 6),
+                                                       ("CAP_SETUID", 7),
+                                                       ("CAP_SETPCAP", 8),
+                                                       ("CAP_LINUX_IMMUTABLE", 9),
+                                                       ("CAP_NET_BIND_SERVICE", 10),
+                                                       ("CAP_NET_BROADCAST", 11),
+                                                       ("CAP_NET_ADMIN", 12),
+                                                       ("CAP_NET_RAW", 13),
+                                                       ("CAP_IPC_LOCK", 14),
+                                                       ("CAP_IPC_OWNER", 15),
+                                                       ("CAP_SYS_MODULE", 16),
+                                                       ("CAP_SYS_RAWIO", 17),
+                                                       ("CAP_SYS_CHROOT", 18),
+                                                       ("CAP_SYS_PTRACE", 19),
+                                                       ("CAP_SYS_PACCT", 20),
+                                                       ("CAP_SYS_ADMIN", 21),
+                                                       ("CAP_SYS_BOOT", 22),
+                                                       ("CAP_SYS_NICE", 23),
+                                                       ("CAP_SYS_RESOURCE", 24),
+                                                       ("CAP_SYS_TIME", 25),
+                                                       ("CAP_SYS_TTY_CONFIG", 26),
+                                                       ("CAP_MKNOD", 27),
+                                                       ("CAP_LEASE", 28),
+                                                       ("CAP_AUDIT_WRITE", 29),
+                                                       ("CAP_AUDIT_CONTROL", 30),
+                                                       ("CAP_SETFCAP", 31),
+                                                       ("CAP_MAC_OVERRIDE", 32),
+                                                       ("CAP_MAC_ADMIN", 33),
+                                                       ("CAP_SYSLOG", 34),
+                                                       ("CAP_WAKE_ALARM", 35),
+                                                       ("CAP_BLOCK_SUSPEND", 36),
+                                                       ("CAP_AUDIT_READ", 37)];
+
+/// Look up a capability by name, in any case and with or without its `CAP_` prefix
+/// (ex: `CAP_NET_BIND_SERVICE`, `net_bind_service`), returning its canonical name and number.
+pub fn parse(name: &str) -> Result<(&'static str, u32)> {
+    let mut canonical = name.trim().to_uppercase();
+    if !canonical.starts_with("CAP_") {
+        canonical = format!("CAP_{}", canonical);
+    }
+    CAPABILITIES.iter()
+        .find(|&&(n, _)| n == canonical)
+        .map(|&(n, number)| (n, number))
+        .ok_or(sup_error!(Error::InvalidCapability(name.to_string())))
+}
+
+#[cfg(target_os = "linux")]
+pub use self::linux::switch_user;
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::io;
+
+    use libc;
+
+    const PR_SET_KEEPCAPS: libc::c_int = 8;
+    const PR_CAP_AMBIENT: libc::c_int = 47;
+    const PR_CAP_AMBIENT_RAISE: libc::c_ulong = 2;
+    const LINUX_CAPABILITY_VERSION_3: u32 = 0x20080522;
+
+    #[repr(C)]
+    struct CapHeader {
+        version: u32,
+        pid: libc::c_int,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Default)]
+    struct CapData {
+        effective: u32,
+        permitted: u32,
+        inheritable: u32,
+    }
+
+    fn cvt(ret: libc::c_long) -> io::Result<()> {
+        if ret == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Switch the calling process to the given user, group and supplementary groups, keeping only
+    /// the given capabilities. This is meant to run in a child process between `fork` and `exec`,
+    /// so it only makes system calls.
+    pub fn switch_user(uid: u32, gid: u32, groups: &[u32], caps: &[u32]) -> io::Result<()> {
+        unsafe {
+            try!(cvt(libc::setgroups(groups.len() as libc::size_t, groups.as_ptr()) as
+                     libc::c_long));
+            try!(cvt(libc::setgid(gid) as libc::c_long));
+            if !caps.is_empty() {
+                try!(cvt(libc::prctl(PR_SET_KEEPCAPS, 1 as libc::c_ulong) as libc::c_long));
+            }
+            try!(cvt(libc::setuid(uid) as libc::c_long));
+            if caps.is_empty() {
+                return Ok(());
+            }
+
+            // The permitted set survived the switch, but only because of PR_SET_KEEPCAPS; narrow it
+            // down to the capabilities we keep, which also need to be inheritable to be ambient.
+            let mut header = CapHeader {
+                version: LINUX_CAPABILITY_VERSION_3,
+                pid: 0,
+            };
+            let mut data = [CapData::default(); 2];
+            for cap in caps {
+                let bit = 1 << (cap % 32);
+                let set = &mut data[(cap / 32) as usize];
+                set.effective |= bit;
+                set.permitted |= bit;
+                set.inheritable |= bit;
+            }
+            try!(cvt(libc::syscall(libc::SYS_capset, &mut header, data.as_ptr())));
+            for cap in caps {
+                try!(cvt(libc::prctl(PR_CAP_AMBIENT,
+                                     PR_CAP_AMBIENT_RAISE,
+                                     *cap as libc::c_ulong,
+                                     0 as libc::c_ulong,
+                                     0 as libc::c_ulong) as libc::c_long));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn parse_capability_names() {
+        assert_eq!(parse("CAP_NET_BIND_SERVICE").unwrap(),
+                   ("CAP_NET_BIND_SERVICE", 10));
+        assert_eq!(parse("net_bind_service").unwrap(),
+                   ("CAP_NET_BIND_SERVICE", 10));
+        assert_eq!(parse("Cap_Sys_Nice").unwrap(), ("CAP_SYS_NICE", 23));
+    }
+
+    #[test]
+    fn parse_unknown_capability() {
+        assert!(parse("CAP_MAKE_COFFEE").is_err());
+        assert!(parse("").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enforces the resource limits of a service with a cgroup of its own.
+//!
+//! We use the version 1 hierarchies of the memory, cpu and pids controllers, mounted under
+//! `/sys/fs/cgroup`. A service's cgroup is `<root>/<service>` in each of them, where the root
+//! defaults to `hab`.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use supervisor::Limits;
+
+/// Where the cgroup hierarchies are mounted.
+pub const CGROUP_MOUNT: &'static str = "/sys/fs/cgroup";
+/// The cgroup under which each service gets a cgroup of its own.
+pub const CGROUP_ROOT_DEFAULT: &'static str = "hab";
+
+const CONTROLLERS: &'static [&'static str] = &["memory", "cpu", "pids"];
+/// The period `cpu_quota` applies to, in microseconds.
+const CPU_PERIOD_US: u64 = 100_000;
+const CPU_SHARES_DEFAULT: u64 = 1024;
+
+/// How much of each resource the processes in a cgroup use.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct Usage {
+    pub memory_bytes: Option<u64>,
+    pub cpu_seconds: Option<f64>,
+    pub pids: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cgroup {
+    mount: PathBuf,
+    path: PathBuf,
+}
+
+impl Cgroup {
+    /// The cgroup at `path`, relative to the root of each hierarchy under `mount`.
+    pub fn new<M, P>(mount: M, path: P) -> Cgroup
+        where M: Into<PathBuf>,
+              P: Into<PathBuf>
+    {
+        Cgroup {
+            mount: mount.into(),
+            path: path.into(),
+        }
+    }
+
+    /// Returns true if the hierarchies we need are mounted.
+    pub fn is_supported(&self) -> bool {
+        cfg!(target_os = "linux") && CONTROLLERS.iter().all(|c| self.mount.join(c).is_dir())
+    }
+
+    /// Create the cgroup if it doesn't exist, and set its limits. Unset limits are reset, so that
+    /// a limit removed from a service no longer applies.
+    pub fn apply(&self, limits: &Limits) -> io::Result<()> {
+        for controller in CONTROLLERS {
+            try!(fs::create_dir_all(self.dir(controller)));
+        }
+        try!(self.write("memory",
+                        "memory.limit_in_bytes",
+                        &limits.memory_max.map_or("-1".to_string(), |m| m.to_string())));
+        try!(self.write("cpu",
+                        "cpu.shares",
+                        &limits.cpu_shares.unwrap_or(CPU_SHARES_DEFAULT).to_string()));
+        try!(self.write("cpu", "cpu.cfs_period_us", &CPU_PERIOD_US.to_string()));
+        try!(self.write("cpu",
+                        "cpu.cfs_quota_us",
+                        &limits.cpu_quota
+                             .map_or("-1".to_string(),
+                                     |q| (q * CPU_PERIOD_US / 100).to_string())));
+        try!(self.write("pids",
+                        "pids.max",
+                        &limits.pids_max.map_or("max".to_string(), |p| p.to_string())));
+        Ok(())
+    }
+
+    /// Open the files processes are moved into the cgroup through, for a process to `join` it
+    /// between forking and exec'ing, when it can no longer safely open them itself.
+    pub fn open_procs(&self) -> io::Result<Vec<File>> {
+        CONTROLLERS.iter()
+            .map(|c| OpenOptions::new().write(true).open(self.dir(c).join("cgroup.procs")))
+            .collect()
+    }
+
+    /// How much of each resource the cgroup uses, as far as it can be read.
+    pub fn usage(&self) -> Usage {
+        Usage {
+            memory_bytes: self.read("memory", "memory.usage_in_bytes"),
+            // The cpuacct controller is usually mounted together with cpu.
+            cpu_seconds: self.read("cpu", "cpuacct.usage").map(|ns| ns as f64 / 1_000_000_000.0),
+            pids: self.read("pids", "pids.current"),
+        }
+    }
+
+    /// Remove the cgroup. This fails quietly while it still has processes.
+    pub fn remove(&self) {
+        for controller in CONTROLLERS {
+            if let Err(e) = fs::remove_dir(self.dir(controller)) {
+                debug!("Unable to remove the {} cgroup {}: {}",
+                       controller,
+                       self.path.display(),
+                       e);
+            }
+        }
+    }
+
+    fn dir(&self, controller: &str) -> PathBuf {
+        self.mount.join(controller).join(&self.path)
+    }
+
+    fn write(&self, controller: &str, file: &str, value: &str) -> io::Result<()> {
+        let mut f = try!(File::create(self.dir(controller).join(file)));
+        f.write_all(value.as_bytes())
+    }
+
+    fn read(&self, controller: &str, file: &str) -> Option<u64> {
+        read_u64(&self.dir(controller).join(file))
+    }
+}
+
+/// Move the calling process into the cgroup whose files `open_procs` returned. Writing 0 stands
+/// for the writer itself. Processes it starts from then on are in the cgroup too.
+pub fn join(procs: &[File]) -> io::Result<()> {
+    for mut f in procs {
+        try!(f.write_all(b"0"));
+    }
+    Ok(())
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    let mut contents = String::new();
+    match File::open(path) {
+        Ok(mut f) => {
+            if f.read_to_string(&mut contents).is_err() {
+                return None;
+            }
+        }
+        Err(_) => return None,
+    }
+    contents.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::path::Path;
+
+    use tempdir::TempDir;
+
+    use super::{join, Cgroup, Usage};
+    use supervisor::Limits;
+
+    fn contents(path: &Path) -> String {
+        let mut contents = String::new();
+        File::open(path).unwrap().read_to_string(&mut contents).unwrap();
+        contents
+    }
+
+    fn mount() -> TempDir {
+        let tmp = TempDir::new("cgroup").unwrap();
+        for controller in &["memory", "cpu", "pids"] {
+            fs::create_dir_all(tmp.path().join(controller)).unwrap();
+        }
+        tmp
+    }
+
+    #[test]
+    fn apply_sets_limits() {
+        let tmp = mount();
+        let cgroup = Cgroup::new(tmp.path(), "hab/redis");
+        let limits = Limits {
+            memory_max: Some(1073741824),
+            cpu_shares: None,
+            cpu_quota: Some(150),
+            pids_max: Some(64),
+        };
+        cgroup.apply(&limits).unwrap();
+        let memory = tmp.path().join("memory/hab/redis");
+        let cpu = tmp.path().join("cpu/hab/redis");
+        let pids = tmp.path().join("pids/hab/redis");
+        assert_eq!(contents(&memory.join("memory.limit_in_bytes")), "1073741824");
+        assert_eq!(contents(&cpu.join("cpu.shares")), "1024");
+        assert_eq!(contents(&cpu.join("cpu.cfs_quota_us")), "150000");
+        assert_eq!(contents(&pids.join("pids.max")), "64");
+    }
+
+    #[test]
+    fn apply_resets_unset_limits() {
+        let tmp = mount();
+        let cgroup = Cgroup::new(tmp.path(), "hab/redis");
+        cgroup.apply(&Limits::default()).unwrap();
+        assert_eq!(contents(&tmp.path().join("memory/hab/redis/memory.limit_in_bytes")),
+                   "-1");
+        assert_eq!(contents(&tmp.path().join("cpu/hab/redis/cpu.cfs_quota_us")), "-1");
+        assert_eq!(contents(&tmp.path().join("pids/hab/redis/pids.max")), "max");
+    }
+
+    #[test]
+    fn usage_reads_what_is_there() {
+        let tmp = mount();
+        let cgroup = Cgroup::new(tmp.path(), "hab/redis");
+        cgroup.apply(&Limits::default()).unwrap();
+        File::create(tmp.path().join("memory/hab/redis/memory.usage_in_bytes"))
+            .unwrap()
+            .write_all(b"4096\n")
+            .unwrap();
+        File::create(tmp.path().join("pids/hab/redis/pids.current"))
+            .unwrap()
+            .write_all(b"3\n")
+            .unwrap();
+        assert_eq!(cgroup.usage(),
+                   Usage {
+                       memory_bytes: Some(4096),
+                       cpu_seconds: None,
+                       pids: Some(3),
+                   });
+    }
+
+    #[test]
+    fn join_writes_every_procs_file() {
+        let tmp = mount();
+        let cgroup = Cgroup::new(tmp.path(), "hab/redis");
+        cgroup.apply(&Limits::default()).unwrap();
+        for controller in &["memory", "cpu", "pids"] {
+            File::create(tmp.path().join(controller).join("hab/redis/cgroup.procs")).unwrap();
+        }
+        join(&cgroup.open_procs().unwrap()).unwrap();
+        for controller in &["memory", "cpu", "pids"] {
+            assert_eq!(contents(&tmp.path().join(controller).join("hab/redis/cgroup.procs")),
+                       "0");
+        }
+    }
+
+    #[test]
+    fn unsupported_without_hierarchies() {
+        let tmp = TempDir::new("cgroup").unwrap();
+        assert!(!Cgroup::new(tmp.path(), "hab/redis").is_supported());
+    }
+}
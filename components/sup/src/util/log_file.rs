@@ -0,0 +1,185 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A log file which rotates itself by size and age.
+//!
+//! When the file is rotated, `stdout.log` becomes `stdout.log.1`, `stdout.log.1` becomes
+//! `stdout.log.2`, and so on; only `keep` rotated files are kept.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug)]
+pub struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    created: SystemTime,
+    max_bytes: u64,
+    max_age: Option<Duration>,
+    keep: u32,
+}
+
+impl LogFile {
+    /// Open the log file at `path` for appending. It is rotated before a write would take it past
+    /// `max_bytes`, or once it was created more than `max_age` seconds ago; a limit of 0 disables
+    /// it. The age of an existing file is taken from its metadata, so reopening it, for example
+    /// when the Supervisor restarts, doesn't postpone its rotation.
+    pub fn open<P: Into<PathBuf>>(path: P,
+                                  max_bytes: u64,
+                                  max_age: u64,
+                                  keep: u32)
+                                  -> io::Result<LogFile> {
+        let path = path.into();
+        let file = try!(OpenOptions::new().create(true).append(true).open(&path));
+        let metadata = try!(file.metadata());
+        // Not every platform records when a file was created; the last write is the next best
+        // thing, and at worst lets an idle file be rotated early.
+        let created = metadata.created()
+            .or_else(|_| metadata.modified())
+            .unwrap_or_else(|_| SystemTime::now());
+        Ok(LogFile {
+               path: path,
+               file: file,
+               size: metadata.len(),
+               created: created,
+               max_bytes: max_bytes,
+               max_age: if max_age == 0 {
+                   None
+               } else {
+                   Some(Duration::from_secs(max_age))
+               },
+               keep: keep,
+           })
+    }
+
+    /// Append a line to the log file, rotating it first if it is due.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        let too_big = self.max_bytes > 0 && self.size > 0 && self.size + len > self.max_bytes;
+        let too_old = self.max_age
+            .map_or(false,
+                    |age| self.created.elapsed().map(|elapsed| elapsed >= age).unwrap_or(false));
+        if too_big || too_old {
+            try!(self.rotate());
+        }
+        try!(writeln!(self.file, "{}", line));
+        self.size += len;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.keep == 0 {
+            try!(fs::remove_file(&self.path));
+        } else {
+            for n in (1..self.keep).rev() {
+                let from = self.rotated_path(n);
+                if from.exists() {
+                    try!(fs::rename(from, self.rotated_path(n + 1)));
+                }
+            }
+            try!(fs::rename(&self.path, self.rotated_path(1)));
+        }
+        self.file = try!(OpenOptions::new().create(true).append(true).open(&self.path));
+        self.size = 0;
+        self.created = SystemTime::now();
+        Ok(())
+    }
+
+    fn rotated_path(&self, n: u32) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".{}", n));
+        self.path.with_file_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::Path;
+    use std::thread;
+    use std::time::Duration;
+
+    use tempdir::TempDir;
+
+    use super::LogFile;
+
+    fn contents(path: &Path) -> String {
+        let mut contents = String::new();
+        File::open(path).unwrap().read_to_string(&mut contents).unwrap();
+        contents
+    }
+
+    #[test]
+    fn rotates_when_too_big() {
+        let tmp = TempDir::new("log-file").unwrap();
+        let path = tmp.path().join("stdout.log");
+        let mut log = LogFile::open(&path, 10, 0, 2).unwrap();
+        log.write_line("slayer").unwrap();
+        log.write_line("anthrax").unwrap();
+        assert_eq!(contents(&path), "anthrax\n");
+        assert_eq!(contents(&tmp.path().join("stdout.log.1")), "slayer\n");
+    }
+
+    #[test]
+    fn keeps_only_keep_rotated_files() {
+        let tmp = TempDir::new("log-file").unwrap();
+        let path = tmp.path().join("stdout.log");
+        let mut log = LogFile::open(&path, 1, 0, 2).unwrap();
+        for line in &["slayer", "anthrax", "megadeth", "metallica"] {
+            log.write_line(line).unwrap();
+        }
+        assert_eq!(contents(&path), "metallica\n");
+        assert_eq!(contents(&tmp.path().join("stdout.log.1")), "megadeth\n");
+        assert_eq!(contents(&tmp.path().join("stdout.log.2")), "anthrax\n");
+        assert!(!tmp.path().join("stdout.log.3").exists());
+    }
+
+    #[test]
+    fn rotates_when_too_old() {
+        let tmp = TempDir::new("log-file").unwrap();
+        let path = tmp.path().join("stdout.log");
+        let mut log = LogFile::open(&path, 0, 1, 1).unwrap();
+        log.write_line("slayer").unwrap();
+        log.write_line("anthrax").unwrap();
+        assert_eq!(contents(&path), "slayer\nanthrax\n");
+        thread::sleep(Duration::from_millis(1100));
+        log.write_line("megadeth").unwrap();
+        assert_eq!(contents(&path), "megadeth\n");
+        assert_eq!(contents(&tmp.path().join("stdout.log.1")), "slayer\nanthrax\n");
+    }
+
+    #[test]
+    fn reopening_does_not_postpone_rotation() {
+        let tmp = TempDir::new("log-file").unwrap();
+        let path = tmp.path().join("stdout.log");
+        LogFile::open(&path, 0, 1, 1).unwrap().write_line("slayer").unwrap();
+        thread::sleep(Duration::from_millis(1100));
+        LogFile::open(&path, 0, 1, 1).unwrap().write_line("anthrax").unwrap();
+        assert_eq!(contents(&path), "anthrax\n");
+        assert_eq!(contents(&tmp.path().join("stdout.log.1")), "slayer\n");
+    }
+
+    #[test]
+    fn appends_to_an_existing_file() {
+        let tmp = TempDir::new("log-file").unwrap();
+        let path = tmp.path().join("stdout.log");
+        LogFile::open(&path, 0, 0, 1).unwrap().write_line("slayer").unwrap();
+        LogFile::open(&path, 0, 0, 1).unwrap().write_line("anthrax").unwrap();
+        assert_eq!(contents(&path), "slayer\nanthrax\n");
+    }
+}
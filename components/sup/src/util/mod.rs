@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod capabilities;
+pub mod cgroup;
 pub mod convert;
+pub mod log_file;
 pub mod path;
 pub mod pkg;
 pub mod sys;
@@ -79,15 +82,50 @@ pub fn create_command<S: AsRef<OsStr>>(path: S, cfg: &RuntimeConfig) -> Result<C
 
     cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .uid(uid)
-        .gid(gid);
-    for (key, val) in &cfg.env_vars {
+        .stderr(Stdio::piped());
+    // Join the cgroup while still privileged, switching user ourselves once it's done, since
+    // `uid` and `gid` take effect before any `before_exec` closure runs.
+    if let Some(ref service_cgroup) = cfg.cgroup {
+        let procs = try!(service_cgroup.open_procs());
+        cmd.before_exec(move || cgroup::join(&procs));
+    }
+    if cfg.supplementary_groups.is_empty() && cfg.capabilities.is_empty() &&
+       cfg.cgroup.is_none() {
+        cmd.uid(uid).gid(gid);
+    } else {
+        try!(switch_user(&mut cmd, uid, gid, cfg));
+    }
+    for (key, val) in cfg.env_vars.iter().chain(cfg.secret_env_vars.iter()) {
         cmd.env(key, val);
     }
     Ok(cmd)
 }
 
+/// Switch to the service's user in the child ourselves, rather than with `uid` and `gid`, which
+/// clear the supplementary groups and drop every capability.
+#[cfg(target_os = "linux")]
+fn switch_user(cmd: &mut Command, uid: u32, gid: u32, cfg: &RuntimeConfig) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+    let mut groups = Vec::with_capacity(cfg.supplementary_groups.len());
+    for group in &cfg.supplementary_groups {
+        groups.push(os::users::get_gid_by_name(group).ok_or(sup_error!(Error::Permissions(
+                    format!("No gid for group '{}' could be found", group))))?);
+    }
+    let mut caps = Vec::with_capacity(cfg.capabilities.len());
+    for capability in &cfg.capabilities {
+        caps.push(try!(capabilities::parse(capability)).1);
+    }
+    cmd.before_exec(move || capabilities::switch_user(uid, gid, &groups, &caps));
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn switch_user(_: &mut Command, _: u32, _: u32, _: &RuntimeConfig) -> Result<()> {
+    Err(sup_error!(Error::Permissions("Supplementary groups and capabilities are only \
+                                       supported on Linux"
+                                              .to_string())))
+}
+
 #[cfg(target_os = "windows")]
 pub fn create_command<S: AsRef<OsStr>>(path: S, cfg: &RuntimeConfig) -> Result<Command> {
     let mut cmd = Command::new("powershell.exe");
@@ -97,7 +135,7 @@ pub fn create_command<S: AsRef<OsStr>>(path: S, cfg: &RuntimeConfig) -> Result<C
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    for (key, val) in &cfg.env_vars {
+    for (key, val) in cfg.env_vars.iter().chain(cfg.secret_env_vars.iter()) {
         cmd.env(key, val);
     }
     Ok(cmd)
@@ -39,16 +39,28 @@ pub fn install(ui: &mut UI, depot_url: &str, ident: &PackageIdent) -> Result<Pac
     Ok(PackageInstall::load(&installed_ident, Some(&fs_root_path))?)
 }
 
+/// Install the latest package satisfying the spec's identifier, only considering packages
+/// promoted to the spec's channel if it has one.
+pub fn install_from_spec(ui: &mut UI, spec: &ServiceSpec) -> Result<PackageInstall> {
+    match spec.channel {
+        Some(ref channel) => {
+            let depot_client = Client::new(&spec.depot_url, PRODUCT, VERSION, None)?;
+            let ident = latest_ident(&depot_client, &spec.ident, Some(channel))?;
+            self::install(ui, &spec.depot_url, &ident)
+        }
+        None => self::install(ui, &spec.depot_url, &spec.ident),
+    }
+}
+
 pub fn maybe_install_newer(ui: &mut UI,
                            spec: &ServiceSpec,
                            current: PackageInstall)
                            -> Result<PackageInstall> {
-    let latest_ident: PackageIdent = {
+    let latest_ident = {
         let depot_client = Client::new(&spec.depot_url, PRODUCT, VERSION, None)?;
-        depot_client.show_package(&spec.ident)?
-            .get_ident()
-            .clone()
-            .into()
+        latest_ident(&depot_client,
+                     &spec.ident,
+                     spec.channel.as_ref().map(String::as_str))?
     };
 
     if &latest_ident > current.ident() {
@@ -64,3 +76,16 @@ pub fn maybe_install_newer(ui: &mut UI,
         Ok(current)
     }
 }
+
+/// Returns the identifier of the latest package satisfying the given identifier. If a channel is
+/// given, only packages promoted to that channel are considered.
+pub fn latest_ident(depot_client: &Client,
+                    ident: &PackageIdent,
+                    channel: Option<&str>)
+                    -> Result<PackageIdent> {
+    let package = match channel {
+        Some(channel) => depot_client.show_package_in_channel(ident, channel)?,
+        None => depot_client.show_package(ident)?,
+    };
+    Ok(package.get_ident().clone().into())
+}
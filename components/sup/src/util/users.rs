@@ -16,11 +16,59 @@ use error::{Result, Error};
 use hcore::os::users;
 use hcore::package::PackageInstall;
 
+use util::capabilities;
+
 pub const DEFAULT_USER: &'static str = "hab";
 pub const DEFAULT_GROUP: &'static str = "hab";
 
 static LOGKEY: &'static str = "UR";
 
+/// The user and group a service runs as when the operator overrides those of its package, along
+/// with the supplementary groups and Linux capabilities it gets.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserConfig {
+    pub user: Option<String>,
+    pub group: Option<String>,
+    pub supplementary_groups: Vec<String>,
+    pub capabilities: Vec<String>,
+}
+
+impl UserConfig {
+    /// Check that the supplementary groups exist and the capabilities are valid, and that the
+    /// Supervisor is able to grant them.
+    pub fn validate(&self) -> Result<()> {
+        if self.supplementary_groups.is_empty() && self.capabilities.is_empty() {
+            return Ok(());
+        }
+        if !cfg!(target_os = "linux") {
+            return Err(sup_error!(Error::Permissions("Supplementary groups and capabilities \
+                                                      are only supported on Linux"
+                                                             .to_string())));
+        }
+        for group in &self.supplementary_groups {
+            if let None = users::get_gid_by_name(group) {
+                return Err(sup_error!(Error::Permissions(format!("Supplementary group {} \
+                                                                  doesn't exist",
+                                                                 group))));
+            }
+        }
+        for capability in &self.capabilities {
+            try!(capabilities::parse(capability));
+        }
+        if users::get_current_username() != Some(users::root_level_account()) {
+            return Err(sup_error!(Error::Permissions("Supplementary groups and capabilities \
+                                                      can only be granted when the Supervisor \
+                                                      runs as root"
+                                                             .to_string())));
+        }
+        Ok(())
+    }
+
+    fn is_overridden(&self) -> bool {
+        self.user.is_some() || self.group.is_some()
+    }
+}
+
 /// This function checks to see if a custom SVC_USER and SVC_GROUP has
 /// been specified as part of the package metadata.
 /// If a pkg_svc_user and pkg_svc_group have been defined, check if:
@@ -33,44 +81,8 @@ fn check_pkg_user_and_group(pkg_install: &PackageInstall) -> Result<Option<(Stri
     let svc_group = try!(pkg_install.svc_group());
     match (svc_user, svc_group) {
         (Some(user), Some(group)) => {
-            if let None = users::get_uid_by_name(&user) {
-                return Err(sup_error!(Error::Permissions(format!("Package requires user {} to \
-                                                                  exist, but it doesn't",
-                                                                 user))));
-            }
-            if let None = users::get_gid_by_name(&group) {
-                return Err(sup_error!(Error::Permissions(format!("Package requires group {} \
-                                                                  to exist, but it doesn't",
-                                                                 group))));
-            }
-
-            let current_user = users::get_current_username();
-            let current_group = users::get_current_groupname();
-
-            if let None = current_user {
-                return Err(sup_error!(Error::Permissions("Can't determine current user"
-                                                             .to_string())));
-            }
-
-            if let None = current_group {
-                return Err(sup_error!(Error::Permissions("Can't determine current group"
-                                                             .to_string())));
-            }
-
-            let current_user = current_user.unwrap();
-            let current_group = current_group.unwrap();
-
-            if current_user == users::root_level_account() {
-                Ok(Some((user, group)))
-            } else {
-                if current_user == user && (cfg!(target_os = "windows") || current_group == group) {
-                    // ok, sup is running as svc_user/svc_group already
-                    Ok(Some((user, group)))
-                } else {
-                    let msg = format!("Package must run as {}:{} or root", &user, &group);
-                    return Err(sup_error!(Error::Permissions(msg)));
-                }
-            }
+            try!(check_user_and_group(&user, &group, "Package"));
+            Ok(Some((user, group)))
         }
         _ => {
             debug!("User/group not specified in package, running with default");
@@ -79,6 +91,67 @@ fn check_pkg_user_and_group(pkg_install: &PackageInstall) -> Result<Option<(Stri
     }
 }
 
+/// Check that the user and group a service runs as exist, and that we are either root or the
+/// user and group already. `source` names where they came from, for the errors.
+fn check_user_and_group(user: &str, group: &str, source: &str) -> Result<()> {
+    if let None = users::get_uid_by_name(&user) {
+        return Err(sup_error!(Error::Permissions(format!("{} requires user {} to exist, but it \
+                                                          doesn't",
+                                                         source,
+                                                         user))));
+    }
+    if let None = users::get_gid_by_name(&group) {
+        return Err(sup_error!(Error::Permissions(format!("{} requires group {} to exist, but \
+                                                          it doesn't",
+                                                         source,
+                                                         group))));
+    }
+
+    let current_user = users::get_current_username();
+    let current_group = users::get_current_groupname();
+
+    if let None = current_user {
+        return Err(sup_error!(Error::Permissions("Can't determine current user".to_string())));
+    }
+
+    if let None = current_group {
+        return Err(sup_error!(Error::Permissions("Can't determine current group".to_string())));
+    }
+
+    let current_user = current_user.unwrap();
+    let current_group = current_group.unwrap();
+
+    if current_user == users::root_level_account() {
+        Ok(())
+    } else {
+        if current_user == user && (cfg!(target_os = "windows") || current_group == group) {
+            // ok, sup is running as svc_user/svc_group already
+            Ok(())
+        } else {
+            let msg = format!("{} must run as {}:{} or root", source, &user, &group);
+            return Err(sup_error!(Error::Permissions(msg)));
+        }
+    }
+}
+
+/// Resolve the user and group given in a service's spec, filling in whichever is missing from
+/// the package metadata, or the defaults.
+fn check_spec_user_and_group(pkg_install: &PackageInstall,
+                             user_config: &UserConfig)
+                             -> Result<(String, String)> {
+    let (default_user, default_group) = try!(get_default_user_and_group());
+    let user = match user_config.user {
+        Some(ref user) => user.clone(),
+        None => try!(pkg_install.svc_user()).unwrap_or(default_user),
+    };
+    let group = match user_config.group {
+        Some(ref group) => group.clone(),
+        None => try!(pkg_install.svc_group()).unwrap_or(default_group),
+    };
+    try!(check_user_and_group(&user, &group, "Service"));
+    Ok((user, group))
+}
+
 /// checks to see if hab/hab exists, if not, fall back to
 /// current user/group. If that fails, then return an error.
 fn get_default_user_and_group() -> Result<(String, String)> {
@@ -104,13 +177,17 @@ fn get_default_user_and_group() -> Result<(String, String)> {
     }
 }
 
-/// check and see if a user/group is specified in package metadata.
+/// check and see if a user/group is specified in the service spec, and then in package metadata.
 /// if not, we'll try and use hab/hab.
 /// If hab/hab doesn't exist, try to use (current username, current group).
 /// If that doesn't work, then give up.
 #[cfg(unix)]
-pub fn get_user_and_group(pkg_install: &PackageInstall) -> Result<(String, String)> {
-    if let Some((user, group)) = try!(check_pkg_user_and_group(&pkg_install)) {
+pub fn get_user_and_group(pkg_install: &PackageInstall,
+                          user_config: &UserConfig)
+                          -> Result<(String, String)> {
+    if user_config.is_overridden() {
+        check_spec_user_and_group(pkg_install, user_config)
+    } else if let Some((user, group)) = try!(check_pkg_user_and_group(&pkg_install)) {
         Ok((user, group))
     } else {
         let defaults = try!(get_default_user_and_group());
@@ -122,7 +199,9 @@ pub fn get_user_and_group(pkg_install: &PackageInstall) -> Result<(String, Strin
 /// because we do not start the supervisor on windows under
 /// alternate credentials
 #[cfg(windows)]
-pub fn get_user_and_group(pkg_install: &PackageInstall) -> Result<(String, String)> {
+pub fn get_user_and_group(pkg_install: &PackageInstall,
+                          user_config: &UserConfig)
+                          -> Result<(String, String)> {
     let defaults = try!(get_default_user_and_group());
     Ok(defaults)
 }